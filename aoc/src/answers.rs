@@ -0,0 +1,119 @@
+use common::Answer;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AnswerKey {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+}
+
+impl AnswerKey {
+    pub fn new(day: u8, part: u8, input: &str) -> AnswerKey {
+        AnswerKey {
+            day,
+            part,
+            input: input.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct AnswerRegistry {
+    answers: HashMap<AnswerKey, Answer>,
+}
+
+impl AnswerRegistry {
+    pub fn from_text(text: &str) -> Result<AnswerRegistry, String> {
+        let mut answers = HashMap::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let line_number = index + 1;
+            let (key, answer) = parse_answer_line(line).ok_or(format!(
+                "line {line_number}: expected '<day> <part> <input> <answer>'"
+            ))?;
+
+            if answers.contains_key(&key) {
+                return Err(format!(
+                    "line {line_number}: duplicate answer for day {} part {} input '{}'",
+                    key.day, key.part, key.input
+                ));
+            }
+
+            answers.insert(key, answer);
+        }
+
+        Ok(AnswerRegistry { answers })
+    }
+}
+
+impl AnswerRegistry {
+    pub fn get(&self, key: &AnswerKey) -> Option<&Answer> {
+        self.answers.get(key)
+    }
+}
+
+fn parse_answer_line(line: &str) -> Option<(AnswerKey, Answer)> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+
+    let [day, part, input, answer] = fields[..] else {
+        return None;
+    };
+
+    let key = AnswerKey::new(day.parse().ok()?, part.parse().ok()?, input);
+    let answer = answer.parse().ok()?;
+
+    Some((key, answer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_answers_by_day_part_and_input() {
+        let text = "# day part input answer\n1 1 input 54390\n\n1 2 input 54277\n1 1 alice -12\n";
+        let registry = AnswerRegistry::from_text(text).unwrap();
+
+        assert_eq!(
+            registry.get(&AnswerKey::new(1, 1, "input")),
+            Some(&Answer::Number(54390))
+        );
+        assert_eq!(
+            registry.get(&AnswerKey::new(1, 2, "input")),
+            Some(&Answer::Number(54277))
+        );
+        assert_eq!(
+            registry.get(&AnswerKey::new(1, 1, "alice")),
+            Some(&Answer::Number(-12))
+        );
+        assert_eq!(registry.get(&AnswerKey::new(1, 2, "alice")), None);
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        let result = AnswerRegistry::from_text("1 1 input 54390\n1 1 input");
+        assert_eq!(
+            result.unwrap_err(),
+            "line 2: expected '<day> <part> <input> <answer>'"
+        );
+
+        assert!(AnswerRegistry::from_text("one 1 input 5").is_err());
+        assert!(AnswerRegistry::from_text("1 1 input five").is_err());
+    }
+
+    #[test]
+    fn rejects_duplicate_answers() {
+        let result = AnswerRegistry::from_text("1 1 input 54390\n1 1 input 54390");
+
+        assert_eq!(
+            result.unwrap_err(),
+            "line 2: duplicate answer for day 1 part 1 input 'input'"
+        );
+    }
+}
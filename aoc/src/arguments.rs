@@ -0,0 +1,386 @@
+use crate::day_options::{find_day_option, DayOptions};
+use std::{collections::HashMap, ops::RangeInclusive, path::PathBuf, str::FromStr};
+
+pub const USAGE: &str = "usage:
+    aoc run --day <1-25> [--part <1|2>] [--input <path>] [<day options>]
+    aoc verify [--day <1-25>] [--answers <path>]
+    aoc examples [--day <1-25>]
+    aoc bench [--day <1-25>] [--samples <count>] [--threshold <percent>] [--baseline <path>] [--save <path>]
+
+day options:
+    day 1: --lexicon <name|path>
+    day 2: --estimate <game id> [--max-cubes <count>]
+    day 4: [--scoring <doubling|linear|fibonacci>] [--prize <cap|wraparound>] [--breakdown]";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunArguments),
+    Verify(VerifyArguments),
+    Examples(ExamplesArguments),
+    Bench(BenchArguments),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RunArguments {
+    pub day: u8,
+    pub part: Option<u8>,
+    pub input: PathBuf,
+    pub day_options: DayOptions,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct VerifyArguments {
+    pub day: Option<u8>,
+    pub answers: PathBuf,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ExamplesArguments {
+    pub day: Option<u8>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchArguments {
+    pub day: Option<u8>,
+    pub samples: usize,
+    // percentage
+    pub threshold: f64,
+    pub baseline: PathBuf,
+    pub save: Option<PathBuf>,
+}
+
+impl Command {
+    pub fn from_args(args: &[String]) -> Result<Command, String> {
+        let (command, options) = args.split_first().ok_or("missing command")?;
+
+        match command.as_str() {
+            "run" => {
+                let (options, day_options) = split_day_options(options)?;
+                let mut options = parse_options(&options, &["--day", "--part", "--input"])?;
+
+                let day = parse_number(&mut options, "--day", 1..=25)?.ok_or("missing '--day'")?;
+                let part = parse_number(&mut options, "--part", 1..=2)?;
+                let input = options
+                    .remove("--input")
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| default_input_path(day));
+
+                for name in day_options.names() {
+                    match find_day_option(name) {
+                        Some((option_day, _)) if option_day != day => {
+                            return Err(format!("'{name}' only applies to day {option_day}"));
+                        }
+                        _ => {}
+                    }
+                }
+
+                Ok(Command::Run(RunArguments {
+                    day,
+                    part,
+                    input,
+                    day_options,
+                }))
+            }
+            "verify" => {
+                let mut options = parse_options(options, &["--day", "--answers"])?;
+
+                let day = parse_number(&mut options, "--day", 1..=25)?;
+                let answers = options
+                    .remove("--answers")
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| workspace_path().join("answers.txt"));
+
+                Ok(Command::Verify(VerifyArguments { day, answers }))
+            }
+            "examples" => {
+                let mut options = parse_options(options, &["--day"])?;
+
+                let day = parse_number(&mut options, "--day", 1..=25)?;
+
+                Ok(Command::Examples(ExamplesArguments { day }))
+            }
+            "bench" => {
+                let mut options = parse_options(
+                    options,
+                    &["--day", "--samples", "--threshold", "--baseline", "--save"],
+                )?;
+
+                let day = parse_number(&mut options, "--day", 1..=25)?;
+                let samples =
+                    parse_value(&mut options, "--samples", "a positive count", |samples| {
+                        *samples > 0
+                    })?
+                    .unwrap_or(10);
+                let threshold = parse_value(
+                    &mut options,
+                    "--threshold",
+                    "a percentage",
+                    |threshold: &f64| threshold.is_finite() && *threshold >= 0.0,
+                )?
+                .unwrap_or(10.0);
+                let baseline = options
+                    .remove("--baseline")
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| workspace_path().join("benchmarks.txt"));
+                let save = options.remove("--save").map(PathBuf::from);
+
+                Ok(Command::Bench(BenchArguments {
+                    day,
+                    samples,
+                    threshold,
+                    baseline,
+                    save,
+                }))
+            }
+            _ => Err(format!("unknown command '{command}'")),
+        }
+    }
+}
+
+impl RunArguments {
+    pub fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+pub fn workspace_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+pub fn day_path(day: u8) -> PathBuf {
+    workspace_path().join(format!("day{day}"))
+}
+
+fn default_input_path(day: u8) -> PathBuf {
+    day_path(day).join("input.txt")
+}
+
+// separates the options of a single day from the ones every day shares
+fn split_day_options(args: &[String]) -> Result<(Vec<String>, DayOptions), String> {
+    let mut shared = vec![];
+    let mut day_options = DayOptions::default();
+
+    let mut args = args.iter();
+    while let Some(option) = args.next() {
+        match find_day_option(option) {
+            Some((_, true)) => {
+                let value = args.next().ok_or(format!("missing value for '{option}'"))?;
+                day_options.insert(option, Some(value));
+            }
+            Some((_, false)) => day_options.insert(option, None),
+            None => {
+                shared.push(option.to_string());
+                shared.extend(args.next().cloned());
+            }
+        }
+    }
+
+    Ok((shared, day_options))
+}
+
+fn parse_options<'a>(
+    args: &'a [String],
+    allowed: &[&str],
+) -> Result<HashMap<&'a str, &'a str>, String> {
+    let mut options = HashMap::new();
+
+    let mut args = args.iter();
+    while let Some(option) = args.next() {
+        if !allowed.contains(&option.as_str()) {
+            return Err(format!("unknown option '{option}'"));
+        }
+
+        let value = args.next().ok_or(format!("missing value for '{option}'"))?;
+        options.insert(option.as_str(), value.as_str());
+    }
+
+    Ok(options)
+}
+
+fn parse_number(
+    options: &mut HashMap<&str, &str>,
+    option: &str,
+    range: RangeInclusive<u8>,
+) -> Result<Option<u8>, String> {
+    let expected = format!("{}-{}", range.start(), range.end());
+
+    parse_value(options, option, &expected, |number| range.contains(number))
+}
+
+fn parse_value<T: FromStr>(
+    options: &mut HashMap<&str, &str>,
+    option: &str,
+    expected: &str,
+    is_valid: impl Fn(&T) -> bool,
+) -> Result<Option<T>, String> {
+    let Some(value) = options.remove(option) else {
+        return Ok(None);
+    };
+
+    value
+        .parse()
+        .ok()
+        .filter(|value| is_valid(value))
+        .map(Some)
+        .ok_or(format!(
+            "invalid value '{value}' for '{option}', expected {expected}"
+        ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_args(text: &str) -> Vec<String> {
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parses_run_options() {
+        let command = Command::from_args(&to_args("run --day 17 --part 2 --input path")).unwrap();
+
+        let expected = RunArguments {
+            day: 17,
+            part: Some(2),
+            input: PathBuf::from("path"),
+            day_options: DayOptions::default(),
+        };
+        assert_eq!(expected.parts(), vec![2]);
+        assert_eq!(command, Command::Run(expected));
+    }
+
+    #[test]
+    fn defaults_to_both_parts_and_day_input() {
+        let Command::Run(arguments) = Command::from_args(&to_args("run --day 3")).unwrap() else {
+            panic!("expected run command");
+        };
+
+        assert_eq!(arguments.parts(), vec![1, 2]);
+        assert!(arguments.input.ends_with("day3/input.txt"));
+    }
+
+    #[test]
+    fn parses_day_options() {
+        let Command::Run(arguments) =
+            Command::from_args(&to_args("run --lexicon german --day 1 --part 2")).unwrap()
+        else {
+            panic!("expected run command");
+        };
+
+        assert_eq!(arguments.part, Some(2));
+        assert_eq!(arguments.day_options.value("--lexicon"), Some("german"));
+
+        assert_eq!(
+            Command::from_args(&to_args("run --day 2 --lexicon german")),
+            Err(String::from("'--lexicon' only applies to day 1"))
+        );
+        assert!(Command::from_args(&to_args("run --day 1 --lexicon")).is_err());
+    }
+
+    #[test]
+    fn parses_day_flags_without_values() {
+        let Command::Run(arguments) =
+            Command::from_args(&to_args("run --day 4 --breakdown --scoring linear")).unwrap()
+        else {
+            panic!("expected run command");
+        };
+
+        assert_eq!(
+            arguments.day_options.names().collect::<Vec<&str>>(),
+            vec!["--breakdown", "--scoring"]
+        );
+        assert_eq!(arguments.day_options.value("--breakdown"), None);
+        assert_eq!(arguments.day_options.value("--scoring"), Some("linear"));
+    }
+
+    #[test]
+    fn parses_verify_options() {
+        let command = Command::from_args(&to_args("verify --answers other.txt --day 5")).unwrap();
+        assert_eq!(
+            command,
+            Command::Verify(VerifyArguments {
+                day: Some(5),
+                answers: PathBuf::from("other.txt"),
+            })
+        );
+
+        let Command::Verify(arguments) = Command::from_args(&to_args("verify")).unwrap() else {
+            panic!("expected verify command");
+        };
+        assert_eq!(arguments.day, None);
+        assert!(arguments.answers.ends_with("answers.txt"));
+    }
+
+    #[test]
+    fn parses_examples_options() {
+        let command = Command::from_args(&to_args("examples --day 8")).unwrap();
+        assert_eq!(
+            command,
+            Command::Examples(ExamplesArguments { day: Some(8) })
+        );
+
+        let command = Command::from_args(&to_args("examples")).unwrap();
+        assert_eq!(command, Command::Examples(ExamplesArguments { day: None }));
+    }
+
+    #[test]
+    fn parses_bench_options() {
+        let command =
+            Command::from_args(&to_args("bench --day 21 --samples 3 --save out.txt")).unwrap();
+        assert_eq!(
+            command,
+            Command::Bench(BenchArguments {
+                day: Some(21),
+                samples: 3,
+                threshold: 10.0,
+                baseline: workspace_path().join("benchmarks.txt"),
+                save: Some(PathBuf::from("out.txt")),
+            })
+        );
+
+        let Command::Bench(arguments) =
+            Command::from_args(&to_args("bench --threshold 25 --baseline old.txt")).unwrap()
+        else {
+            panic!("expected bench command");
+        };
+        assert_eq!(arguments.samples, 10);
+        assert_eq!(arguments.threshold, 25.0);
+        assert_eq!(arguments.baseline, PathBuf::from("old.txt"));
+        assert_eq!(arguments.save, None);
+    }
+
+    #[test]
+    fn rejects_invalid_arguments() {
+        assert!(Command::from_args(&to_args("")).is_err());
+        assert!(Command::from_args(&to_args("walk --day 1")).is_err());
+        assert!(Command::from_args(&to_args("run --part 1")).is_err());
+        assert!(Command::from_args(&to_args("run --day 26")).is_err());
+        assert!(Command::from_args(&to_args("run --day 1 --part 3")).is_err());
+        assert!(Command::from_args(&to_args("run --day 1 --part")).is_err());
+        assert!(Command::from_args(&to_args("run --day 1 --speed 2")).is_err());
+        assert!(Command::from_args(&to_args("verify --input path")).is_err());
+        assert!(Command::from_args(&to_args("examples --answers path")).is_err());
+        assert!(Command::from_args(&to_args("bench --samples 0")).is_err());
+        assert_eq!(
+            Command::from_args(&to_args("bench --threshold -5")),
+            Err(String::from(
+                "invalid value '-5' for '--threshold', expected a percentage"
+            ))
+        );
+    }
+
+    #[test]
+    fn parses_large_bench_values() {
+        let Command::Bench(arguments) =
+            Command::from_args(&to_args("bench --samples 1000 --threshold 2.5")).unwrap()
+        else {
+            panic!("expected bench command");
+        };
+
+        assert_eq!(arguments.samples, 1000);
+        assert_eq!(arguments.threshold, 2.5);
+    }
+}
@@ -0,0 +1,306 @@
+use common::Timings;
+use std::{collections::BTreeMap, fmt::Display, time::Duration};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Stage {
+    fn from_text(text: &str) -> Option<Stage> {
+        match text {
+            "parse" => Some(Stage::Parse),
+            "part1" => Some(Stage::Part1),
+            "part2" => Some(Stage::Part2),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part1",
+            Stage::Part2 => "part2",
+        };
+
+        f.pad(text)
+    }
+}
+
+pub type BenchmarkKey = (u8, Stage);
+
+pub fn timing_entries(day: u8, timings: &Timings) -> Vec<(BenchmarkKey, Duration)> {
+    [
+        (Stage::Parse, Some(timings.parse)),
+        (Stage::Part1, timings.part1),
+        (Stage::Part2, timings.part2),
+    ]
+    .into_iter()
+    .filter_map(|(stage, time)| Some(((day, stage), time?)))
+    .collect()
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    times: BTreeMap<BenchmarkKey, Duration>,
+}
+
+impl Baseline {
+    pub fn from_text(text: &str) -> Result<Baseline, String> {
+        let mut times = BTreeMap::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let line_number = index + 1;
+            let (key, time) = parse_baseline_line(line).ok_or(format!(
+                "line {line_number}: expected '<day> <parse|part1|part2> <nanoseconds>'"
+            ))?;
+
+            if times.insert(key, time).is_some() {
+                return Err(format!(
+                    "line {line_number}: duplicate time for day {} {}",
+                    key.0, key.1
+                ));
+            }
+        }
+
+        Ok(Baseline { times })
+    }
+
+    pub fn to_text(&self) -> String {
+        let lines: String = self
+            .times
+            .iter()
+            .map(|((day, stage), time)| format!("{day} {stage} {}\n", time.as_nanos()))
+            .collect();
+
+        format!("# day stage nanoseconds\n{lines}")
+    }
+}
+
+impl Baseline {
+    pub fn get(&self, key: &BenchmarkKey) -> Option<Duration> {
+        self.times.get(key).copied()
+    }
+
+    pub fn insert(&mut self, key: BenchmarkKey, time: Duration) {
+        self.times.insert(key, time);
+    }
+}
+
+fn parse_baseline_line(line: &str) -> Option<(BenchmarkKey, Duration)> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+
+    let [day, stage, nanoseconds] = fields[..] else {
+        return None;
+    };
+
+    let key = (day.parse().ok()?, Stage::from_text(stage)?);
+
+    Some((key, Duration::from_nanos(nanoseconds.parse().ok()?)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change {
+    New,
+    Unchanged,
+    Faster,
+    Regression,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Change::New => "new",
+            Change::Unchanged => "unchanged",
+            Change::Faster => "faster",
+            Change::Regression => "regression",
+        };
+
+        f.pad(text)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Comparison {
+    pub key: BenchmarkKey,
+    pub baseline: Option<Duration>,
+    pub time: Duration,
+    pub change: Change,
+}
+
+impl Comparison {
+    // changes within the threshold percentage either way count as noise
+    pub fn new(
+        key: BenchmarkKey,
+        time: Duration,
+        baseline: &Baseline,
+        threshold: f64,
+    ) -> Comparison {
+        let previous = baseline.get(&key);
+
+        let change = match previous {
+            None => Change::New,
+            Some(previous) => {
+                let ratio = time.as_secs_f64() / previous.as_secs_f64().max(f64::MIN_POSITIVE);
+                let threshold = threshold / 100.0;
+
+                match ratio {
+                    _ if ratio > 1.0 + threshold => Change::Regression,
+                    _ if ratio < 1.0 - threshold => Change::Faster,
+                    _ => Change::Unchanged,
+                }
+            }
+        };
+
+        Comparison {
+            key,
+            baseline: previous,
+            time,
+            change,
+        }
+    }
+
+    fn percentage(&self) -> Option<f64> {
+        let baseline = self.baseline?.as_secs_f64();
+
+        match baseline > 0.0 {
+            true => Some((self.time.as_secs_f64() / baseline - 1.0) * 100.0),
+            false => None,
+        }
+    }
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanoseconds = duration.as_nanos() as f64;
+
+    match nanoseconds {
+        _ if nanoseconds < 1e3 => format!("{nanoseconds:.0} ns"),
+        _ if nanoseconds < 1e6 => format!("{:.1} µs", nanoseconds / 1e3),
+        _ if nanoseconds < 1e9 => format!("{:.1} ms", nanoseconds / 1e6),
+        _ => format!("{:.2} s", nanoseconds / 1e9),
+    }
+}
+
+pub fn format_table(comparisons: &[Comparison]) -> String {
+    let header = ["day", "stage", "time", "baseline", "change", "status"].map(String::from);
+
+    let rows: Vec<[String; 6]> = comparisons
+        .iter()
+        .map(|comparison| {
+            let (day, stage) = comparison.key;
+
+            [
+                day.to_string(),
+                stage.to_string(),
+                format_duration(comparison.time),
+                comparison.baseline.map(format_duration).unwrap_or_default(),
+                comparison
+                    .percentage()
+                    .map(|percentage| format!("{percentage:+.1}%"))
+                    .unwrap_or_default(),
+                comparison.change.to_string(),
+            ]
+        })
+        .collect();
+
+    let mut widths = header.clone().map(|column| column.chars().count());
+    for row in &rows {
+        for (width, column) in widths.iter_mut().zip(row) {
+            *width = (*width).max(column.chars().count());
+        }
+    }
+
+    std::iter::once(&header)
+        .chain(&rows)
+        .map(|row| {
+            let columns: Vec<String> = row
+                .iter()
+                .zip(widths)
+                .map(|(column, width)| format!("{column:<width$}"))
+                .collect();
+
+            format!("{}\n", columns.join("  ").trim_end())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn baseline() -> Baseline {
+        Baseline::from_text("# day stage nanoseconds\n1 parse 1000\n1 part1 2000000\n").unwrap()
+    }
+
+    #[test]
+    fn parses_and_writes_baselines() {
+        let baseline = baseline();
+
+        assert_eq!(
+            baseline.get(&(1, Stage::Part1)),
+            Some(Duration::from_millis(2))
+        );
+        assert_eq!(baseline.get(&(1, Stage::Part2)), None);
+        assert_eq!(Baseline::from_text(&baseline.to_text()), Ok(baseline));
+    }
+
+    #[test]
+    fn rejects_malformed_baselines() {
+        let result = Baseline::from_text("1 parse 1000\n1 part3 1000");
+        assert_eq!(
+            result.unwrap_err(),
+            "line 2: expected '<day> <parse|part1|part2> <nanoseconds>'"
+        );
+
+        let result = Baseline::from_text("1 parse 1000\n1 parse 2000");
+        assert_eq!(
+            result.unwrap_err(),
+            "line 2: duplicate time for day 1 parse"
+        );
+    }
+
+    #[test]
+    fn flags_changes_beyond_threshold() {
+        let baseline = baseline();
+        let compare = |time| Comparison::new((1, Stage::Part1), time, &baseline, 10.0).change;
+
+        assert_eq!(compare(Duration::from_micros(2100)), Change::Unchanged);
+        assert_eq!(compare(Duration::from_micros(2300)), Change::Regression);
+        assert_eq!(compare(Duration::from_micros(1500)), Change::Faster);
+
+        let new = Comparison::new((2, Stage::Parse), Duration::from_micros(1), &baseline, 10.0);
+        assert_eq!(new.change, Change::New);
+    }
+
+    #[test]
+    fn formats_comparison_table() {
+        let baseline = baseline();
+        let comparisons = vec![
+            Comparison::new(
+                (1, Stage::Parse),
+                Duration::from_nanos(900),
+                &baseline,
+                10.0,
+            ),
+            Comparison::new((1, Stage::Part1), Duration::from_millis(3), &baseline, 10.0),
+            Comparison::new((1, Stage::Part2), Duration::from_secs(2), &baseline, 10.0),
+        ];
+
+        assert_eq!(
+            format_table(&comparisons),
+            "day  stage  time    baseline  change  status\n\
+             1    parse  900 ns  1.0 µs    -10.0%  unchanged\n\
+             1    part1  3.0 ms  2.0 ms    +50.0%  regression\n\
+             1    part2  2.00 s                    new\n"
+        );
+    }
+}
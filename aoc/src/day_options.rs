@@ -0,0 +1,258 @@
+use common::{Answer, ParseError, Solution};
+use day1::{Day1, DigitLexicon};
+use day2::{describe_estimate, Day2, DEFAULT_MAX_PER_COLOUR};
+use day4::{
+    format_breakdown, get_all_card_scores, get_total_cards_won, resolve_cards, Day4, PrizeRule,
+    ScoringRule,
+};
+use std::fmt::Display;
+
+// options that only one day understands, with whether they take a value
+pub const DAY_OPTIONS: &[(u8, &str, bool)] = &[
+    (1, "--lexicon", true),
+    (2, "--estimate", true),
+    (2, "--max-cubes", true),
+    (4, "--scoring", true),
+    (4, "--prize", true),
+    (4, "--breakdown", false),
+];
+
+pub fn find_day_option(name: &str) -> Option<(u8, bool)> {
+    DAY_OPTIONS
+        .iter()
+        .find(|(_, option, _)| *option == name)
+        .map(|(day, _, takes_value)| (*day, *takes_value))
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct DayOptions {
+    options: Vec<(String, Option<String>)>,
+}
+
+impl DayOptions {
+    pub fn insert(&mut self, name: &str, value: Option<&str>) {
+        self.options
+            .push((name.to_string(), value.map(String::from)));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.options.is_empty()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.options.iter().map(|(name, _)| name.as_str())
+    }
+
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .rev()
+            .find(|(option, _)| option == name)
+            .and_then(|(_, value)| value.as_deref())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DayOutput {
+    Answers(Vec<Answer>),
+    Report(String),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DayError {
+    Option(String),
+    Input(ParseError),
+}
+
+impl Display for DayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DayError::Option(error) => write!(f, "{error}"),
+            DayError::Input(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+// solves a day the way its options ask, instead of the puzzle's way
+pub fn run_with_options(
+    day: u8,
+    text: &str,
+    parts: &[u8],
+    options: &DayOptions,
+) -> Result<DayOutput, DayError> {
+    match day {
+        1 => run_day1(text, parts, options),
+        2 => run_day2(text, options),
+        4 => run_day4(text, parts, options),
+        _ => Err(DayError::Option(format!("day {day} takes no options"))),
+    }
+}
+
+fn run_day1(text: &str, parts: &[u8], options: &DayOptions) -> Result<DayOutput, DayError> {
+    let lexicon = match options.value("--lexicon") {
+        Some(name) => DigitLexicon::load(name).map_err(DayError::Option)?,
+        None => DigitLexicon::english(),
+    };
+    let input = Day1::parse(text).map_err(DayError::Input)?;
+
+    let answers = parts
+        .iter()
+        .map(|part| match part {
+            1 => Day1::part1(&input),
+            _ => Day1::part2_with_lexicon(&input, &lexicon),
+        })
+        .collect();
+
+    Ok(DayOutput::Answers(answers))
+}
+
+// the bag estimate replaces the answers, since it describes a single game
+fn run_day2(text: &str, options: &DayOptions) -> Result<DayOutput, DayError> {
+    let id = options
+        .value("--estimate")
+        .ok_or_else(|| DayError::Option(String::from("'--max-cubes' needs '--estimate'")))?;
+    let id = parse_option_number("--estimate", id)?;
+    let max_per_colour = match options.value("--max-cubes") {
+        Some(value) => parse_option_number("--max-cubes", value)?,
+        None => DEFAULT_MAX_PER_COLOUR,
+    };
+    let games = Day2::parse(text).map_err(DayError::Input)?;
+
+    describe_estimate(&games, id, max_per_colour)
+        .map(DayOutput::Report)
+        .map_err(DayError::Option)
+}
+
+fn run_day4(text: &str, parts: &[u8], options: &DayOptions) -> Result<DayOutput, DayError> {
+    let scoring = match options.value("--scoring") {
+        Some(value) => ScoringRule::from_text(value)
+            .ok_or_else(|| DayError::Option(format!("unknown scoring rule '{value}'")))?,
+        None => ScoringRule::default(),
+    };
+    let prize = match options.value("--prize") {
+        Some(value) => PrizeRule::from_text(value)
+            .ok_or_else(|| DayError::Option(format!("unknown prize rule '{value}'")))?,
+        None => PrizeRule::default(),
+    };
+    let cards = Day4::parse(text).map_err(DayError::Input)?;
+
+    // the breakdown lists the copies of every card instead of the answers
+    if options.names().any(|name| name == "--breakdown") {
+        return Ok(DayOutput::Report(format_breakdown(&resolve_cards(
+            &cards, prize,
+        ))));
+    }
+
+    let answers = parts
+        .iter()
+        .map(|part| match part {
+            1 => get_all_card_scores(&cards, scoring).into(),
+            _ => get_total_cards_won(&cards, prize).into(),
+        })
+        .collect();
+
+    Ok(DayOutput::Answers(answers))
+}
+
+fn parse_option_number(name: &str, value: &str) -> Result<u32, DayError> {
+    value
+        .parse()
+        .map_err(|_| DayError::Option(format!("invalid number '{value}' for '{name}'")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_day1_with_other_lexicon() {
+        let mut options = DayOptions::default();
+        options.insert("--lexicon", Some("english-extended"));
+
+        let output = run_with_options(1, "zero4\n1x2", &[1, 2], &options);
+
+        assert_eq!(
+            output,
+            Ok(DayOutput::Answers(vec![
+                Answer::Number(44 + 12),
+                Answer::Number(4 + 12)
+            ]))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_lexicon() {
+        let mut options = DayOptions::default();
+        options.insert("--lexicon", Some("klingon"));
+
+        let output = run_with_options(1, "1", &[2], &options);
+
+        assert!(matches!(output, Err(DayError::Option(_))));
+    }
+
+    #[test]
+    fn estimates_day2_bag() {
+        let mut options = DayOptions::default();
+        options.insert("--estimate", Some("2"));
+        options.insert("--max-cubes", Some("4"));
+
+        let output = run_with_options(
+            2,
+            "Game 1: 1 red\nGame 2: 4 blue, 4 green, 4 red",
+            &[1, 2],
+            &options,
+        );
+
+        assert_eq!(
+            output,
+            Ok(DayOutput::Report(String::from(
+                "most likely bag: 4 blue, 4 green, 4 red\n\
+                 blue: mean 4.00, 90% between 4 and 4\n\
+                 green: mean 4.00, 90% between 4 and 4\n\
+                 red: mean 4.00, 90% between 4 and 4"
+            )))
+        );
+
+        let mut options = DayOptions::default();
+        options.insert("--max-cubes", Some("4"));
+        assert!(matches!(
+            run_with_options(2, "Game 1: 1 red", &[1], &options),
+            Err(DayError::Option(_))
+        ));
+
+        options.insert("--estimate", Some("3"));
+        assert_eq!(
+            run_with_options(2, "Game 1: 1 red", &[1], &options),
+            Err(DayError::Option(String::from("no game with id 3")))
+        );
+    }
+
+    #[test]
+    fn runs_day4_with_other_rules() {
+        let text = "Card 1: 1 2 3 | 1 2 3\nCard 2: 4 | 5";
+
+        let mut options = DayOptions::default();
+        options.insert("--scoring", Some("linear"));
+        assert_eq!(
+            run_with_options(4, text, &[1, 2], &options),
+            Ok(DayOutput::Answers(vec![
+                Answer::Number(3),
+                Answer::Number(3)
+            ]))
+        );
+
+        options.insert("--breakdown", None);
+        assert!(matches!(
+            run_with_options(4, text, &[1, 2], &options),
+            Ok(DayOutput::Report(_))
+        ));
+
+        options.insert("--prize", Some("lottery"));
+        assert_eq!(
+            run_with_options(4, text, &[1], &options),
+            Err(DayError::Option(String::from(
+                "unknown prize rule 'lottery'"
+            )))
+        );
+    }
+}
@@ -0,0 +1,253 @@
+mod answers;
+mod arguments;
+mod benchmarks;
+mod day_options;
+mod solvers;
+mod verify;
+
+use answers::{AnswerKey, AnswerRegistry};
+use arguments::{
+    day_path, BenchArguments, Command, ExamplesArguments, RunArguments, VerifyArguments, USAGE,
+};
+use benchmarks::{timing_entries, Baseline, Change, Comparison};
+use common::parse_examples;
+use day_options::{run_with_options, DayError, DayOutput};
+use solvers::{find_benchmark, find_solver};
+use std::{
+    env,
+    fs::{read_to_string, write},
+    path::Path,
+    process::ExitCode,
+};
+use verify::{find_input_names, format_table, Status, Verification};
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = match Command::from_args(&args) {
+        Ok(command) => command,
+        Err(error) => {
+            eprintln!("error: {error}\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match command {
+        Command::Run(arguments) => run(arguments),
+        Command::Verify(arguments) => verify(arguments),
+        Command::Examples(arguments) => examples(arguments),
+        Command::Bench(arguments) => bench(arguments),
+    };
+
+    match result {
+        Ok(exit_code) => exit_code,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(arguments: RunArguments) -> Result<ExitCode, String> {
+    let solver =
+        find_solver(arguments.day).ok_or(format!("day {} is not implemented", arguments.day))?;
+    let text = read_file(&arguments.input)?;
+
+    let parts = arguments.parts();
+    let output = match arguments.day_options.is_empty() {
+        true => solver(&text, &parts)
+            .map(DayOutput::Answers)
+            .map_err(|error| format!("{}: {error}", arguments.input.display()))?,
+        false => match run_with_options(arguments.day, &text, &parts, &arguments.day_options) {
+            Ok(output) => output,
+            Err(DayError::Input(error)) => {
+                return Err(format!("{}: {error}", arguments.input.display()))
+            }
+            Err(error) => return Err(error.to_string()),
+        },
+    };
+
+    match output {
+        DayOutput::Answers(answers) => {
+            for (part, answer) in parts.iter().zip(answers) {
+                println!("day {} part {part}: {answer}", arguments.day);
+            }
+        }
+        DayOutput::Report(report) => println!("{}", report.trim_end()),
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn verify(arguments: VerifyArguments) -> Result<ExitCode, String> {
+    let registry = AnswerRegistry::from_text(&read_file(&arguments.answers)?)
+        .map_err(|error| format!("{}: {error}", arguments.answers.display()))?;
+
+    let mut verifications = vec![];
+
+    for day in selected_days(arguments.day) {
+        let Some(solver) = find_solver(day) else {
+            continue;
+        };
+
+        let day_path = day_path(day);
+
+        for input in find_input_names(&day_path) {
+            let input_path = day_path.join(format!("{input}.txt"));
+            let answers = solver(&read_file(&input_path)?, &[1, 2])
+                .map_err(|error| format!("{}: {error}", input_path.display()))?;
+
+            for (part, answer) in [1, 2].into_iter().zip(answers) {
+                let key = AnswerKey::new(day, part, &input);
+                verifications.extend(Verification::new(key, answer, &registry));
+            }
+        }
+    }
+
+    Ok(report(&verifications))
+}
+
+fn examples(arguments: ExamplesArguments) -> Result<ExitCode, String> {
+    let mut verifications = vec![];
+
+    for day in selected_days(arguments.day) {
+        let Some(solver) = find_solver(day) else {
+            continue;
+        };
+
+        let readme_path = day_path(day).join("readme.md");
+        let examples = parse_examples(&read_file(&readme_path)?)
+            .map_err(|error| format!("{}: {error}", readme_path.display()))?;
+
+        let mut checked_parts = vec![];
+
+        for example in examples {
+            let input = format!("readme:{}", example.line);
+            let parts: Vec<u8> = example.answers.iter().map(|(part, _)| *part).collect();
+            let answers = solver(&example.input, &parts).map_err(|error| {
+                format!(
+                    "{}: example on line {}: {error}",
+                    readme_path.display(),
+                    example.line
+                )
+            })?;
+
+            for ((part, expected), answer) in example.answers.into_iter().zip(answers) {
+                let key = AnswerKey::new(day, part, &input);
+                verifications.extend(Verification::compare(key, answer, Some(expected)));
+                checked_parts.push(part);
+            }
+        }
+
+        // parts no readme example covers, such as ones needing fewer steps than the puzzle
+        for part in [1, 2]
+            .into_iter()
+            .filter(|part| !checked_parts.contains(part))
+        {
+            verifications.push(Verification::unchecked(AnswerKey::new(day, part, "readme")));
+        }
+    }
+
+    Ok(report(&verifications))
+}
+
+fn bench(arguments: BenchArguments) -> Result<ExitCode, String> {
+    let baseline = read_baseline(&arguments.baseline)?;
+
+    let mut comparisons = vec![];
+
+    for day in selected_days(arguments.day) {
+        let Some(benchmark) = find_benchmark(day) else {
+            continue;
+        };
+
+        let input_path = day_path(day).join("input.txt");
+        let timings = benchmark(&read_file(&input_path)?, arguments.samples)
+            .map_err(|error| format!("{}: {error}", input_path.display()))?;
+
+        for (key, time) in timing_entries(day, &timings) {
+            comparisons.push(Comparison::new(key, time, &baseline, arguments.threshold));
+        }
+    }
+
+    print!("{}", benchmarks::format_table(&comparisons));
+
+    let count = |change| {
+        comparisons
+            .iter()
+            .filter(|comparison| comparison.change == change)
+            .count()
+    };
+    let regressions = count(Change::Regression);
+
+    println!(
+        "\n{regressions} regressed, {} faster, {} unchanged, {} new",
+        count(Change::Faster),
+        count(Change::Unchanged),
+        count(Change::New)
+    );
+
+    if let Some(path) = &arguments.save {
+        // keep the timings of days that were not measured this time
+        let mut saved = read_baseline(path)?;
+        for comparison in &comparisons {
+            saved.insert(comparison.key, comparison.time);
+        }
+
+        write(path, saved.to_text())
+            .map_err(|error| format!("could not write {}: {error}", path.display()))?;
+        println!("saved {} timings to {}", comparisons.len(), path.display());
+    }
+
+    match regressions {
+        0 => Ok(ExitCode::SUCCESS),
+        _ => Ok(ExitCode::FAILURE),
+    }
+}
+
+fn read_baseline(path: &Path) -> Result<Baseline, String> {
+    if !path.exists() {
+        return Ok(Baseline::default());
+    }
+
+    Baseline::from_text(&read_file(path)?).map_err(|error| format!("{}: {error}", path.display()))
+}
+
+fn selected_days(day: Option<u8>) -> Vec<u8> {
+    match day {
+        Some(day) => vec![day],
+        None => (1..=25).collect(),
+    }
+}
+
+fn report(verifications: &[Verification]) -> ExitCode {
+    print!("{}", format_table(verifications));
+
+    let count = |status| {
+        verifications
+            .iter()
+            .filter(|verification| verification.status == status)
+            .count()
+    };
+    let failed = count(Status::Fail);
+
+    print!(
+        "\n{} passed, {failed} failed, {} missing",
+        count(Status::Pass),
+        count(Status::Missing)
+    );
+
+    match count(Status::Unchecked) {
+        0 => println!(),
+        unchecked => println!(", {unchecked} unchecked"),
+    }
+
+    match failed {
+        0 => ExitCode::SUCCESS,
+        _ => ExitCode::FAILURE,
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    read_to_string(path).map_err(|error| format!("could not read {}: {error}", path.display()))
+}
@@ -0,0 +1,77 @@
+use common::{benchmark, solve, Answer, ParseError, Solution, Timings};
+
+pub type Solver = fn(&str, &[u8]) -> Result<Vec<Answer>, ParseError>;
+
+pub type Benchmark = fn(&str, usize) -> Result<Timings, ParseError>;
+
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub solve: Solver,
+    pub benchmark: Benchmark,
+}
+
+impl Day {
+    fn of<S: Solution>() -> Day {
+        Day {
+            solve: solve::<S>,
+            benchmark: benchmark::<S>,
+        }
+    }
+}
+
+pub fn find_solver(day: u8) -> Option<Solver> {
+    find_day(day).map(|day| day.solve)
+}
+
+pub fn find_benchmark(day: u8) -> Option<Benchmark> {
+    find_day(day).map(|day| day.benchmark)
+}
+
+fn find_day(day: u8) -> Option<Day> {
+    let day = match day {
+        1 => Day::of::<day1::Day1>(),
+        2 => Day::of::<day2::Day2>(),
+        3 => Day::of::<day3::Day3>(),
+        4 => Day::of::<day4::Day4>(),
+        5 => Day::of::<day5::Day5>(),
+        6 => Day::of::<day6::Day6>(),
+        7 => Day::of::<day7::Day7>(),
+        8 => Day::of::<day8::Day8>(),
+        9 => Day::of::<day9::Day9>(),
+        10 => Day::of::<day10::Day10>(),
+        11 => Day::of::<day11::Day11>(),
+        12 => Day::of::<day12::Day12>(),
+        13 => Day::of::<day13::Day13>(),
+        14 => Day::of::<day14::Day14>(),
+        15 => Day::of::<day15::Day15>(),
+        16 => Day::of::<day16::Day16>(),
+        17 => Day::of::<day17::Day17>(),
+        18 => Day::of::<day18::Day18>(),
+        19 => Day::of::<day19::Day19>(),
+        20 => Day::of::<day20::Day20>(),
+        21 => Day::of::<day21::Day21>(),
+        22 => Day::of::<day22::Day22>(),
+        23 => Day::of::<day23::Day23>(),
+        24 => Day::of::<day24::Day24>(),
+        _ => return None,
+    };
+
+    Some(day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_solver_for_implemented_days() {
+        assert!(find_solver(1).is_some());
+        assert!(find_solver(24).is_some());
+    }
+
+    #[test]
+    fn finds_no_solver_for_missing_days() {
+        assert!(find_solver(25).is_none());
+        assert!(find_benchmark(25).is_none());
+    }
+}
@@ -0,0 +1,211 @@
+use crate::answers::{AnswerKey, AnswerRegistry};
+use common::Answer;
+use std::{fmt::Display, fs::read_dir, path::Path};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+    // no example in the readme covers the part
+    Unchecked,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Missing => "missing",
+            Status::Unchecked => "unchecked",
+        };
+
+        f.pad(text)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Verification {
+    pub key: AnswerKey,
+    pub expected: Option<Answer>,
+    pub actual: Answer,
+    pub status: Status,
+}
+
+impl Verification {
+    pub fn new(key: AnswerKey, actual: Answer, registry: &AnswerRegistry) -> Option<Verification> {
+        let expected = registry.get(&key).cloned();
+
+        Verification::compare(key, actual, expected)
+    }
+
+    pub fn compare(
+        key: AnswerKey,
+        actual: Answer,
+        expected: Option<Answer>,
+    ) -> Option<Verification> {
+        let status = match &expected {
+            None if actual == Answer::Unsolved => return None,
+            None => Status::Missing,
+            Some(expected) if expected == &actual => Status::Pass,
+            Some(_) => Status::Fail,
+        };
+
+        Some(Verification {
+            key,
+            expected,
+            actual,
+            status,
+        })
+    }
+
+    pub fn unchecked(key: AnswerKey) -> Verification {
+        Verification {
+            key,
+            expected: None,
+            actual: Answer::Unsolved,
+            status: Status::Unchecked,
+        }
+    }
+}
+
+pub fn find_input_names(day_path: &Path) -> Vec<String> {
+    let Ok(entries) = read_dir(day_path) else {
+        return vec![];
+    };
+
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+
+            match path.extension()?.to_str()? {
+                "txt" => Some(path.file_stem()?.to_str()?.to_string()),
+                _ => None,
+            }
+        })
+        .collect();
+
+    names.sort();
+    names
+}
+
+pub fn format_table(verifications: &[Verification]) -> String {
+    let header = ["day", "part", "input", "status", "expected", "actual"].map(String::from);
+
+    let rows: Vec<[String; 6]> = verifications
+        .iter()
+        .map(|verification| {
+            let Verification {
+                key,
+                expected,
+                actual,
+                status,
+            } = verification;
+
+            [
+                key.day.to_string(),
+                key.part.to_string(),
+                key.input.to_string(),
+                status.to_string(),
+                expected.as_ref().map(Answer::to_string).unwrap_or_default(),
+                match status {
+                    Status::Unchecked => String::new(),
+                    _ => actual.to_string(),
+                },
+            ]
+        })
+        .collect();
+
+    let mut widths = header.clone().map(|column| column.len());
+    for row in &rows {
+        for (width, column) in widths.iter_mut().zip(row) {
+            *width = (*width).max(column.len());
+        }
+    }
+
+    std::iter::once(&header)
+        .chain(&rows)
+        .map(|row| {
+            let columns: Vec<String> = row
+                .iter()
+                .zip(widths)
+                .map(|(column, width)| format!("{column:<width$}"))
+                .collect();
+
+            format!("{}\n", columns.join("  ").trim_end())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> AnswerRegistry {
+        AnswerRegistry::from_text("1 1 input 54390\n1 2 input 54277").unwrap()
+    }
+
+    #[test]
+    fn compares_answers_to_registry() {
+        let registry = registry();
+
+        let pass = Verification::new(
+            AnswerKey::new(1, 1, "input"),
+            Answer::Number(54390),
+            &registry,
+        );
+        assert_eq!(pass.unwrap().status, Status::Pass);
+
+        let fail = Verification::new(AnswerKey::new(1, 2, "input"), Answer::Number(1), &registry);
+        assert_eq!(fail.unwrap().status, Status::Fail);
+
+        let missing =
+            Verification::new(AnswerKey::new(1, 1, "alice"), Answer::Number(1), &registry);
+        assert_eq!(missing.unwrap().status, Status::Missing);
+    }
+
+    #[test]
+    fn skips_unsolved_parts_without_answers() {
+        let registry = registry();
+
+        let unsolved =
+            Verification::new(AnswerKey::new(21, 2, "input"), Answer::Unsolved, &registry);
+        assert_eq!(unsolved, None);
+
+        let unsolved =
+            Verification::new(AnswerKey::new(1, 2, "input"), Answer::Unsolved, &registry);
+        assert_eq!(unsolved.unwrap().status, Status::Fail);
+    }
+
+    #[test]
+    fn formats_aligned_table() {
+        let registry = registry();
+        let verifications = vec![
+            Verification::new(
+                AnswerKey::new(1, 1, "input"),
+                Answer::Number(54390),
+                &registry,
+            )
+            .unwrap(),
+            Verification::new(AnswerKey::new(1, 1, "alice"), Answer::Number(7), &registry).unwrap(),
+        ];
+
+        assert_eq!(
+            format_table(&verifications),
+            "day  part  input  status   expected  actual\n\
+             1    1     input  pass     54390     54390\n\
+             1    1     alice  missing            7\n"
+        );
+    }
+
+    #[test]
+    fn leaves_unchecked_answers_blank() {
+        let verifications = vec![Verification::unchecked(AnswerKey::new(21, 2, "readme"))];
+
+        assert_eq!(
+            format_table(&verifications),
+            "day  part  input   status     expected  actual\n\
+             21   2     readme  unchecked\n"
+        );
+    }
+}
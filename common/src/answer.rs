@@ -0,0 +1,90 @@
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    // only for positive answers that do not fit in an i128
+    Large(u128),
+    Unsolved,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{value}"),
+            Answer::Large(value) => write!(f, "{value}"),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Answer {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Answer {
+        match i128::try_from(value) {
+            Ok(value) => Answer::Number(value),
+            Err(_) => Answer::Large(value),
+        }
+    }
+}
+
+// a number, read the same way it is displayed
+impl FromStr for Answer {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Answer, String> {
+        match (text.parse::<i128>(), text.parse::<u128>()) {
+            (Ok(value), _) => Ok(Answer::Number(value)),
+            (_, Ok(value)) => Ok(Answer::Large(value)),
+            _ => Err(format!("invalid answer '{text}'")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_integers_to_numbers() {
+        assert_eq!(Answer::from(54390_u32), Answer::Number(54390));
+        assert_eq!(Answer::from(-3_i64), Answer::Number(-3));
+        assert_eq!(
+            Answer::from(22289513667691_u128),
+            Answer::Number(22289513667691)
+        );
+        assert_eq!(Answer::from(u128::MAX), Answer::Large(u128::MAX));
+    }
+
+    #[test]
+    fn parses_displayed_answers() {
+        for answer in [
+            Answer::Number(-12),
+            Answer::Number(i128::MAX),
+            Answer::Large(u128::MAX),
+        ] {
+            assert_eq!(answer.to_string().parse(), Ok(answer));
+        }
+
+        assert!("12x".parse::<Answer>().is_err());
+    }
+
+    #[test]
+    fn displays_answers() {
+        assert_eq!(Answer::Number(801).to_string(), "801");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+}
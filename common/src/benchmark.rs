@@ -0,0 +1,85 @@
+use crate::{Answer, ParseError, Solution};
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timings {
+    pub parse: Duration,
+    // None for parts without a solution
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+// median time of each stage over the given number of samples
+pub fn benchmark<S: Solution>(text: &str, samples: usize) -> Result<Timings, ParseError> {
+    let input = S::parse(text)?;
+
+    let parse = median_time(samples, || {
+        let _ = black_box(S::parse(black_box(text)));
+    });
+
+    let time_part = |solve: fn(&S::Input) -> Answer| match solve(&input) {
+        Answer::Unsolved => None,
+        _ => Some(median_time(samples, || {
+            black_box(solve(black_box(&input)));
+        })),
+    };
+
+    Ok(Timings {
+        parse,
+        part1: time_part(S::part1),
+        part2: time_part(S::part2),
+    })
+}
+
+fn median_time(samples: usize, mut run: impl FnMut()) -> Duration {
+    let mut times: Vec<Duration> = (0..samples.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .collect();
+
+    times.sort();
+    times[times.len() / 2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct WordCount;
+
+    impl Solution for WordCount {
+        type Input = Vec<String>;
+
+        fn parse(text: &str) -> Result<Vec<String>, ParseError> {
+            match text.is_empty() {
+                true => Err(ParseError::new(1, 1, "empty input")),
+                false => Ok(text.split_whitespace().map(String::from).collect()),
+            }
+        }
+
+        fn part1(input: &Vec<String>) -> Answer {
+            input.len().into()
+        }
+    }
+
+    #[test]
+    fn times_parse_and_solved_parts() {
+        let timings = benchmark::<WordCount>("a b c", 3).unwrap();
+
+        assert!(timings.part1.is_some());
+        assert_eq!(timings.part2, None);
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        let error = benchmark::<WordCount>("", 3).unwrap_err();
+
+        assert_eq!(error, ParseError::new(1, 1, "empty input"));
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{solve, Answer, Solution};
+
+// a puzzle example marked in a readme as a fenced block, e.g. "```example part1=142"
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub line: usize,
+    pub input: String,
+    pub answers: Vec<(u8, Answer)>,
+}
+
+pub fn parse_examples(readme: &str) -> Result<Vec<Example>, String> {
+    let mut examples = vec![];
+    let mut current: Option<(Example, Vec<&str>)> = None;
+    let mut in_other_block = false;
+
+    for (index, line) in readme.lines().enumerate() {
+        let number = index + 1;
+
+        let Some(info) = line.trim_end().strip_prefix("```") else {
+            if let Some((_, lines)) = current.as_mut() {
+                lines.push(line);
+            }
+            continue;
+        };
+
+        if let Some((mut example, lines)) = current.take() {
+            example.input = lines.join("\n");
+            examples.push(example);
+            continue;
+        }
+
+        if in_other_block {
+            in_other_block = false;
+            continue;
+        }
+
+        let mut words = info.split_whitespace();
+
+        if words.next() != Some("example") {
+            in_other_block = true;
+            continue;
+        }
+
+        let answers = words
+            .map(|word| parse_answer(word).ok_or(format!("line {number}: invalid answer '{word}'")))
+            .collect::<Result<Vec<(u8, Answer)>, String>>()?;
+
+        let example = Example {
+            line: number,
+            input: String::new(),
+            answers,
+        };
+        current = Some((example, vec![]));
+    }
+
+    match current {
+        Some((example, _)) => Err(format!("line {}: unterminated example", example.line)),
+        None => Ok(examples),
+    }
+}
+
+// "part1=142" -> (1, 142)
+fn parse_answer(text: &str) -> Option<(u8, Answer)> {
+    let (part, value) = text.strip_prefix("part")?.split_once('=')?;
+
+    let part = part.parse().ok().filter(|part| matches!(part, 1 | 2))?;
+    let value = value.parse().ok()?;
+
+    Some((part, value))
+}
+
+// test helper that solves every example in a readme and panics on the first wrong answer
+pub fn check_examples<S: Solution>(readme: &str) {
+    let examples = parse_examples(readme).unwrap();
+
+    for example in examples {
+        let parts: Vec<u8> = example.answers.iter().map(|(part, _)| *part).collect();
+        let actual = solve::<S>(&example.input, &parts)
+            .unwrap_or_else(|error| panic!("example on line {}: {error}", example.line));
+
+        for ((part, expected), actual) in example.answers.iter().zip(actual) {
+            assert_eq!(
+                &actual, expected,
+                "example on line {} part {part}",
+                example.line
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_examples_with_answers() {
+        let readme = "For example:\n\n```example part1=3 part2=6\n1\n\n2\n```\n\n```\nnot an example\n```\n```example part2=-1\n4\n```";
+
+        let examples = parse_examples(readme).unwrap();
+
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    line: 3,
+                    input: String::from("1\n\n2"),
+                    answers: vec![(1, Answer::Number(3)), (2, Answer::Number(6))],
+                },
+                Example {
+                    line: 12,
+                    input: String::from("4"),
+                    answers: vec![(2, Answer::Number(-1))],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_examples() {
+        assert_eq!(
+            parse_examples("```example part3=1\n1\n```"),
+            Err(String::from("line 1: invalid answer 'part3=1'"))
+        );
+        assert_eq!(
+            parse_examples("text\n```example part1=x\n1\n```"),
+            Err(String::from("line 2: invalid answer 'part1=x'"))
+        );
+        assert_eq!(
+            parse_examples("```example part1=1\n1"),
+            Err(String::from("line 1: unterminated example"))
+        );
+    }
+
+    use crate::{parse_lines, parse_number, ParseError};
+
+    struct LineSum;
+
+    impl Solution for LineSum {
+        type Input = Vec<i128>;
+
+        fn parse(text: &str) -> Result<Vec<i128>, ParseError> {
+            parse_lines(text, |line| parse_number(line, line))
+        }
+
+        fn part1(input: &Vec<i128>) -> Answer {
+            input.iter().sum::<i128>().into()
+        }
+    }
+
+    #[test]
+    fn checks_examples_against_solution() {
+        check_examples::<LineSum>("```example part1=6\n1\n2\n3\n```");
+    }
+
+    #[test]
+    #[should_panic(expected = "example on line 1 part 1")]
+    fn panics_on_wrong_example_answer() {
+        check_examples::<LineSum>("```example part1=7\n1\n2\n3\n```");
+    }
+}
@@ -0,0 +1,11 @@
+mod answer;
+mod benchmark;
+mod example;
+mod parse_error;
+mod solution;
+
+pub use answer::Answer;
+pub use benchmark::{benchmark, Timings};
+pub use example::{check_examples, parse_examples, Example};
+pub use parse_error::{parse_lines, parse_number, ParseError};
+pub use solution::{print_answers, solve, Solution};
@@ -0,0 +1,135 @@
+use std::{fmt::Display, str::FromStr};
+
+// line and column are 1-based, counted in characters
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    // error pointing at `part`, which should be a slice of `text`
+    pub fn at(text: &str, part: &str, message: impl Into<String>) -> ParseError {
+        let (line, column) = locate(text, part);
+
+        ParseError::new(line, column, message)
+    }
+}
+
+impl ParseError {
+    // moves an error found while parsing `part` to its position within the surrounding `text`
+    pub fn within(self, text: &str, part: &str) -> ParseError {
+        let (line, column) = locate(text, part);
+
+        match self.line {
+            1 => ParseError::new(line, column + self.column - 1, self.message),
+            _ => ParseError::new(line + self.line - 1, self.column, self.message),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn locate(text: &str, part: &str) -> (usize, usize) {
+    let offset = (part.as_ptr() as usize)
+        .checked_sub(text.as_ptr() as usize)
+        .filter(|offset| offset <= &text.len());
+
+    let Some(before) = offset.and_then(|offset| text.get(..offset)) else {
+        return (1, 1);
+    };
+
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .chars()
+        .rev()
+        .take_while(|symbol| symbol != &'\n')
+        .count()
+        + 1;
+
+    (line, column)
+}
+
+pub fn parse_lines<T>(
+    text: &str,
+    mut parse_line: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    text.lines()
+        .map(|line| parse_line(line).map_err(|error| error.within(text, line)))
+        .collect()
+}
+
+// parses `part`, a slice of `text`, ignoring surrounding whitespace
+pub fn parse_number<T: FromStr>(text: &str, part: &str) -> Result<T, ParseError> {
+    let number = part.trim();
+
+    number
+        .parse()
+        .map_err(|_| ParseError::at(text, number, format!("invalid number '{number}'")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_part_within_text() {
+        let text = "first line\nsecond line";
+        let part = &text[18..22];
+
+        assert_eq!(
+            ParseError::at(text, part, "bad"),
+            ParseError::new(2, 8, "bad")
+        );
+        assert_eq!(
+            ParseError::at(text, "elsewhere", "bad"),
+            ParseError::new(1, 1, "bad")
+        );
+    }
+
+    #[test]
+    fn moves_error_into_surrounding_text() {
+        let text = "a: 1\nb: 2, x";
+        let line = text.lines().nth(1).unwrap();
+
+        let error = ParseError::new(1, 7, "bad").within(text, line);
+        assert_eq!(error, ParseError::new(2, 7, "bad"));
+
+        let section = &text[5..];
+        let error = ParseError::new(2, 3, "bad").within(text, section);
+        assert_eq!(error, ParseError::new(3, 3, "bad"));
+    }
+
+    #[test]
+    fn reports_line_of_invalid_number() {
+        let result = parse_lines("1\n2\nthree", |line| parse_number::<u32>(line, line));
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "line 3, column 1: invalid number 'three'"
+        );
+        assert_eq!(
+            parse_lines("1\n 2", |line| parse_number::<u32>(line, line)),
+            Ok(vec![1, 2])
+        );
+    }
+}
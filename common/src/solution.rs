@@ -0,0 +1,65 @@
+use crate::{Answer, ParseError};
+
+pub trait Solution {
+    type Input;
+
+    fn parse(text: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+pub fn solve<S: Solution>(text: &str, parts: &[u8]) -> Result<Vec<Answer>, ParseError> {
+    let input = S::parse(text)?;
+
+    let answers = parts
+        .iter()
+        .map(|part| match part {
+            1 => S::part1(&input),
+            2 => S::part2(&input),
+            _ => Answer::Unsolved,
+        })
+        .collect();
+
+    Ok(answers)
+}
+
+pub fn print_answers<S: Solution>(text: &str) {
+    let answers = match solve::<S>(text, &[1, 2]) {
+        Ok(answers) => answers,
+        Err(error) => return eprintln!("error: input.txt: {error}"),
+    };
+
+    for (index, answer) in answers.iter().enumerate() {
+        println!("part {}: {answer}", index + 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct LineCount;
+
+    impl Solution for LineCount {
+        type Input = Vec<String>;
+
+        fn parse(text: &str) -> Result<Vec<String>, ParseError> {
+            Ok(text.lines().map(String::from).collect())
+        }
+
+        fn part1(input: &Vec<String>) -> Answer {
+            input.len().into()
+        }
+    }
+
+    #[test]
+    fn solves_requested_parts_from_one_parse() {
+        let answers = solve::<LineCount>("a\nb\nc", &[1, 2]);
+
+        assert_eq!(answers, Ok(vec![Answer::Number(3), Answer::Unsolved]));
+    }
+}
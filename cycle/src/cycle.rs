@@ -0,0 +1,125 @@
+// a sequence of states that repeats forever once `start` (mu) steps have been taken,
+// with a period of `length` (lambda) steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    pub fn new(start: usize, length: usize) -> Cycle {
+        Cycle { start, length }
+    }
+}
+
+impl Cycle {
+    // returns the earliest step that produces the same state as `step`
+    pub fn project(&self, step: usize) -> usize {
+        match step < self.start {
+            true => step,
+            false => self.start + (step - self.start) % self.length,
+        }
+    }
+}
+
+// both searches only return once a cycle is found, so the sequence must eventually repeat
+
+// https://en.wikipedia.org/wiki/Cycle_detection#Floyd's_tortoise_and_hare
+pub fn floyd<T: Eq + Clone>(initial: T, next: impl Fn(&T) -> T) -> Cycle {
+    let mut tortoise = next(&initial);
+    let mut hare = next(&tortoise);
+
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&next(&hare));
+    }
+
+    let mut start = 0;
+    tortoise = initial;
+
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = next(&tortoise);
+
+    while tortoise != hare {
+        hare = next(&hare);
+        length += 1;
+    }
+
+    Cycle::new(start, length)
+}
+
+// https://en.wikipedia.org/wiki/Cycle_detection#Brent's_algorithm
+pub fn brent<T: Eq + Clone>(initial: T, next: impl Fn(&T) -> T) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = next(&initial);
+
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+
+        hare = next(&hare);
+        length += 1;
+    }
+
+    let mut start = 0;
+    tortoise = initial.clone();
+    hare = initial;
+
+    for _ in 0..length {
+        hare = next(&hare);
+    }
+
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        start += 1;
+    }
+
+    Cycle::new(start, length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 2
+    fn next(value: &u32) -> u32 {
+        match value {
+            6 => 2,
+            _ => value + 1,
+        }
+    }
+
+    #[test]
+    fn finds_cycle_with_floyd() {
+        assert_eq!(floyd(0, next), Cycle::new(2, 5));
+        assert_eq!(floyd(4, next), Cycle::new(0, 5));
+    }
+
+    #[test]
+    fn finds_cycle_with_brent() {
+        assert_eq!(brent(0, next), Cycle::new(2, 5));
+        assert_eq!(brent(4, next), Cycle::new(0, 5));
+    }
+
+    #[test]
+    fn projects_steps_into_cycle() {
+        let cycle = Cycle::new(2, 5);
+
+        assert_eq!(cycle.project(1), 1);
+        assert_eq!(cycle.project(6), 6);
+        assert_eq!(cycle.project(7), 2);
+        assert_eq!(cycle.project(1_000_000_000), 5);
+    }
+}
@@ -0,0 +1,89 @@
+use crate::Cycle;
+use std::{collections::HashMap, hash::Hash};
+
+// remembers every observed state so that loops with side effects can stop at the first repeat
+#[derive(Debug)]
+pub struct CycleDetector<T> {
+    seen: HashMap<T, usize>,
+}
+
+impl<T: Hash + Eq> CycleDetector<T> {
+    pub fn new() -> CycleDetector<T> {
+        CycleDetector {
+            seen: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Hash + Eq> Default for CycleDetector<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Hash + Eq> CycleDetector<T> {
+    // states must be observed in order, starting with step 0
+    pub fn observe(&mut self, state: T) -> Option<Cycle> {
+        let step = self.seen.len();
+
+        match self.seen.get(&state) {
+            Some(start) => Some(Cycle::new(*start, step - start)),
+            None => {
+                self.seen.insert(state, step);
+                None
+            }
+        }
+    }
+}
+
+pub fn state_after<T: Hash + Eq + Clone>(initial: T, steps: usize, next: impl Fn(&T) -> T) -> T {
+    let mut detector = CycleDetector::new();
+    let mut history = vec![];
+    let mut state = initial;
+
+    for _ in 0..steps {
+        if let Some(cycle) = detector.observe(state.clone()) {
+            return history.swap_remove(cycle.project(steps));
+        }
+
+        let following = next(&state);
+        history.push(state);
+        state = following;
+    }
+
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::floyd;
+
+    #[test]
+    fn detects_first_repeated_state() {
+        let mut detector = CycleDetector::new();
+
+        for state in [0, 1, 2, 3, 4] {
+            assert_eq!(detector.observe(state), None);
+        }
+
+        assert_eq!(detector.observe(2), Some(Cycle::new(2, 3)));
+    }
+
+    #[test]
+    fn projects_state_after_many_steps() {
+        let next = |value: &u32| (value * value + 1) % 255;
+
+        let mut expected = 3;
+        for _ in 0..1000 {
+            expected = next(&expected);
+        }
+
+        assert_eq!(state_after(3, 1000, next), expected);
+
+        let steps = 1_000_000_000_000;
+        let projected = floyd(3, next).project(steps);
+        assert_eq!(state_after(3, steps, next), state_after(3, projected, next));
+        assert_eq!(state_after(3, 0, next), 3);
+    }
+}
@@ -0,0 +1,5 @@
+mod cycle;
+mod detector;
+
+pub use cycle::{brent, floyd, Cycle};
+pub use detector::{state_after, CycleDetector};
@@ -0,0 +1,220 @@
+use common::ParseError;
+use std::{collections::BTreeMap, fs::read_to_string};
+
+const BUILTIN_LEXICONS: &str = include_str!("../lexicons.conf");
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DigitLexicon {
+    // spelled word and the digits it stands for, e.g. ("twenty-one", "21")
+    entries: Vec<(String, String)>,
+}
+
+impl DigitLexicon {
+    pub fn english() -> DigitLexicon {
+        DigitLexicon::builtin("english").expect("missing english lexicon")
+    }
+
+    pub fn builtin(language: &str) -> Option<DigitLexicon> {
+        parse_lexicons(BUILTIN_LEXICONS)
+            .expect("invalid built-in lexicons")
+            .remove(language)
+    }
+
+    // a built-in language, or else a lexicon file whose languages are combined in name order
+    pub fn load(name_or_path: &str) -> Result<DigitLexicon, String> {
+        if let Some(lexicon) = DigitLexicon::builtin(name_or_path) {
+            return Ok(lexicon);
+        }
+
+        let text = read_to_string(name_or_path).map_err(|error| {
+            format!("'{name_or_path}' is neither a built-in lexicon nor a readable file: {error}")
+        })?;
+        let lexicons = parse_lexicons(&text).map_err(|error| format!("{name_or_path}: {error}"))?;
+
+        Ok(DigitLexicon::combined(lexicons.values()))
+    }
+
+    // all words of the given lexicons; the first lexicon wins when a word is spelled the same
+    pub fn combined<'a>(lexicons: impl IntoIterator<Item = &'a DigitLexicon>) -> DigitLexicon {
+        let mut combined = DigitLexicon::default();
+
+        for (word, digits) in lexicons.into_iter().flat_map(DigitLexicon::entries) {
+            combined.insert(word, digits);
+        }
+
+        combined
+    }
+}
+
+impl DigitLexicon {
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries
+            .iter()
+            .map(|(word, digits)| (word.as_str(), digits.as_str()))
+    }
+
+    pub fn get(&self, word: &str) -> Option<&str> {
+        self.entries()
+            .find(|(entry, _)| entry == &word)
+            .map(|(_, digits)| digits)
+    }
+
+    // returns false if the word was already present
+    pub fn insert(&mut self, word: &str, digits: &str) -> bool {
+        if self.get(word).is_some() {
+            return false;
+        }
+
+        self.entries.push((word.to_string(), digits.to_string()));
+        true
+    }
+}
+
+pub fn parse_lexicons(text: &str) -> Result<BTreeMap<String, DigitLexicon>, ParseError> {
+    let mut lexicons: BTreeMap<String, DigitLexicon> = BTreeMap::new();
+
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (language, digits, word) = split_lexicon_line(line)
+            .ok_or_else(|| ParseError::at(text, line, "expected '<language> <digits> <word>'"))?;
+
+        if !digits.chars().all(|symbol| symbol.is_ascii_digit()) {
+            return Err(ParseError::at(
+                text,
+                digits,
+                format!("invalid digits '{digits}'"),
+            ));
+        }
+
+        let lexicon = lexicons.entry(language.to_string()).or_default();
+
+        if !lexicon.insert(word, digits) {
+            return Err(ParseError::at(
+                text,
+                word,
+                format!("duplicate word '{word}' in {language}"),
+            ));
+        }
+    }
+
+    Ok(lexicons)
+}
+
+fn split_lexicon_line(line: &str) -> Option<(&str, &str, &str)> {
+    let (language, rest) = line.split_once(char::is_whitespace)?;
+    let (digits, word) = rest.trim_start().split_once(char::is_whitespace)?;
+
+    Some((language, digits, word.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_lexicons_from_config() {
+        let text = "# language digits word\nfrench 2 deux\n\nfrench 21 vingt et un\nspanish 0 cero";
+        let lexicons = parse_lexicons(text).unwrap();
+
+        assert_eq!(lexicons.len(), 2);
+        assert_eq!(lexicons["french"].get("vingt et un"), Some("21"));
+        assert_eq!(lexicons["spanish"].get("cero"), Some("0"));
+    }
+
+    #[test]
+    fn rejects_malformed_lexicons() {
+        let error = parse_lexicons("german 2 zwei\ngerman drei").unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(2, 1, "expected '<language> <digits> <word>'")
+        );
+
+        let error = parse_lexicons("german x drei").unwrap_err();
+        assert_eq!(error, ParseError::new(1, 8, "invalid digits 'x'"));
+
+        let error = parse_lexicons("german 2 zwei\ngerman 3 zwei").unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(2, 10, "duplicate word 'zwei' in german")
+        );
+    }
+
+    #[test]
+    fn loads_builtin_languages() {
+        for language in ["english-extended", "german", "french", "spanish"] {
+            let lexicon = DigitLexicon::builtin(language).unwrap();
+            assert_eq!(lexicon.entries().count(), 100);
+        }
+
+        // the puzzle only spells out one to nine
+        let english = DigitLexicon::english();
+        assert_eq!(english.entries().count(), 9);
+        assert_eq!(english.get("zero"), None);
+        assert_eq!(english.get("twenty-one"), None);
+
+        assert_eq!(DigitLexicon::builtin("klingon"), None);
+    }
+
+    #[test]
+    fn combines_lexicons() {
+        let english = DigitLexicon::english();
+        let french = DigitLexicon::builtin("french").unwrap();
+        let combined = DigitLexicon::combined([&english, &french]);
+
+        assert_eq!(combined.get("seven"), Some("7"));
+        assert_eq!(combined.get("huit"), Some("8"));
+        assert_eq!(combined.entries().count(), 108);
+    }
+
+    #[test]
+    fn spells_every_number_below_a_hundred() {
+        for language in ["english-extended", "german", "french", "spanish"] {
+            let lexicon = DigitLexicon::builtin(language).unwrap();
+            let mut numbers: Vec<u32> = lexicon
+                .entries()
+                .map(|(_, digits)| digits.parse().unwrap())
+                .collect();
+            numbers.sort();
+
+            assert_eq!(numbers, (0..100).collect::<Vec<u32>>(), "{language}");
+        }
+    }
+
+    #[test]
+    fn spells_compound_numbers() {
+        let spelled = [
+            ("english-extended", "forty-two", "42"),
+            ("english-extended", "seventeen", "17"),
+            ("english-extended", "ninety", "90"),
+            ("german", "siebenunddreißig", "37"),
+            ("german", "einundneunzig", "91"),
+            ("german", "zwölf", "12"),
+            ("french", "soixante et onze", "71"),
+            ("french", "quatre-vingt-dix-neuf", "99"),
+            ("french", "cinquante-trois", "53"),
+            ("spanish", "veintitrés", "23"),
+            ("spanish", "ochenta y cuatro", "84"),
+            ("spanish", "dieciséis", "16"),
+        ];
+
+        for (language, word, digits) in spelled {
+            let lexicon = DigitLexicon::builtin(language).unwrap();
+            assert_eq!(lexicon.get(word), Some(digits), "{word}");
+        }
+    }
+
+    #[test]
+    fn loads_lexicons_by_name_or_path() {
+        assert_eq!(
+            DigitLexicon::load("german"),
+            Ok(DigitLexicon::builtin("german").unwrap())
+        );
+
+        let error = DigitLexicon::load("klingon").unwrap_err();
+        assert!(error.starts_with("'klingon' is neither a built-in lexicon nor a readable file"));
+    }
+}
@@ -0,0 +1,64 @@
+mod lexicon;
+mod part1;
+mod part2;
+mod scanner;
+
+use common::{Answer, ParseError, Solution};
+pub use lexicon::{parse_lexicons, DigitLexicon};
+use part1::solve_part_1;
+pub use part2::{explain_calibrations, find_calibration, solve_part_2, Calibration};
+pub use scanner::{DigitMatch, DigitScanner};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = String;
+
+    fn parse(text: &str) -> Result<String, ParseError> {
+        Ok(text.to_string())
+    }
+
+    fn part1(input: &String) -> Answer {
+        solve_part_1(input).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(input: &String) -> Answer {
+        Day1::part2_with_lexicon(input, &DigitLexicon::english())
+    }
+}
+
+impl Day1 {
+    // part 2 reading spelled digits from another lexicon than the puzzle's
+    pub fn part2_with_lexicon(input: &str, lexicon: &DigitLexicon) -> Answer {
+        solve_part_2(input, lexicon).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn solves_part_2_with_other_lexicons() {
+        let german = DigitLexicon::builtin("german").unwrap();
+
+        assert_eq!(
+            Day1::part2_with_lexicon("zwei3\nnullacht", &german),
+            Answer::Number(23 + 8)
+        );
+    }
+
+    #[test]
+    fn leaves_lines_without_digits_unsolved() {
+        let input = String::from("a1b2\nxyz");
+
+        assert_eq!(Day1::part1(&input), Answer::Unsolved);
+        assert_eq!(Day1::part2(&input), Answer::Unsolved);
+    }
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day1>(include_str!("../readme.md"));
+    }
+}
@@ -1,12 +1,62 @@
-mod part1;
-mod part2;
+use common::{print_answers, Solution};
+use day1::{explain_calibrations, Day1, DigitLexicon};
+use std::{env, fs::read_to_string, process::ExitCode};
 
-use part1::solve_part_1;
-use part2::solve_part_2;
+const USAGE: &str = "usage: day1 [--lexicon <name|path>] [--explain]";
 
-fn main() {
-    let file_path = "input.txt";
+struct Options {
+    // a built-in language or a lexicon file, replacing english in part 2
+    lexicon: Option<DigitLexicon>,
+    // shows the tokens behind every part 2 calibration value
+    explain: bool,
+}
+
+fn parse_options(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        lexicon: None,
+        explain: false,
+    };
+
+    let mut args = args.iter().map(String::as_str);
+
+    while let Some(argument) = args.next() {
+        match argument {
+            "--lexicon" => {
+                let value = args.next().ok_or("missing value for '--lexicon'")?;
+                options.lexicon = Some(DigitLexicon::load(value)?);
+            }
+            "--explain" => options.explain = true,
+            _ => return Err(format!("unexpected argument '{argument}'")),
+        }
+    }
+
+    Ok(options)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let options = match parse_options(&args) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("error: {error}\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let text = read_to_string("input.txt").unwrap();
+
+    match (options.explain, options.lexicon) {
+        (true, lexicon) => {
+            let lexicon = lexicon.unwrap_or_else(DigitLexicon::english);
+            print!("{}", explain_calibrations(&text, &lexicon));
+        }
+        (false, None) => print_answers::<Day1>(&text),
+        (false, Some(lexicon)) => {
+            println!("part 1: {}", Day1::part1(&text));
+            println!("part 2: {}", Day1::part2_with_lexicon(&text, &lexicon));
+        }
+    }
 
-    solve_part_1(file_path);
-    solve_part_2(file_path);
+    ExitCode::SUCCESS
 }
@@ -1,142 +1,103 @@
-use std::{collections::HashMap, fs::read_to_string};
-
-type DigitResult = (usize, char);
-
-pub fn solve_part_2(file_path: &str) {
-    let file = read_to_string(file_path).unwrap();
-
-    let sum = file.lines().fold(0, |previous, next| {
-        let number = find_calibration_number(next);
-
-        previous + number
-    });
-
-    assert_eq!(sum, 54277);
-}
-
-fn find_calibration_number(input: &str) -> u32 {
-    let (first_digit, last_digit) = find_calibration_digits(input);
-
-    concat_chars_to_number(first_digit, last_digit)
+use crate::{
+    lexicon::DigitLexicon,
+    scanner::{DigitMatch, DigitScanner},
+};
+use std::{cmp::Reverse, fmt::Display};
+
+// none when a line has no digits to calibrate with
+pub fn solve_part_2(text: &str, lexicon: &DigitLexicon) -> Option<u32> {
+    let scanner = DigitScanner::new(lexicon);
+
+    text.lines()
+        .map(|line| find_calibration_number(line, &scanner))
+        .sum()
 }
 
-fn concat_chars_to_number(first: char, last: char) -> u32 {
-    let mut number = String::from(first);
-    number.push(last);
-
-    number.parse::<u32>().expect("invalid number")
+fn find_calibration_number(input: &str, scanner: &DigitScanner) -> Option<u32> {
+    find_calibration(input, scanner).map(|calibration| calibration.value())
 }
 
-fn find_calibration_digits(input: &str) -> (char, char) {
-    let first_digit = find_digit_left(input);
-    let last_digit = find_digit_right(input);
-
-    (first_digit, last_digit)
+#[derive(Debug)]
+pub struct Calibration<'a> {
+    pub first: DigitMatch<'a>,
+    pub last: DigitMatch<'a>,
+    pub matches: Vec<DigitMatch<'a>>,
 }
 
-fn find_digit_left(input: &str) -> char {
-    let (numerical_index, numerical_value) = find_numerical_digit_left(input);
-    let (spelled_index, spelled_value) = find_spelled_digit_left(input);
+impl<'a> Calibration<'a> {
+    pub fn value(&self) -> u32 {
+        let first = self.first.first_digit().unwrap_or('0');
+        let last = self.last.last_digit().unwrap_or('0');
 
-    match numerical_index <= spelled_index {
-        true => numerical_value,
-        false => spelled_value,
+        concat_chars_to_number(first, last)
     }
 }
 
-fn find_digit_right(input: &str) -> char {
-    let (numerical_index, numerical_value) = find_numerical_digit_right(input);
-    let (spelled_index, spelled_value) = find_spelled_digit_right(input);
-
-    match numerical_index <= spelled_index {
-        true => numerical_value,
-        false => spelled_value,
+impl<'a> Display for Calibration<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let tokens: Vec<String> = self
+            .matches
+            .iter()
+            .map(|found| format!("{}@{}", found.token, found.offset))
+            .collect();
+
+        write!(
+            f,
+            "{} from '{}' at {} and '{}' at {} (tokens: {})",
+            self.value(),
+            self.first.token,
+            self.first.offset,
+            self.last.token,
+            self.last.offset,
+            tokens.join(" ")
+        )
     }
 }
 
-fn find_numerical_digit_left(input: &str) -> DigitResult {
-    let result = input
-        .chars()
-        .enumerate()
-        .find(|(_, character)| character.is_ascii_digit());
+fn concat_chars_to_number(first: char, last: char) -> u32 {
+    let mut number = String::from(first);
+    number.push(last);
 
-    let result = result.unwrap_or((input.len(), '\0'));
-    (result.0, result.1)
+    number.parse::<u32>().expect("invalid number")
 }
 
-fn find_numerical_digit_right(input: &str) -> DigitResult {
-    let as_vec: Vec<char> = input.chars().collect();
-
-    let result = as_vec
-        .iter()
-        .enumerate()
-        .rfind(|(_, character)| character.is_ascii_digit());
+pub fn find_calibration<'a>(input: &'a str, scanner: &'a DigitScanner) -> Option<Calibration<'a>> {
+    let mut matches: Vec<DigitMatch> = scanner.scan(input).collect();
+    matches.sort_by_key(|found| (found.offset, Reverse(found.token.len())));
 
-    let result = result.unwrap_or((0, &'\0'));
-    (input.len() - result.0, result.1.to_owned())
+    Some(Calibration {
+        first: find_digit_left(&matches)?,
+        last: find_digit_right(&matches)?,
+        matches,
+    })
 }
 
-fn get_spelled_digits() -> HashMap<&'static str, char> {
-    HashMap::from([
-        ("one", '1'),
-        ("two", '2'),
-        ("three", '3'),
-        ("four", '4'),
-        ("five", '5'),
-        ("six", '6'),
-        ("seven", '7'),
-        ("eight", '8'),
-        ("nine", '9'),
-    ])
+// of the tokens starting at the same offset, the longest is used, so "twenty-one" beats "twenty"
+fn find_digit_left<'a>(matches: &[DigitMatch<'a>]) -> Option<DigitMatch<'a>> {
+    matches
+        .iter()
+        .min_by_key(|found| (found.offset, Reverse(found.token.len())))
+        .copied()
 }
 
-fn find_spelled_digit_left(input: &str) -> DigitResult {
-    let spelled_digits = get_spelled_digits();
-
-    let mut result = (input.len(), '\0');
-
-    let character_string: String = input.to_owned();
-
-    for (spelling, character) in spelled_digits.iter() {
-        let found = character_string.find(spelling);
-
-        if found.is_none() {
-            continue;
-        }
-
-        let found_index = found.unwrap();
-
-        if found_index < result.0 {
-            result = (found_index, character.to_owned());
-        }
-    }
-
-    result
+fn find_digit_right<'a>(matches: &[DigitMatch<'a>]) -> Option<DigitMatch<'a>> {
+    matches
+        .iter()
+        .max_by_key(|found| (found.offset, found.token.len()))
+        .copied()
 }
 
-fn find_spelled_digit_right(input: &str) -> DigitResult {
-    let spelled_digits = get_spelled_digits();
-
-    let length = input.len();
-    let mut result = (length, '\0');
-
-    let character_string: String = input.to_owned();
-
-    for (spelling, character) in spelled_digits.iter() {
-        let found = character_string.rfind(spelling);
+// one line per calibration value, naming the tokens it was made from
+pub fn explain_calibrations(text: &str, lexicon: &DigitLexicon) -> String {
+    let scanner = DigitScanner::new(lexicon);
 
-        if found.is_none() {
-            continue;
-        }
-
-        let found_index = length - found.unwrap();
-
-        if found_index < result.0 {
-            result = (found_index, character.to_owned());
-        }
-    }
-
-    result
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| match find_calibration(line, &scanner) {
+            Some(calibration) => format!("{}: {line} -> {calibration}\n", index + 1),
+            None => format!("{}: {line} -> no digits\n", index + 1),
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -145,32 +106,96 @@ mod test_find_digit {
 
     #[test]
     fn find_digits_left_works() {
-        let input = "19qdlpmdrxone7sevennine";
+        let scanner = DigitScanner::new(&DigitLexicon::english());
+        let calibration = find_calibration("19qdlpmdrxone7sevennine", &scanner).unwrap();
 
-        assert_eq!(find_digit_left(input), '1');
-        assert_eq!(find_digit_right(input), '9');
+        assert_eq!(calibration.first.token, "1");
+        assert_eq!(calibration.last.token, "nine");
     }
 
     #[test]
     fn find_calibration_number_works() {
-        assert_eq!(find_calibration_number("19qdlpmdrxone7sevennine"), 19);
+        let scanner = DigitScanner::new(&DigitLexicon::english());
+
+        assert_eq!(
+            find_calibration_number("19qdlpmdrxone7sevennine", &scanner),
+            Some(19)
+        );
 
-        assert_eq!(find_calibration_number("joneseven2sseven64chvczzn"), 14);
+        assert_eq!(
+            find_calibration_number("joneseven2sseven64chvczzn", &scanner),
+            Some(14)
+        );
 
-        assert_eq!(find_calibration_number("seven82683"), 73);
+        assert_eq!(find_calibration_number("seven82683", &scanner), Some(73));
 
-        assert_eq!(find_calibration_number("two1nine"), 29);
+        assert_eq!(find_calibration_number("two1nine", &scanner), Some(29));
 
-        assert_eq!(find_calibration_number("eightwothree"), 83);
+        assert_eq!(find_calibration_number("eightwothree", &scanner), Some(83));
 
-        assert_eq!(find_calibration_number("abcone2threexyz"), 13);
+        assert_eq!(
+            find_calibration_number("abcone2threexyz", &scanner),
+            Some(13)
+        );
 
-        assert_eq!(find_calibration_number("xtwone3four"), 24);
+        assert_eq!(find_calibration_number("xtwone3four", &scanner), Some(24));
 
-        assert_eq!(find_calibration_number("4nineeightseven2"), 42);
+        assert_eq!(
+            find_calibration_number("4nineeightseven2", &scanner),
+            Some(42)
+        );
 
-        assert_eq!(find_calibration_number("zoneight234"), 14);
+        assert_eq!(find_calibration_number("zoneight234", &scanner), Some(14));
 
-        assert_eq!(find_calibration_number("7pqrstsixteen"), 76);
+        assert_eq!(find_calibration_number("7pqrstsixteen", &scanner), Some(76));
+    }
+
+    #[test]
+    fn finds_digits_in_other_languages() {
+        let german = DigitScanner::new(&DigitLexicon::builtin("german").unwrap());
+        assert_eq!(find_calibration_number("xzweiqfünfnull", &german), Some(20));
+
+        let french = DigitScanner::new(&DigitLexicon::builtin("french").unwrap());
+        assert_eq!(
+            find_calibration_number("vingt et unzéro", &french),
+            Some(20)
+        );
+        assert_eq!(find_calibration_number("7vingt et un", &french), Some(71));
+
+        let english = DigitScanner::new(&DigitLexicon::english());
+        assert_eq!(find_calibration_number("twenty-one", &english), Some(11));
+        assert_eq!(find_calibration_number("zero4", &english), Some(44));
+
+        let extended = DigitScanner::new(&DigitLexicon::builtin("english-extended").unwrap());
+        assert_eq!(find_calibration_number("twenty-one", &extended), Some(21));
+        assert_eq!(find_calibration_number("zero4", &extended), Some(4));
+        assert_eq!(
+            find_calibration_number("ninety-eightx", &extended),
+            Some(98)
+        );
+        assert_eq!(
+            find_calibration_number("xseventeenfifty", &extended),
+            Some(10)
+        );
+
+        let german = DigitScanner::new(&DigitLexicon::builtin("german").unwrap());
+        assert_eq!(find_calibration_number("dreiundvierzig", &german), Some(40));
+
+        let spanish = DigitScanner::new(&DigitLexicon::builtin("spanish").unwrap());
+        assert_eq!(
+            find_calibration_number("treinta y seis", &spanish),
+            Some(36)
+        );
+    }
+
+    #[test]
+    fn explains_calibration_values() {
+        let explanation = explain_calibrations("eightwothree\nabc", &DigitLexicon::english());
+
+        assert_eq!(
+            explanation,
+            "1: eightwothree -> 83 from 'eight' at 0 and 'three' at 7 (tokens: eight@0 two@4 three@7)\n\
+             2: abc -> no digits\n"
+        );
     }
 }
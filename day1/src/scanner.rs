@@ -0,0 +1,176 @@
+use crate::lexicon::DigitLexicon;
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DigitMatch<'a> {
+    // byte offset of the token within the scanned line
+    pub offset: usize,
+    pub token: &'a str,
+    pub digits: &'a str,
+}
+
+impl<'a> DigitMatch<'a> {
+    pub fn first_digit(&self) -> Option<char> {
+        self.digits.chars().next()
+    }
+
+    pub fn last_digit(&self) -> Option<char> {
+        self.digits.chars().last()
+    }
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    // next state for every input byte, following failure links where the trie has no edge
+    transitions: Vec<usize>,
+    // patterns ending in this state, longest first
+    outputs: Vec<usize>,
+}
+
+// Aho-Corasick automaton over the numerals and every word of a lexicon
+#[derive(Debug)]
+pub struct DigitScanner {
+    nodes: Vec<Node>,
+    // token and the digits it stands for
+    patterns: Vec<(String, String)>,
+}
+
+impl DigitScanner {
+    pub fn new(lexicon: &DigitLexicon) -> DigitScanner {
+        let numerals = ('0'..='9').map(|digit| (digit.to_string(), digit.to_string()));
+        let words = lexicon
+            .entries()
+            .filter(|(word, _)| !word.is_empty())
+            .map(|(word, digits)| (word.to_string(), digits.to_string()));
+
+        let patterns: Vec<(String, String)> = numerals.chain(words).collect();
+
+        let mut trie: Vec<Vec<Option<usize>>> = vec![vec![None; 256]];
+        let mut outputs: Vec<Vec<usize>> = vec![vec![]];
+
+        for (index, (token, _)) in patterns.iter().enumerate() {
+            let mut state = 0;
+
+            for byte in token.bytes() {
+                state = match trie[state][byte as usize] {
+                    Some(next) => next,
+                    None => {
+                        trie.push(vec![None; 256]);
+                        outputs.push(vec![]);
+
+                        let next = trie.len() - 1;
+                        trie[state][byte as usize] = Some(next);
+                        next
+                    }
+                };
+            }
+
+            outputs[state].push(index);
+        }
+
+        let mut nodes: Vec<Node> = (0..trie.len()).map(|_| Node::default()).collect();
+        let mut failures = vec![0; trie.len()];
+
+        // breadth first, so the failure state of every node is complete before its children
+        let mut queue = VecDeque::from([0]);
+
+        while let Some(state) = queue.pop_front() {
+            let failure = failures[state];
+
+            if state != 0 {
+                let inherited = nodes[failure].outputs.clone();
+                outputs[state].extend(inherited);
+            }
+            nodes[state].outputs = outputs[state].clone();
+
+            nodes[state].transitions = (0..256)
+                .map(|byte| match trie[state][byte] {
+                    Some(next) => {
+                        failures[next] = match state {
+                            0 => 0,
+                            _ => nodes[failure].transitions[byte],
+                        };
+                        queue.push_back(next);
+                        next
+                    }
+                    None => match state {
+                        0 => 0,
+                        _ => nodes[failure].transitions[byte],
+                    },
+                })
+                .collect();
+        }
+
+        DigitScanner { nodes, patterns }
+    }
+}
+
+impl DigitScanner {
+    // every digit in the line, overlapping ones included, ordered by where they end
+    pub fn scan<'a>(&'a self, line: &'a str) -> impl Iterator<Item = DigitMatch<'a>> + 'a {
+        let mut state = 0;
+
+        line.bytes().enumerate().flat_map(move |(index, byte)| {
+            state = self.nodes[state].transitions[byte as usize];
+
+            self.nodes[state].outputs.iter().map(move |pattern| {
+                let (token, digits) = &self.patterns[*pattern];
+                let offset = index + 1 - token.len();
+
+                DigitMatch {
+                    offset,
+                    token: &line[offset..=index],
+                    digits,
+                }
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(scanner: &DigitScanner, line: &str) -> Vec<(usize, String)> {
+        scanner
+            .scan(line)
+            .map(|found| (found.offset, found.token.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn finds_overlapping_digits() {
+        let scanner = DigitScanner::new(&DigitLexicon::english());
+
+        assert_eq!(
+            tokens(&scanner, "eightwo3"),
+            vec![
+                (0, "eight".to_string()),
+                (4, "two".to_string()),
+                (7, "3".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_words_within_longer_words() {
+        let scanner = DigitScanner::new(&DigitLexicon::builtin("english-extended").unwrap());
+        let found: Vec<DigitMatch> = scanner.scan("xtwenty-one").collect();
+
+        assert_eq!(found.len(), 3);
+        assert_eq!(found[0].token, "twenty");
+        assert_eq!(found[1].token, "twenty-one");
+        assert_eq!(found[2].token, "one");
+        assert_eq!(found[2].offset, 8);
+    }
+
+    #[test]
+    fn reports_byte_offsets_for_multibyte_words() {
+        let scanner = DigitScanner::new(&DigitLexicon::builtin("german").unwrap());
+        let found: Vec<DigitMatch> = scanner.scan("über fünf").collect();
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].offset, 6);
+        assert_eq!(found[0].digits, "5");
+    }
+}
@@ -1,16 +1,14 @@
 use crate::model::{PipeLoop, PipeMap, Position};
 
-pub fn find_enclosed_tiles_in_map(input: &str) -> Vec<Position> {
-    let map = PipeMap::from_text(input);
-
-    let pipe_loop = PipeLoop::from_map(&map);
-    let empty_tiles = find_empty_tiles(&map, &pipe_loop);
+pub fn find_enclosed_tiles_in_map(map: &PipeMap) -> Vec<Position> {
+    let pipe_loop = PipeLoop::from_map(map);
+    let empty_tiles = find_empty_tiles(map, &pipe_loop);
 
     find_enclosed_tiles(&empty_tiles, &pipe_loop)
 }
 
 fn find_empty_tiles(map: &PipeMap, pipe_loop: &PipeLoop) -> Vec<Position> {
-    let (x_max, y_max) = map.size;
+    let (x_max, y_max) = map.size();
 
     let mut result = vec![];
 
@@ -28,11 +26,11 @@ fn find_empty_tiles(map: &PipeMap, pipe_loop: &PipeLoop) -> Vec<Position> {
     result
 }
 
-fn find_enclosed_tiles(empty_tiles: &Vec<Position>, pipe_loop: &PipeLoop) -> Vec<Position> {
+fn find_enclosed_tiles(empty_tiles: &[Position], pipe_loop: &PipeLoop) -> Vec<Position> {
     empty_tiles
         .iter()
         .filter(|tile_position| is_tile_enclosed(tile_position, pipe_loop))
-        .map(|position| position.clone())
+        .copied()
         .collect()
 }
 
@@ -64,6 +62,7 @@ fn is_tile_enclosed_by_pipes(pipes: Vec<char>, excluded_symbol: char) -> bool {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::needless_borrow)]
 mod tests {
     use super::*;
 
@@ -71,7 +70,7 @@ mod tests {
     fn determines_if_tile_enclosed() {
         let input = "...........\n.S-------7.\n.|F-----7|.\n.||.....||.\n.||.....||.\n.|L-7.F-J|.\n.|..|.|..|.\n.L--J.L--J.\n...........";
 
-        let map = PipeMap::from_text(&input);
+        let map = PipeMap::from_text(&input).unwrap();
         let pipe_loop = PipeLoop::from_map(&map);
 
         assert_eq!(is_tile_enclosed(&(0, 1), &pipe_loop), false);
@@ -89,7 +88,7 @@ mod tests {
     fn finds_empty_tiles() {
         let input = "FF7FSF7F7F7F7F7F---7\nL|LJ||||||||||||F--J\nFL-7LJLJ||||||LJL-77\nF--JF--7||LJLJ7F7FJ-\nL---JF-JLJ.||-FJLJJ7\n|F|F-JF---7F7-L7L|7|\n|FFJF7L7F-JF7|JL---7\n7-L-JL7||F7|L7F-7F7|\nL.L7LFJ|||||FJL7||LJ\nL7JLJL-JLJLJL--JLJ.L";
 
-        let map = PipeMap::from_text(&input);
+        let map = PipeMap::from_text(&input).unwrap();
         let pipe_loop = PipeLoop::from_map(&map);
 
         assert_eq!(find_empty_tiles(&map, &pipe_loop).len(), 40);
@@ -99,7 +98,7 @@ mod tests {
     fn finds_enclosed_tiles_small_map() {
         let input = "...........\n.S-------7.\n.|F-----7|.\n.||.....||.\n.||.....||.\n.|L-7.F-J|.\n.|..|.|..|.\n.L--J.L--J.\n...........";
 
-        let tiles = find_enclosed_tiles_in_map(&input);
+        let tiles = find_enclosed_tiles_in_map(&PipeMap::from_text(&input).unwrap());
         assert_eq!(tiles, vec![(2, 6), (3, 6), (7, 6), (8, 6)]);
     }
 
@@ -107,9 +106,12 @@ mod tests {
     fn finds_enclosed_tiles_large_map() {
         let input = ".F----7F7F7F7F-7....\n.|F--7||||||||FJ....\n.||.FJ||||||||L7....\nFJL7L7LJLJ||LJ.L-7..\nL--J.L7...LJS7F-7L7.\n....F-J..F7FJ|L7L7L7\n....L7.F7||L7|.L7L7|\n.....|FJLJ|FJ|F7|.LJ\n....FJL-7.||.||||...\n....L---J.LJ.LJLJ...";
 
-        assert_eq!(find_enclosed_tiles_in_map(input).len(), 8);
+        assert_eq!(
+            find_enclosed_tiles_in_map(&PipeMap::from_text(input).unwrap()).len(),
+            8
+        );
 
-        let map = PipeMap::from_text(input);
+        let map = PipeMap::from_text(input).unwrap();
         let pipe_loop = PipeLoop::from_map(&map);
 
         pipe_loop.draw_in_map(&map, vec![]);
@@ -135,9 +137,12 @@ mod tests {
     fn finds_enclosed_tiles_larger_map() {
         let input = "FF7FSF7F7F7F7F7F---7\nL|LJ||||||||||||F--J\nFL-7LJLJ||||||LJL-77\nF--JF--7||LJLJ7F7FJ-\nL---JF-JLJ.||-FJLJJ7\n|F|F-JF---7F7-L7L|7|\n|FFJF7L7F-JF7|JL---7\n7-L-JL7||F7|L7F-7F7|\nL.L7LFJ|||||FJL7||LJ\nL7JLJL-JLJLJL--JLJ.L";
 
-        assert_eq!(find_enclosed_tiles_in_map(input).len(), 10);
+        assert_eq!(
+            find_enclosed_tiles_in_map(&PipeMap::from_text(input).unwrap()).len(),
+            10
+        );
 
-        let map = PipeMap::from_text(input);
+        let map = PipeMap::from_text(input).unwrap();
         let pipe_loop = PipeLoop::from_map(&map);
 
         let locations = vec![(10, 4), (11, 5), (13, 6)];
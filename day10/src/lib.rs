@@ -0,0 +1,56 @@
+mod enclosed_tiles;
+mod model;
+
+use common::{Answer, ParseError, Solution};
+use enclosed_tiles::find_enclosed_tiles_in_map;
+use model::{PipeLoop, PipeMap};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = PipeMap;
+
+    fn parse(text: &str) -> Result<PipeMap, ParseError> {
+        PipeMap::from_text(text)
+    }
+
+    fn part1(input: &PipeMap) -> Answer {
+        find_farthest_connected_pipe(input).into()
+    }
+
+    fn part2(input: &PipeMap) -> Answer {
+        find_enclosed_tiles_in_map(input).len().into()
+    }
+}
+
+pub fn find_farthest_connected_pipe(map: &PipeMap) -> u32 {
+    let pipe_loop = PipeLoop::from_map(map);
+
+    ((pipe_loop.count_pipes() as f32) / 2.0).round() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn finds_steps_to_furthest_pipe_in_loop() {
+        let input = ".....\n.S-7.\n.|.|.\n.L-J.\n.....";
+        assert_eq!(
+            find_farthest_connected_pipe(&PipeMap::from_text(input).unwrap()),
+            4
+        );
+
+        let input = "..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...";
+        assert_eq!(
+            find_farthest_connected_pipe(&PipeMap::from_text(input).unwrap()),
+            8
+        );
+    }
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day10>(include_str!("../readme.md"));
+    }
+}
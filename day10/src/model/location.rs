@@ -1 +1 @@
-pub type Position = (usize, usize);
+pub use grid::Position;
@@ -1,4 +1,5 @@
 use super::Position;
+use grid::Direction;
 
 pub struct SurroundingTiles<'a> {
     pub left: Option<&'a Pipe>,
@@ -34,54 +35,36 @@ impl Pipe {
 
 impl Pipe {
     pub fn connects_left(&self) -> bool {
-        match self.symbol {
-            '-' | '7' | 'J' => true,
-            _ => false,
-        }
+        matches!(self.symbol, '-' | '7' | 'J')
     }
 
     pub fn connects_right(&self) -> bool {
-        match self.symbol {
-            '-' | 'L' | 'F' => true,
-            _ => false,
-        }
+        matches!(self.symbol, '-' | 'L' | 'F')
     }
 
     pub fn connects_up(&self) -> bool {
-        match self.symbol {
-            '|' | 'L' | 'J' => true,
-            _ => false,
-        }
+        matches!(self.symbol, '|' | 'L' | 'J')
     }
 
     pub fn connects_down(&self) -> bool {
-        match self.symbol {
-            '|' | '7' | 'F' => true,
-            _ => false,
-        }
+        matches!(self.symbol, '|' | '7' | 'F')
     }
 
     pub fn get_connectors(&self) -> Vec<Position> {
-        let mut connectors: Vec<Position> = vec![];
-        let (x, y) = self.position;
-
-        if self.connects_left() {
-            connectors.push((x - 1, y));
-        }
-
-        if self.connects_right() {
-            connectors.push((x + 1, y));
-        }
-
-        if self.connects_up() {
-            connectors.push((x, y - 1));
-        }
-
-        if self.connects_down() {
-            connectors.push((x, y + 1));
-        }
-
-        connectors
+        let connections = [
+            (self.connects_left(), Direction::W),
+            (self.connects_right(), Direction::E),
+            (self.connects_up(), Direction::N),
+            (self.connects_down(), Direction::S),
+        ];
+
+        connections
+            .iter()
+            .filter_map(|(connects, direction)| match connects {
+                true => direction.step(&self.position),
+                false => None,
+            })
+            .collect()
     }
 }
 
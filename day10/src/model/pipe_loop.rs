@@ -70,9 +70,9 @@ impl PipeLoop {
 
     #[cfg(test)]
     pub fn draw_in_map(&self, map: &PipeMap, marks: Vec<Position>) {
-        let (x_max, y_max) = map.size;
+        let (x_max, y_max) = map.size();
 
-        println!("");
+        println!();
         for y in 0..y_max {
             for x in 0..x_max {
                 let pipe = self.get(&(x, y));
@@ -87,9 +87,9 @@ impl PipeLoop {
                     Some(pipe) => print!("{}", pipe.symbol),
                 }
             }
-            println!("");
+            println!();
         }
-        println!("");
+        println!();
     }
 }
 
@@ -97,14 +97,14 @@ fn find_connecting_pipe(pipe: &Pipe, previous: Option<Position>) -> Position {
     let connectors = pipe.get_connectors();
 
     if previous.is_none() {
-        return *connectors.get(0).unwrap();
+        return *connectors.first().unwrap();
     }
 
     *connectors
         .into_iter()
         .filter(|position| position != &previous.unwrap())
         .collect::<Vec<Position>>()
-        .get(0)
+        .first()
         .unwrap()
 }
 
@@ -117,7 +117,7 @@ mod tests {
     fn constructs_pipe_loop() {
         let input = ".....\n.S-7.\n.|.|.\n.L-J.\n.....";
 
-        let map = PipeMap::from_text(&input);
+        let map = PipeMap::from_text(input).unwrap();
         let pipe_loop = PipeLoop::from_map(&map);
 
         pipe_loop.draw_in_map(&map, vec![]);
@@ -129,7 +129,7 @@ mod tests {
     fn constructs_bigger_pipe_loop() {
         let input = "...........\n.S-------7.\n.|F-----7|.\n.||.....||.\n.||.....||.\n.|L-7.F-J|.\n.|..|.|..|.\n.L--J.L--J.\n...........";
 
-        let map = PipeMap::from_text(&input);
+        let map = PipeMap::from_text(input).unwrap();
         let pipe_loop = PipeLoop::from_map(&map);
 
         assert_eq!(&pipe_loop.count_pipes(), &46);
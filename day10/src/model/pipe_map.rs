@@ -1,67 +1,47 @@
-use std::collections::HashMap;
-
 use crate::model::{Pipe, Position, SurroundingTiles};
+use common::ParseError;
+use grid::{Direction, Grid};
 
-type PipeTiles = HashMap<Position, Pipe>;
-
+#[derive(Debug)]
 pub struct PipeMap {
     pub start: Position,
-    tiles: PipeTiles,
-
-    pub size: (usize, usize),
+    tiles: Grid<Option<Pipe>>,
 }
 
 impl PipeMap {
-    pub fn from_text(input: &str) -> PipeMap {
-        let mut start_x = 0;
-        let mut start_y = 0;
-
-        let size = (
-            input
-                .lines()
-                .collect::<Vec<&str>>()
-                .get(0)
-                .unwrap()
-                .chars()
-                .count(),
-            input.lines().count(),
-        );
-
-        let mut tiles = HashMap::new();
-
-        for (y, line) in input.lines().enumerate() {
-            for (x, symbol) in line.chars().enumerate() {
-                if symbol == '.' {
-                    continue;
-                }
-
-                if symbol == 'S' {
-                    start_x = x;
-                    start_y = y;
-                }
-
-                let position = (x, y);
-                tiles.insert(position, Pipe::new(position, symbol));
+    pub fn from_text(input: &str) -> Result<PipeMap, ParseError> {
+        let mut start = None;
+
+        let tiles = Grid::parse(input, |position, symbol| match symbol {
+            '.' => Some(None),
+            'S' => {
+                start = Some(position);
+                Some(Some(Pipe::new(position, symbol)))
             }
-        }
+            '|' | '-' | 'L' | 'J' | '7' | 'F' => Some(Some(Pipe::new(position, symbol))),
+            _ => None,
+        })?;
 
-        let start = (start_x, start_y);
-
-        let mut map = PipeMap { tiles, start, size };
+        let start = start.ok_or_else(|| ParseError::new(1, 1, "missing start 'S'"))?;
+        let mut map = PipeMap { tiles, start };
 
         map.set_pipe(&start, find_starting_pipe(&map, start));
 
-        map
+        Ok(map)
     }
 }
 
 impl PipeMap {
+    pub fn size(&self) -> (usize, usize) {
+        self.tiles.size()
+    }
+
     pub fn get_tile(&self, position: &Position) -> Option<&Pipe> {
-        self.tiles.get(position)
+        self.tiles.get(position)?.as_ref()
     }
 
     pub fn set_pipe(&mut self, position: &Position, pipe: Pipe) {
-        self.tiles.insert(*position, pipe);
+        self.tiles.set(position, Some(pipe));
     }
 }
 
@@ -82,20 +62,18 @@ fn find_starting_pipe(map: &PipeMap, position: Position) -> Pipe {
     )
 }
 
-fn find_surrounding_tiles(map: &PipeMap, position: Position) -> SurroundingTiles {
-    let (x, y) = position;
+fn find_surrounding_tiles(map: &PipeMap, position: Position) -> SurroundingTiles<'_> {
+    let find_tile = |direction| {
+        map.tiles
+            .step(&position, direction)
+            .and_then(|neighbor| map.get_tile(&neighbor))
+    };
 
     SurroundingTiles {
-        left: match x == 0 {
-            true => None,
-            false => map.get_tile(&(x - 1, y)),
-        },
-        right: map.get_tile(&(x + 1, y)),
-        up: match y == 0 {
-            true => None,
-            false => map.get_tile(&(x, y - 1)),
-        },
-        down: map.get_tile(&(x, y + 1)),
+        left: find_tile(Direction::W),
+        right: find_tile(Direction::E),
+        up: find_tile(Direction::N),
+        down: find_tile(Direction::S),
     }
 }
 
@@ -107,7 +85,7 @@ mod tests {
     fn constructs_map_from_text() {
         let input = ".....\n.S-7.\n.|.|.\n.L-J.\n.....";
 
-        let map = PipeMap::from_text(&input);
+        let map = PipeMap::from_text(input).unwrap();
 
         assert_eq!(map.start, (1, 1));
 
@@ -118,11 +96,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rejects_unknown_tiles_and_missing_start() {
+        let error = PipeMap::from_text(".S-7.\n.|x|.").unwrap_err();
+        assert_eq!(error, ParseError::new(2, 3, "unexpected symbol 'x'"));
+
+        let error = PipeMap::from_text(".F-7.\n.L-J.").unwrap_err();
+        assert_eq!(error.message, "missing start 'S'");
+    }
+
     #[test]
     fn finds_correct_tile_in_map() {
         let input = "...........\n.S-------7.\n.|F-----7|.\n.||.....||.\n.||.....||.\n.|L-7.F-J|.\n.|..|.|..|.\n.L--J.L--J.\n...........";
 
-        let map = PipeMap::from_text(&input);
+        let map = PipeMap::from_text(input).unwrap();
 
         assert_eq!(map.get_tile(&(2, 6)), None);
     }
@@ -0,0 +1,39 @@
+mod model;
+
+use common::{Answer, ParseError, Solution};
+use model::StarMap;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = StarMap;
+
+    fn parse(text: &str) -> Result<StarMap, ParseError> {
+        StarMap::new(text)
+    }
+
+    fn part1(input: &StarMap) -> Answer {
+        let mut star_map = input.clone();
+        star_map.expand();
+
+        star_map.sum_galaxy_pair_distances().into()
+    }
+
+    fn part2(input: &StarMap) -> Answer {
+        let mut star_map = input.clone();
+        star_map.expand_by_factor(1_000_000);
+
+        star_map.sum_galaxy_pair_distances().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day11>(include_str!("../readme.md"));
+    }
+}
@@ -1,32 +1,35 @@
 use super::position::{distance_between_positions, Position};
+use common::ParseError;
+use grid::Grid;
 
 type MapSize = Position;
 
+#[derive(Debug, Clone)]
 pub struct StarMap {
     galaxies: Vec<Position>,
     size: MapSize,
 }
 
 impl StarMap {
-    pub fn new(input: &str) -> StarMap {
-        let mut galaxies = vec![];
-
-        let mut size = (0, 0);
-
-        for (y, line) in input.lines().enumerate() {
-            size.0 = y;
+    pub fn new(input: &str) -> Result<StarMap, ParseError> {
+        let grid = Grid::parse(input, |_, symbol| match symbol {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        let galaxies = grid
+            .iter()
+            .filter(|(_, is_galaxy)| **is_galaxy)
+            .map(|(position, _)| position)
+            .collect();
 
-            for (x, char) in line.chars().enumerate() {
-                size.1 = x;
+        let size = (
+            grid.height().saturating_sub(1),
+            grid.width().saturating_sub(1),
+        );
 
-                match char {
-                    '#' => galaxies.push((x, y)),
-                    _ => {}
-                }
-            }
-        }
-
-        StarMap { galaxies, size }
+        Ok(StarMap { galaxies, size })
     }
 }
 
@@ -148,7 +151,7 @@ mod tests {
     fn expands_galaxy_row() {
         let input = "#.........\n..........\n......#...";
 
-        let mut map = StarMap::new(input);
+        let mut map = StarMap::new(input).unwrap();
 
         assert_eq!(map.galaxies, vec![(0, 0), (6, 2)]);
 
@@ -158,11 +161,18 @@ mod tests {
         assert_eq!(map.galaxies, vec![(0, 0), (7, 3)]);
     }
 
+    #[test]
+    fn rejects_unexpected_symbols() {
+        let error = StarMap::new("#...\n..*.").unwrap_err();
+
+        assert_eq!(error, ParseError::new(2, 3, "unexpected symbol '*'"));
+    }
+
     #[test]
     fn finds_galaxy_pairs() {
         let input = "......#...\n.#........\n.........#\n..........\n.......#..";
 
-        let map = StarMap::new(input);
+        let map = StarMap::new(input).unwrap();
         let pairs = map.find_galaxy_pairs();
 
         assert_eq!(pairs.len(), 6);
@@ -172,7 +182,7 @@ mod tests {
     fn finds_empty_rows_and_columns() {
         let input = "......#...\n.#........\n.........#\n..........\n.......#..";
 
-        let map = StarMap::new(input);
+        let map = StarMap::new(input).unwrap();
         let empty = map.find_empty_rows_cols();
 
         assert_eq!(empty.0, vec![3]);
@@ -182,7 +192,7 @@ mod tests {
     #[test]
     fn expands_map() {
         let input = "......#...\n.#........\n.........#\n..........\n.......#..";
-        let mut map = StarMap::new(input);
+        let mut map = StarMap::new(input).unwrap();
 
         assert_eq!(map.galaxies, vec![(6, 0), (1, 1), (9, 2), (7, 4)]);
 
@@ -194,7 +204,7 @@ mod tests {
     #[test]
     fn calculates_galaxy_pair_distances() {
         let input = "...#......\n.......#..\n#.........\n..........\n......#...\n.#........\n.........#\n..........\n.......#..\n#...#.....";
-        let mut map = StarMap::new(input);
+        let mut map = StarMap::new(input).unwrap();
 
         map.expand();
 
@@ -204,7 +214,7 @@ mod tests {
     #[test]
     fn expands_map_by_factor_10() {
         let input = "...#......\n.......#..\n#.........\n..........\n......#...\n.#........\n.........#\n..........\n.......#..\n#...#.....";
-        let mut map = StarMap::new(input);
+        let mut map = StarMap::new(input).unwrap();
 
         map.expand_by_factor(10);
 
@@ -214,7 +224,7 @@ mod tests {
     #[test]
     fn expands_map_by_factor_100() {
         let input = "...#......\n.......#..\n#.........\n..........\n......#...\n.#........\n.........#\n..........\n.......#..\n#...#.....";
-        let mut map = StarMap::new(input);
+        let mut map = StarMap::new(input).unwrap();
 
         map.expand_by_factor(100);
 
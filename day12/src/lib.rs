@@ -0,0 +1,41 @@
+mod model;
+
+use common::{parse_lines, Answer, ParseError, Solution};
+use model::SpringSchematic;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<SpringSchematic>;
+
+    fn parse(text: &str) -> Result<Vec<SpringSchematic>, ParseError> {
+        parse_lines(text, SpringSchematic::from_text)
+    }
+
+    fn part1(input: &Vec<SpringSchematic>) -> Answer {
+        input
+            .iter()
+            .map(|schematic| schematic.find_damaged_combinations())
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(input: &Vec<SpringSchematic>) -> Answer {
+        input
+            .iter()
+            .map(|schematic| schematic.expanded().find_damaged_combinations())
+            .sum::<usize>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day12>(include_str!("../readme.md"));
+    }
+}
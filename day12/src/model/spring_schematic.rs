@@ -1,3 +1,4 @@
+use common::{parse_number, ParseError};
 use std::collections::HashMap;
 
 use super::{SpringState, SpringStates};
@@ -20,27 +21,31 @@ impl SpringSchematic {
         }
     }
 
-    pub fn from_text(text: &str) -> SpringSchematic {
-        let components: Vec<&str> = text.split_whitespace().collect();
-
-        let states = components.get(0).unwrap();
-        let groups = components.get(1).unwrap();
-
-        SpringSchematic {
-            states: states
-                .chars()
-                .map(|symbol| match symbol {
-                    '.' => SpringState::Operational,
-                    '#' => SpringState::Damaged,
-                    _ => SpringState::Unknown,
-                })
-                .collect(),
-
-            damaged_groups: groups
-                .split(',')
-                .map(|symbol| symbol.to_string().parse().unwrap())
-                .collect(),
-        }
+    pub fn from_text(text: &str) -> Result<SpringSchematic, ParseError> {
+        let (states, groups) = text
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(text, text, "expected '<springs> <groups>'"))?;
+
+        let states = states
+            .char_indices()
+            .map(|(index, symbol)| match symbol {
+                '.' => Ok(SpringState::Operational),
+                '#' => Ok(SpringState::Damaged),
+                '?' => Ok(SpringState::Unknown),
+                _ => Err(ParseError::at(
+                    text,
+                    &states[index..(index + symbol.len_utf8())],
+                    format!("unexpected symbol '{symbol}'"),
+                )),
+            })
+            .collect::<Result<SpringStates, ParseError>>()?;
+
+        let damaged_groups = groups
+            .split(',')
+            .map(|group| parse_number(text, group))
+            .collect::<Result<Vec<usize>, ParseError>>()?;
+
+        Ok(SpringSchematic::new(states, damaged_groups))
     }
 }
 
@@ -59,9 +64,8 @@ impl SpringSchematic {
     }
 
     fn get_damaged_combinations(&self, indices: StateGroupSpaceIndex, cache: &mut Cache) -> usize {
-        let cache_hit = cache.get(&indices);
-        if cache_hit.is_some() {
-            return *cache_hit.unwrap();
+        if let Some(cache_hit) = cache.get(&indices) {
+            return *cache_hit;
         }
 
         let states = &self.states;
@@ -123,7 +127,7 @@ mod tests {
     fn creates_spring_schematic() {
         let input = "#.#.##? 1,1,3";
 
-        let schematic = SpringSchematic::from_text(input);
+        let schematic = SpringSchematic::from_text(input).unwrap();
 
         assert_eq!(
             schematic.states,
@@ -140,28 +144,37 @@ mod tests {
         assert_eq!(schematic.damaged_groups, vec![1, 1, 3]);
     }
 
+    #[test]
+    fn rejects_malformed_spring_schematics() {
+        let error = SpringSchematic::from_text("#.x.##? 1,1,3").unwrap_err();
+        assert_eq!(error, ParseError::new(1, 3, "unexpected symbol 'x'"));
+
+        let error = SpringSchematic::from_text("#.#.##? 1,,3").unwrap_err();
+        assert_eq!(error.column, 11);
+    }
+
     #[test]
     fn calculates_damaged_combinations() {
         let input = "#.#.### 1,1,3";
-        let schematic = SpringSchematic::from_text(input);
+        let schematic = SpringSchematic::from_text(input).unwrap();
 
         let result = schematic.find_damaged_combinations();
         assert_eq!(result, 1);
 
         let input = ".??..??...?##. 1,1,3";
-        let schematic = SpringSchematic::from_text(input);
+        let schematic = SpringSchematic::from_text(input).unwrap();
 
         let result = schematic.find_damaged_combinations();
         assert_eq!(result, 4);
 
         let input = "????.######..#####. 1,6,5";
-        let schematic = SpringSchematic::from_text(input);
+        let schematic = SpringSchematic::from_text(input).unwrap();
 
         let result = schematic.find_damaged_combinations();
         assert_eq!(result, 4);
 
         let input = "?###???????? 3,2,1";
-        let schematic = SpringSchematic::from_text(input);
+        let schematic = SpringSchematic::from_text(input).unwrap();
 
         let result = schematic.find_damaged_combinations();
         assert_eq!(result, 10);
@@ -3,16 +3,14 @@ use super::{SpringSchematic, SpringState};
 const EXPANSIONS: usize = 5;
 
 impl SpringSchematic {
-    pub fn from_expanded_text(text: &str) -> SpringSchematic {
-        let schematic = SpringSchematic::from_text(text);
-
-        let mut states = schematic.get_states().to_vec();
-        let mut groups = schematic.get_damaged_groups().to_vec();
+    pub fn expanded(&self) -> SpringSchematic {
+        let mut states = self.get_states().to_vec();
+        let mut groups = self.get_damaged_groups().to_vec();
 
         for _ in 1..EXPANSIONS {
             states.push(SpringState::Unknown);
-            states.append(&mut schematic.get_states().to_vec());
-            groups.append(&mut schematic.get_damaged_groups().to_vec());
+            states.append(&mut self.get_states().to_vec());
+            groups.append(&mut self.get_damaged_groups().to_vec());
         }
 
         SpringSchematic::new(states, groups)
@@ -27,7 +25,7 @@ mod tests {
     fn creates_expanded_spring_schematic() {
         let input = "#.#.##? 1,1,3";
 
-        let schematic = SpringSchematic::from_expanded_text(input);
+        let schematic = SpringSchematic::from_text(input).unwrap().expanded();
 
         assert_eq!(schematic.get_states().len(), (8 * EXPANSIONS) - 1);
         assert_eq!(schematic.get_damaged_groups().len(), 3 * EXPANSIONS);
@@ -0,0 +1,62 @@
+mod model;
+mod util;
+
+use common::{Answer, ParseError, Solution};
+use model::TerrainMap;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<TerrainMap>;
+
+    fn parse(text: &str) -> Result<Vec<TerrainMap>, ParseError> {
+        parse_terrain_maps(text)
+    }
+
+    fn part1(input: &Vec<TerrainMap>) -> Answer {
+        let result = input.iter().fold(0, |sum, map| {
+            let row = map.find_reflecting_row();
+            let col = map.find_reflecting_col();
+
+            sum + 100 * row.unwrap_or(0) + col.unwrap_or(0)
+        });
+
+        result.into()
+    }
+
+    fn part2(input: &Vec<TerrainMap>) -> Answer {
+        let result = input.iter().fold(0, |sum, map| {
+            let (row, col) = map.find_partial_reflections();
+
+            sum + 100 * row.unwrap_or(0) + col.unwrap_or(0)
+        });
+
+        result.into()
+    }
+}
+
+fn parse_terrain_maps(text: &str) -> Result<Vec<TerrainMap>, ParseError> {
+    text.split("\n\n")
+        .map(|map_text| {
+            TerrainMap::from_text(map_text).map_err(|error| error.within(text, map_text))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn reports_position_within_all_maps() {
+        let error = parse_terrain_maps("#.#\n.#.\n\n##.\n.x#").unwrap_err();
+
+        assert_eq!(error, ParseError::new(5, 2, "unexpected symbol 'x'"));
+    }
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day13>(include_str!("../readme.md"));
+    }
+}
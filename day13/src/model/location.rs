@@ -1,3 +1 @@
-pub type Position = (usize, usize);
-
 pub type RowPair = (usize, usize);
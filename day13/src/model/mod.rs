@@ -5,7 +5,7 @@ mod terrain_map;
 mod terrain_partial_reflection;
 mod terrain_reflection;
 
-pub use location::{Position, RowPair};
+pub use location::RowPair;
 pub use terrain_collection::TerrainCollection;
 pub use terrain_feature::TerrainFeature;
 pub use terrain_map::TerrainMap;
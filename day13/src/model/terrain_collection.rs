@@ -30,6 +30,6 @@ impl TerrainCollection {
 
 impl PartialEq for TerrainCollection {
     fn eq(&self, other: &Self) -> bool {
-        self.find_differences(other).len() == 0
+        self.find_differences(other).is_empty()
     }
 }
@@ -15,8 +15,8 @@ impl TerrainMap {
         let rows = self.find_partial_reflecting_rows();
         let cols = self.find_partial_reflecting_cols();
 
-        let row = rows.get(0).map(|row| *row);
-        let col = cols.get(0).map(|col| *col);
+        let row = rows.first().copied();
+        let col = cols.first().copied();
 
         (row, col)
     }
@@ -62,7 +62,7 @@ impl TerrainMap {
     }
 }
 
-fn find_partial_pairs(collection: &Vec<TerrainCollection>) -> Vec<RowPair> {
+fn find_partial_pairs(collection: &[TerrainCollection]) -> Vec<RowPair> {
     let mut result = vec![];
     let num = collection.len();
 
@@ -84,7 +84,7 @@ fn find_partial_pairs(collection: &Vec<TerrainCollection>) -> Vec<RowPair> {
 
 fn find_partial_reflections(
     pairs: &Vec<RowPair>,
-    partial_pairs: &Vec<RowPair>,
+    partial_pairs: &[RowPair],
     max_index: usize,
 ) -> Vec<usize> {
     let candidates: Vec<usize> = partial_pairs
@@ -122,14 +122,14 @@ mod tests {
     fn finds_partial_pairs() {
         let input = "#.##..##.\n..#.##.#.\n##......#\n##......#\n..#.##.#.\n..##..##.\n#.#.##.#.";
 
-        let map = TerrainMap::from_text(input);
+        let map = TerrainMap::from_text(input).unwrap();
 
         assert_eq!(map.find_partial_row_pairs(), vec![(0, 5), (1, 6), (4, 6)]);
         assert_eq!(map.find_partial_col_pairs(), vec![]);
 
         let input = "#...##..#\n#....#..#\n..##..###\n#####.##.\n#####.##.\n..##..###\n#....#..#";
 
-        let map = TerrainMap::from_text(input);
+        let map = TerrainMap::from_text(input).unwrap();
 
         assert_eq!(map.find_partial_row_pairs(), vec![(0, 1), (0, 6)]);
         assert_eq!(map.find_partial_col_pairs(), vec![(1, 4)]);
@@ -139,7 +139,7 @@ mod tests {
     fn finds_partial_reflections() {
         let input = "#.##..##.\n..#.##.#.\n##......#\n##......#\n..#.##.#.\n..##..##.\n#.#.##.#.";
 
-        let map = TerrainMap::from_text(input);
+        let map = TerrainMap::from_text(input).unwrap();
 
         let (row, col) = map.find_partial_reflections();
         assert_eq!(row, Some(3));
@@ -147,7 +147,7 @@ mod tests {
 
         let input = "#...##..#\n#....#..#\n..##..###\n#####.##.\n#####.##.\n..##..###\n#....#..#";
 
-        let map = TerrainMap::from_text(input);
+        let map = TerrainMap::from_text(input).unwrap();
 
         let (row, col) = map.find_partial_reflections();
         assert_eq!(row, Some(1));
@@ -158,7 +158,7 @@ mod tests {
     fn finds_partial_reflections_in_large_map() {
         let input = ".####.#...##.#.\n...###.##.#.#..\n...###.##.#.#..\n.####.#...#..#.\n..##......#..#.\n#.#...#.##...##\n.#.#.#..##..##.\n#..##...#####.#\n.#.#.#.#..##.#.\n.....###.#.#..#\n.....###.#.#..#";
 
-        let map = TerrainMap::from_text(input);
+        let map = TerrainMap::from_text(input).unwrap();
 
         let (row, col) = map.find_partial_reflections();
 
@@ -20,7 +20,7 @@ impl TerrainMap {
     }
 }
 
-fn find_pairs(collection: &Vec<TerrainCollection>) -> Vec<RowPair> {
+fn find_pairs(collection: &[TerrainCollection]) -> Vec<RowPair> {
     let mut result = vec![];
     let num = collection.len();
 
@@ -46,7 +46,7 @@ mod tests {
     fn finds_paired_rows() {
         let input = "#.##..##.\n..#.##.#.\n##......#\n##......#\n..#.##.#.\n..##..##.\n#.#.##.#.";
 
-        let map = TerrainMap::from_text(input);
+        let map = TerrainMap::from_text(input).unwrap();
 
         assert_eq!(map.find_row_pairs(), vec![(1, 4), (2, 3)]);
     }
@@ -55,7 +55,7 @@ mod tests {
     fn finds_paired_cols() {
         let input = "#.##..##.\n..#.##.#.\n##......#\n##......#\n..#.##.#.\n..##..##.\n#.#.##.#.";
 
-        let map = TerrainMap::from_text(input);
+        let map = TerrainMap::from_text(input).unwrap();
 
         assert_eq!(map.find_col_pairs(), vec![(1, 8), (2, 7), (3, 6), (4, 5)]);
     }
@@ -64,14 +64,14 @@ mod tests {
     fn finds_reflections() {
         let input = "#.##..##.\n..#.##.#.\n##......#\n##......#\n..#.##.#.\n..##..##.\n#.#.##.#.";
 
-        let map = TerrainMap::from_text(input);
+        let map = TerrainMap::from_text(input).unwrap();
 
         assert_eq!(map.find_reflecting_row(), None);
         assert_eq!(map.find_reflecting_col(), Some(5));
 
         let input = "#...##..#\n#....#..#\n..##..###\n#####.##.\n#####.##.\n..##..###\n#....#..#";
 
-        let map = TerrainMap::from_text(input);
+        let map = TerrainMap::from_text(input).unwrap();
 
         assert_eq!(map.find_reflecting_row(), Some(4));
         assert_eq!(map.find_reflecting_col(), None);
@@ -1,12 +1,12 @@
 type RowPair = (usize, usize);
 
-pub fn find_reflection(pairs: &Vec<RowPair>, max_index: usize) -> Option<usize> {
+pub fn find_reflection(pairs: &[RowPair], max_index: usize) -> Option<usize> {
     let candidates = find_reflections(pairs, max_index);
 
-    candidates.get(0).map(|value| *value)
+    candidates.first().copied()
 }
 
-pub fn find_reflections(pairs: &Vec<RowPair>, max_index: usize) -> Vec<usize> {
+pub fn find_reflections(pairs: &[RowPair], max_index: usize) -> Vec<usize> {
     pairs
         .iter()
         .filter_map(|(index_a, index_b)| {
@@ -22,7 +22,7 @@ pub fn find_reflections(pairs: &Vec<RowPair>, max_index: usize) -> Vec<usize> {
         .collect()
 }
 
-fn is_reflection(index: usize, pairs: &Vec<RowPair>, max_index: usize) -> bool {
+fn is_reflection(index: usize, pairs: &[RowPair], max_index: usize) -> bool {
     for i in 1..(max_index - index) {
         let upper = index + i + 1;
 
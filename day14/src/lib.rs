@@ -0,0 +1,38 @@
+mod platform;
+
+use common::{Answer, ParseError, Solution};
+use platform::Platform;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Platform;
+
+    fn parse(text: &str) -> Result<Platform, ParseError> {
+        Platform::from_text(text)
+    }
+
+    fn part1(input: &Platform) -> Answer {
+        let mut platform = input.clone();
+        platform.tilt_north();
+
+        platform.calculate_north_load().into()
+    }
+
+    fn part2(input: &Platform) -> Answer {
+        let mut platform = input.clone();
+
+        platform.calculate_load_after_cycles(1_000_000_000).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day14>(include_str!("../readme.md"));
+    }
+}
@@ -1,21 +1,19 @@
-pub fn compute_hash_values(input: &str) -> usize {
-    input
-        .split(',')
+pub fn compute_hash_values(steps: &[&str]) -> usize {
+    steps
+        .iter()
         .fold(0, |value, text| value + compute_hash_value(text))
 }
 
 pub fn compute_hash_value(input: &str) -> usize {
     input
         .chars()
-        .map(|symbol| symbol_to_ascii(symbol))
-        .fold(0, |value: usize, next: usize| {
-            return ((value + next) * 17) % 256;
-        })
+        .map(symbol_to_ascii)
+        .fold(0, |value: usize, next: usize| ((value + next) * 17) % 256)
 }
 
 fn symbol_to_ascii(symbol: char) -> usize {
     let as_string = String::from(symbol);
-    let as_ascii = as_string.as_bytes().get(0).unwrap();
+    let as_ascii = as_string.as_bytes().first().unwrap();
     *as_ascii as usize
 }
 
@@ -35,7 +33,7 @@ mod tests {
     fn calculates_hash_for_multiple_values() {
         let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
 
-        let result = compute_hash_values(input);
+        let result = compute_hash_values(&input.split(',').collect::<Vec<&str>>());
         assert_eq!(result, 1320);
     }
 }
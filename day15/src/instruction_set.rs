@@ -1,3 +1,5 @@
+use common::{parse_number, ParseError};
+
 #[derive(PartialEq, Debug)]
 pub enum Instruction {
     Remove,
@@ -5,29 +7,50 @@ pub enum Instruction {
 }
 
 impl Instruction {
-    pub fn from_text(text: &str) -> (String, Instruction) {
-        let instruction_index = text.find(|value| value == '=' || value == '-').unwrap();
+    pub fn from_text(text: &str) -> Result<(String, Instruction), ParseError> {
+        let instruction_index = text.find(['=', '-']).ok_or_else(|| {
+            ParseError::at(
+                text,
+                text,
+                "expected '<label>=<focal length>' or '<label>-'",
+            )
+        })?;
 
-        let (label, focal_length) = text.split_at(instruction_index);
+        let (label, operation) = text.split_at(instruction_index);
 
         let label = label.to_string();
 
-        let focal_length = focal_length
-            .chars()
-            .filter(|value| value.is_ascii_digit())
-            .collect::<String>()
-            .parse();
-
-        let instruction = match text
-            .chars()
-            .collect::<Vec<char>>()
-            .get(instruction_index)
-            .unwrap()
-        {
-            '=' => (label, Instruction::AddOrReplace(focal_length.unwrap())),
-            _ => (label, Instruction::Remove),
-        };
-        instruction
+        match operation.split_at(1) {
+            ("=", focal_length) => Ok((
+                label,
+                Instruction::AddOrReplace(parse_number(text, focal_length)?),
+            )),
+            (_, "") => Ok((label, Instruction::Remove)),
+            (_, rest) => Err(ParseError::at(
+                text,
+                rest,
+                format!("unexpected '{rest}' after '-'"),
+            )),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Step {
+    pub text: String,
+    pub label: String,
+    pub instruction: Instruction,
+}
+
+impl Step {
+    pub fn from_text(text: &str) -> Result<Step, ParseError> {
+        let (label, instruction) = Instruction::from_text(text)?;
+
+        Ok(Step {
+            text: text.to_string(),
+            label,
+            instruction,
+        })
     }
 }
 
@@ -38,7 +61,7 @@ mod tests {
     #[test]
     fn creates_instruction_set() {
         let input = "rx=5";
-        let instruction = Instruction::from_text(input);
+        let instruction = Instruction::from_text(input).unwrap();
 
         assert_eq!(
             instruction,
@@ -46,8 +69,17 @@ mod tests {
         );
 
         let input = "hh-";
-        let instruction = Instruction::from_text(input);
+        let instruction = Instruction::from_text(input).unwrap();
 
         assert_eq!(instruction, (String::from("hh"), Instruction::Remove));
     }
+
+    #[test]
+    fn rejects_malformed_instructions() {
+        let error = Instruction::from_text("rx=x").unwrap_err();
+        assert_eq!(error, ParseError::new(1, 4, "invalid number 'x'"));
+
+        let error = Instruction::from_text("rx5").unwrap_err();
+        assert_eq!(error.column, 1);
+    }
 }
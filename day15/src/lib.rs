@@ -0,0 +1,146 @@
+mod hash;
+mod instruction_set;
+mod lens;
+
+use std::collections::HashMap;
+
+use common::{Answer, ParseError, Solution};
+use instruction_set::{Instruction, Step};
+use lens::Lens;
+
+use crate::hash::{compute_hash_value, compute_hash_values};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Step>;
+
+    fn parse(text: &str) -> Result<Vec<Step>, ParseError> {
+        parse_steps(text)
+    }
+
+    fn part1(input: &Vec<Step>) -> Answer {
+        let texts: Vec<&str> = input.iter().map(|step| step.text.as_str()).collect();
+
+        compute_hash_values(&texts).into()
+    }
+
+    fn part2(input: &Vec<Step>) -> Answer {
+        let map = arrange_lenses(input);
+
+        sum_focal_map(&map).into()
+    }
+}
+
+fn parse_steps(text: &str) -> Result<Vec<Step>, ParseError> {
+    let sequence = text.trim_end();
+
+    sequence
+        .split(',')
+        .map(|step| Step::from_text(step).map_err(|error| error.within(text, step)))
+        .collect()
+}
+
+fn arrange_lenses(steps: &[Step]) -> HashMap<usize, Vec<Lens>> {
+    let mut map: HashMap<usize, Vec<Lens>> = HashMap::new();
+
+    steps.iter().for_each(
+        |Step {
+             label, instruction, ..
+         }| {
+            let room_number = compute_hash_value(label);
+
+            let mut lenses = map.get(&room_number).unwrap_or(&vec![]).to_vec();
+
+            match instruction {
+                Instruction::AddOrReplace(focal_length) => {
+                    add_or_replace_lens(&mut lenses, (label.to_string(), *focal_length));
+                }
+                Instruction::Remove => {
+                    lenses = lenses
+                        .iter()
+                        .filter_map(|lens| match &lens.0 != label {
+                            true => Some(lens.clone()),
+                            false => None,
+                        })
+                        .collect()
+                }
+            }
+
+            map.insert(room_number, lenses.to_owned());
+        },
+    );
+
+    map
+}
+
+fn sum_focal_map(map: &HashMap<usize, Vec<Lens>>) -> usize {
+    map.iter().fold(0, |previous, (room, lenses)| {
+        let focals = lenses.iter().enumerate().fold(0, |sum, (index, lens)| {
+            let (_, focal_length) = lens;
+
+            sum + (*room + 1) * (index + 1) * (*focal_length)
+        });
+
+        previous + focals
+    })
+}
+
+pub fn add_or_replace_lens(lenses: &mut Vec<Lens>, lens: Lens) -> &Vec<Lens> {
+    let (label, focal_length) = &lens;
+
+    let exists = lenses.iter().position(|lens| &lens.0 == label);
+
+    match exists {
+        Some(index) => lenses[index] = (label.to_string(), *focal_length),
+        _ => lenses.push(lens),
+    }
+
+    lenses
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn parses_instructions() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+        let map = arrange_lenses(&parse_steps(input).unwrap());
+
+        assert_eq!(
+            map.get(&0).unwrap(),
+            &vec![(String::from("rn"), 1), (String::from("cm"), 2)]
+        );
+
+        assert_eq!(map.get(&1), Some(&vec![]));
+        assert_eq!(map.get(&2), None);
+
+        assert_eq!(
+            map.get(&3).unwrap(),
+            &vec![
+                (String::from("ot"), 7),
+                (String::from("ab"), 5),
+                (String::from("pc"), 6)
+            ]
+        );
+
+        let result = sum_focal_map(&map);
+
+        assert_eq!(result, 145);
+    }
+
+    #[test]
+    fn reports_position_of_bad_step() {
+        let error = parse_steps("rn=1,cm-,qp=x").unwrap_err();
+
+        assert_eq!(error, ParseError::new(1, 13, "invalid number 'x'"));
+    }
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day15>(include_str!("../readme.md"));
+    }
+}
@@ -8,11 +8,9 @@ pub fn find_energized_tiles_from_beam(map: &Map, initial_beam: &Beam) -> HashSet
     let initial_position = initial_beam.position.unwrap();
 
     let mut energized_tiles: HashSet<Position> = HashSet::from([initial_position]);
-    let mut beams: Vec<Beam> = vec![initial_beam.clone()];
-
-    while beams.len() > 0 {
-        let mut beam = beams.pop().unwrap();
+    let mut beams: Vec<Beam> = vec![*initial_beam];
 
+    while let Some(mut beam) = beams.pop() {
         loop {
             let position = &beam.position;
 
@@ -45,7 +43,7 @@ pub fn find_energized_tiles_from_beam(map: &Map, initial_beam: &Beam) -> HashSet
                     beams.append(&mut new_beams);
                     break;
                 }
-                MapFeature::MirrorSW | MapFeature::MirrorSE => beam.update_direction(&tile),
+                MapFeature::MirrorSW | MapFeature::MirrorSE => beam.update_direction(tile),
                 _ => {}
             }
 
@@ -57,15 +55,14 @@ pub fn find_energized_tiles_from_beam(map: &Map, initial_beam: &Beam) -> HashSet
 }
 
 pub fn find_energized_tiles_from_position(
-    text: &str,
+    map: &Map,
     initial_position: Position,
 ) -> HashSet<Position> {
-    let map = Map::from_text(text);
-
-    find_energized_tiles_from_beam(&map, &mut Beam::default(initial_position))
+    find_energized_tiles_from_beam(map, &Beam::default(initial_position))
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
 
@@ -73,12 +70,12 @@ mod tests {
     fn calculates_energized_tiles_simple() {
         let input = ".............\n..........|..\n.............";
 
-        let energized = find_energized_tiles_from_position(input, (0, 1));
+        let energized = find_energized_tiles_from_position(&Map::from_text(input).unwrap(), (0, 1));
         assert_eq!(energized.len(), 13);
 
         let input = "...\\...\n.......\n.......\n.......\n.......\n...-../\n.......";
 
-        let energized = find_energized_tiles_from_position(input, (0, 0));
+        let energized = find_energized_tiles_from_position(&Map::from_text(input).unwrap(), (0, 0));
         assert_eq!(energized.len(), 20);
     }
 
@@ -86,7 +83,7 @@ mod tests {
     fn calculates_energized_tiles_all_features() {
         let input = ".......|........-....\\\n......................\n......................\n/.....................\n.....................|\n......................\n......................\n\\......-......\\...././\n......................\n......................\n......................\n......................\n..............\\.-../..\n......................";
 
-        let energized = find_energized_tiles_from_position(input, (0, 0));
+        let energized = find_energized_tiles_from_position(&Map::from_text(input).unwrap(), (0, 0));
         assert_eq!(energized.len(), 89);
     }
 
@@ -94,7 +91,7 @@ mod tests {
     fn calculates_energized_tiles_all_tiles() {
         let input = "...................\\\n/...............\\/\\.\n./.............\\....\n../...........\\.....\n...|.........-......\n....|.......-.......\n...-........|.......\n..\\........../......\n.\\............/.....\n\\............../\\/\\/";
 
-        let energized = find_energized_tiles_from_position(input, (0, 0));
+        let energized = find_energized_tiles_from_position(&Map::from_text(input).unwrap(), (0, 0));
         assert_eq!(energized.len(), 200);
     }
 
@@ -102,7 +99,7 @@ mod tests {
     fn calculates_energized_tiles_mirrors() {
         let input = ".........|..........\n....................\n....................\n.............-......\n....-...............\n....................\n....................\n....................\n....|....-...|......\n....................\n....................";
 
-        let energized = find_energized_tiles_from_position(input, (0, 0));
+        let energized = find_energized_tiles_from_position(&Map::from_text(input).unwrap(), (0, 0));
         assert_eq!(energized.len(), 75);
     }
 
@@ -110,7 +107,7 @@ mod tests {
     fn calculates_energized_tiles_sample() {
         let input = ".|...\\....\n|.-.\\.....\n.....|-...\n........|.\n..........\n.........\\\n..../.\\\\..\n.-.-/..|..\n.|....-|.\\\n..//.|....";
 
-        let energized = find_energized_tiles_from_position(input, (0, 0));
+        let energized = find_energized_tiles_from_position(&Map::from_text(input).unwrap(), (0, 0));
 
         assert_eq!(energized.contains(&(1, 1)), true);
         assert_eq!(energized.contains(&(9, 2)), true);
@@ -0,0 +1,106 @@
+mod energized;
+mod model;
+
+use common::{Answer, ParseError, Solution};
+use energized::find_energized_tiles_from_beam;
+use model::{Beam, Map};
+
+use crate::energized::find_energized_tiles_from_position;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Map;
+
+    fn parse(text: &str) -> Result<Map, ParseError> {
+        Map::from_text(text)
+    }
+
+    fn part1(input: &Map) -> Answer {
+        let energized = find_energized_tiles_from_position(input, (0, 0));
+
+        energized.len().into()
+    }
+
+    fn part2(input: &Map) -> Answer {
+        find_most_energized_beams_by_entry(input).into()
+    }
+}
+
+fn find_most_energized_beams_by_entry(map: &Map) -> usize {
+    let beams = find_possible_starting_beams(map);
+
+    beams
+        .iter()
+        .map(|beam| {
+            let energized = find_energized_tiles_from_beam(map, beam);
+            energized.len()
+        })
+        .max()
+        .expect("maps have at least one tile")
+}
+
+fn find_possible_starting_beams(map: &Map) -> Vec<Beam> {
+    let mut beams = vec![];
+
+    let (x_max, y_max) = map.size();
+
+    // top and bottom sides
+    for x in 0..x_max {
+        beams.push(Beam::new((x, 0), model::Direction::S));
+        beams.push(Beam::new((x, y_max - 1), model::Direction::N));
+    }
+
+    // left and right sides
+    for y in 0..y_max {
+        beams.push(Beam::new((0, y), model::Direction::E));
+        beams.push(Beam::new((x_max - 1, y), model::Direction::W));
+    }
+
+    beams
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn calculates_most_energized_tiles() {
+        let input = ".|...\\....\n|.-.\\.....\n.....|-...\n........|.\n..........\n.........\\\n..../.\\\\..\n.-.-/..|..\n.|....-|.\\\n..//.|....";
+
+        let energized = find_most_energized_beams_by_entry(&Map::from_text(input).unwrap());
+        assert_eq!(energized, 51);
+    }
+
+    #[test]
+    fn rejects_unexpected_symbols() {
+        let error = Map::from_text(".|..\n.x-.").unwrap_err();
+
+        assert_eq!(error, ParseError::new(2, 2, "unexpected symbol 'x'"));
+
+        let error = Map::from_text("").unwrap_err();
+        assert_eq!(error, ParseError::new(1, 1, "expected at least one tile"));
+    }
+
+    #[test]
+    fn finds_all_possible_beam_entries() {
+        let input = ".|...\\....\n|.-.\\.....\n.....|-...\n........|.\n..........\n.........\\\n..../.\\\\..\n.-.-/..|..\n.|....-|.\\\n..//.|....";
+        let map = Map::from_text(input).unwrap();
+
+        let beams = find_possible_starting_beams(&map);
+
+        assert_eq!(beams.len(), 40);
+
+        assert!(beams.contains(&Beam::new((0, 9), model::Direction::N)));
+
+        assert!(beams.contains(&Beam::new((0, 0), model::Direction::S)));
+
+        assert!(beams.contains(&Beam::new((9, 0), model::Direction::W)));
+    }
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day16>(include_str!("../readme.md"));
+    }
+}
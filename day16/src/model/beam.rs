@@ -1,20 +1,20 @@
-use super::{MapFeature, Position, TravelDirection};
+use super::{Direction, MapFeature, Position};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Beam {
-    pub travel_direction: TravelDirection,
+    pub travel_direction: Direction,
     pub position: Option<Position>,
 }
 
 impl Beam {
     pub fn default(position: Position) -> Beam {
         Beam {
-            travel_direction: TravelDirection::E,
+            travel_direction: Direction::E,
             position: Some(position),
         }
     }
 
-    pub fn new(position: Position, travel_direction: TravelDirection) -> Beam {
+    pub fn new(position: Position, travel_direction: Direction) -> Beam {
         Beam {
             travel_direction,
             position: Some(position),
@@ -35,35 +35,22 @@ impl Beam {
     }
 
     pub fn next_position(&self) -> Option<Position> {
-        let (x, y) = self.position.unwrap();
-
-        match self.travel_direction {
-            TravelDirection::E => Some((x + 1, y)),
-            TravelDirection::W => match x > 0 {
-                true => Some((x - 1, y)),
-                _ => None,
-            },
-            TravelDirection::S => Some((x, y + 1)),
-            TravelDirection::N => match y > 0 {
-                true => Some((x, y - 1)),
-                _ => None,
-            },
-        }
+        self.travel_direction.step(&self.position.unwrap())
     }
 
-    pub fn deflect(&self, feature: &MapFeature) -> TravelDirection {
+    pub fn deflect(&self, feature: &MapFeature) -> Direction {
         match feature {
             MapFeature::MirrorSW => match self.travel_direction {
-                TravelDirection::E => TravelDirection::N,
-                TravelDirection::W => TravelDirection::S,
-                TravelDirection::S => TravelDirection::W,
-                TravelDirection::N => TravelDirection::E,
+                Direction::E => Direction::N,
+                Direction::W => Direction::S,
+                Direction::S => Direction::W,
+                Direction::N => Direction::E,
             },
             MapFeature::MirrorSE => match self.travel_direction {
-                TravelDirection::E => TravelDirection::S,
-                TravelDirection::W => TravelDirection::N,
-                TravelDirection::S => TravelDirection::E,
-                TravelDirection::N => TravelDirection::W,
+                Direction::E => Direction::S,
+                Direction::W => Direction::N,
+                Direction::S => Direction::E,
+                Direction::N => Direction::W,
             },
             _ => self.travel_direction,
         }
@@ -74,9 +61,9 @@ impl Beam {
 
         match feature {
             MapFeature::SplitterNS => match self.travel_direction {
-                TravelDirection::E | TravelDirection::W => {
-                    let mut beam_n = Beam::new(position.clone(), TravelDirection::N);
-                    let mut beam_s = Beam::new(position.clone(), TravelDirection::S);
+                Direction::E | Direction::W => {
+                    let mut beam_n = Beam::new(position, Direction::N);
+                    let mut beam_s = Beam::new(position, Direction::S);
 
                     beam_n.update_position();
                     beam_s.update_position();
@@ -89,9 +76,9 @@ impl Beam {
                 }
             },
             MapFeature::SplitterWE => match self.travel_direction {
-                TravelDirection::N | TravelDirection::S => {
-                    let mut beam_w = Beam::new(position.clone(), TravelDirection::W);
-                    let mut beam_e = Beam::new(position.clone(), TravelDirection::E);
+                Direction::N | Direction::S => {
+                    let mut beam_w = Beam::new(position, Direction::W);
+                    let mut beam_e = Beam::new(position, Direction::E);
 
                     beam_w.update_position();
                     beam_e.update_position();
@@ -111,10 +98,10 @@ impl Beam {
         let (x, y) = self.position.unwrap();
 
         let direction_key = match self.travel_direction {
-            TravelDirection::E => "E",
-            TravelDirection::W => "W",
-            TravelDirection::N => "N",
-            TravelDirection::S => "S",
+            Direction::E => "E",
+            Direction::W => "W",
+            Direction::N => "N",
+            Direction::S => "S",
         };
 
         format!("({},{})|{}", x, y, direction_key)
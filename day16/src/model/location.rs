@@ -1,12 +1,4 @@
-pub type Position = (usize, usize);
-
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum TravelDirection {
-    N,
-    E,
-    S,
-    W,
-}
+pub use grid::{Direction, Position};
 
 #[derive(Debug)]
 pub enum MapFeature {
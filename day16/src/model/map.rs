@@ -1,49 +1,49 @@
 #![allow(dead_code)]
 
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
 use super::{MapFeature, Position};
+use common::ParseError;
+use grid::Grid;
 
+#[derive(Debug)]
 pub struct Map {
-    tiles: HashMap<Position, MapFeature>,
-    pub size: (usize, usize),
+    tiles: Grid<MapFeature>,
 }
 
 impl Map {
-    pub fn from_text(text: &str) -> Map {
-        let mut tiles = HashMap::new();
-        let mut size = (0, 0);
-
-        for (y, line) in text.lines().enumerate() {
-            for (x, symbol) in line.chars().enumerate() {
-                let feature = match symbol {
-                    '|' => MapFeature::SplitterNS,
-                    '-' => MapFeature::SplitterWE,
-                    '\\' => MapFeature::MirrorSE,
-                    '/' => MapFeature::MirrorSW,
-                    _ => MapFeature::Empty,
-                };
-
-                tiles.insert((x, y), feature);
-                size.0 = x + 1;
-            }
-
-            size.1 = y + 1;
+    pub fn from_text(text: &str) -> Result<Map, ParseError> {
+        let tiles = Grid::parse(text, |_, symbol| match symbol {
+            '|' => Some(MapFeature::SplitterNS),
+            '-' => Some(MapFeature::SplitterWE),
+            '\\' => Some(MapFeature::MirrorSE),
+            '/' => Some(MapFeature::MirrorSW),
+            '.' => Some(MapFeature::Empty),
+            _ => None,
+        })?;
+
+        // beams have to enter somewhere
+        if tiles.size().0 == 0 {
+            return Err(ParseError::new(1, 1, "expected at least one tile"));
         }
 
-        Map { tiles, size }
+        Ok(Map { tiles })
     }
 }
 
 impl Map {
+    pub fn size(&self) -> (usize, usize) {
+        self.tiles.size()
+    }
+
     pub fn get(&self, position: &Position) -> Option<&MapFeature> {
         self.tiles.get(position)
     }
 
     pub fn draw_energized(&self, energized: &HashSet<Position>) {
-        let (x_max, y_max) = self.size;
+        let (x_max, y_max) = self.size();
 
-        println!("");
+        println!();
         for y in 0..y_max {
             for x in 0..x_max {
                 let position = (x, y);
@@ -53,8 +53,8 @@ impl Map {
                     false => print!("."),
                 }
             }
-            println!("");
+            println!();
         }
-        println!("");
+        println!();
     }
 }
@@ -3,5 +3,5 @@ mod location;
 mod map;
 
 pub use beam::Beam;
-pub use location::{MapFeature, Position, TravelDirection};
+pub use location::{Direction, MapFeature, Position};
 pub use map::Map;
@@ -0,0 +1,44 @@
+mod pathing;
+
+use common::{Answer, ParseError, Solution};
+use pathing::{find_shortest_path_in_graph, Graph};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Graph;
+
+    fn parse(text: &str) -> Result<Graph, ParseError> {
+        Graph::from_text(text)
+    }
+
+    fn part1(input: &Graph) -> Answer {
+        find_shortest_path_in_graph(input, (0, 0), input.end_position(), 1, 3)
+            .map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(input: &Graph) -> Answer {
+        find_shortest_path_in_graph(input, (0, 0), input.end_position(), 4, 10)
+            .map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day17>(include_str!("../readme.md"));
+    }
+
+    #[test]
+    fn leaves_unreachable_end_unsolved() {
+        // an ultra crucible can't stop after the single block it has to move
+        let graph = Day17::parse("12").unwrap();
+
+        assert_eq!(Day17::part1(&graph), Answer::Number(2));
+        assert_eq!(Day17::part2(&graph), Answer::Unsolved);
+    }
+}
@@ -1,139 +1,110 @@
 use super::{
-    graph::GraphNode,
     map_2d::{Direction, Position},
-    priority_queue::{QueueNode, StateKey},
     Graph,
 };
-use std::collections::{BinaryHeap, HashMap};
+use search::{a_star, SearchState};
 
-// uses A* search: https://en.wikipedia.org/wiki/A*_search_algorithm
-pub fn find_shortest_path_in_graph(
-    graph: &Graph,
-    start: Position,
+struct Crucible<'a> {
+    graph: &'a Graph,
     end: Position,
     min_steps: u32,
     max_steps: u32,
-) -> Option<u32> {
-    let mut end_state: Option<QueueNode> = None;
-
-    let mut queue = BinaryHeap::new();
-    let mut came_from: HashMap<StateKey, QueueNode> = HashMap::new();
-    let mut distance: HashMap<StateKey, u32> = HashMap::new();
-
-    let initial_state = QueueNode::new(0, start, Direction::None, 0);
-
-    queue.push(initial_state);
-    distance.insert(initial_state.into(), 0);
-
-    while queue.len() > 0 {
-        let state = queue.pop().unwrap();
-
-        let QueueNode {
-            position: current_position,
-            steps: current_steps,
-            direction: current_direction,
-            cost: current_cost,
-        } = state;
-
-        if current_position == end && current_steps >= min_steps {
-            end_state = Some(state);
-            break;
-        }
-
-        let key: StateKey = state.into();
-        let previous_score = distance.get(&key).unwrap_or(&u32::max_value());
-
-        if previous_score < &current_cost {
-            continue;
-        }
-
-        let current_node = graph.get(&current_position).unwrap();
-        let neighbors = current_node.find_connected_positions(current_direction);
-
-        neighbors.iter().for_each(|(direction, neighbor_position)| {
-            let neighbor_node: Option<&GraphNode> = graph.get(neighbor_position);
-            let did_change_direction = direction == &current_direction;
-
-            let steps = match did_change_direction {
-                true => current_steps + 1,
-                false => 1,
-            };
-
-            let too_many_steps = steps > max_steps;
-            let too_few_steps = match current_direction != Direction::None {
-                true => !did_change_direction && current_steps < min_steps,
-                _ => false,
-            };
-
-            if neighbor_node.is_none() || too_many_steps || too_few_steps {
-                return;
-            }
-
-            let neigbor_node = neighbor_node.unwrap();
-            let new_score = current_cost + neigbor_node.value;
-
-            let next = QueueNode::new(new_score, *neighbor_position, *direction, steps);
-            let key = next.into();
-            let best_score = distance.get(&key).unwrap_or(&u32::max_value());
-
-            if &next.cost >= best_score {
-                return;
-            }
+}
 
-            came_from.insert(key, state);
-            distance.insert(key, new_score);
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct CrucibleState {
+    position: Position,
+    direction: Option<Direction>,
+    steps: u32,
+}
 
-            queue.push(next)
-        });
+impl SearchState<Crucible<'_>> for CrucibleState {
+    fn successors(&self, crucible: &Crucible) -> Vec<(Self, u32)> {
+        let current_node = crucible.graph.get(&self.position).unwrap();
+
+        current_node
+            .find_connected_positions(self.direction)
+            .into_iter()
+            .filter_map(|(direction, position)| {
+                let neighbor_node = crucible.graph.get(&position)?;
+                let same_direction = Some(direction) == self.direction;
+
+                let steps = match same_direction {
+                    true => self.steps + 1,
+                    false => 1,
+                };
+
+                let too_many_steps = steps > crucible.max_steps;
+                let too_few_steps = match self.direction.is_some() {
+                    true => !same_direction && self.steps < crucible.min_steps,
+                    _ => false,
+                };
+
+                if too_many_steps || too_few_steps {
+                    return None;
+                }
+
+                let next = CrucibleState {
+                    position,
+                    direction: Some(direction),
+                    steps,
+                };
+
+                Some((next, neighbor_node.value))
+            })
+            .collect()
     }
 
-    let end_state = end_state.unwrap();
-    let path = construct_path(&came_from, graph, start, &end_state);
+    fn is_goal(&self, crucible: &Crucible) -> bool {
+        self.position == crucible.end && self.steps >= crucible.min_steps
+    }
 
-    // draw_path_in_graph(graph, &path);
+    fn heuristic(&self, crucible: &Crucible) -> u32 {
+        let (x, y) = self.position;
+        let (end_x, end_y) = crucible.end;
 
-    Some(
-        path.iter()
-            .rev()
-            .skip(1)
-            .fold(0, |sum, node| sum + node.value),
-    )
+        (x.abs_diff(end_x) + y.abs_diff(end_y)) as u32
+    }
 }
 
-fn construct_path<'a>(
-    came_from: &'a HashMap<StateKey, QueueNode>,
-    graph: &'a Graph,
+pub fn find_shortest_path_in_graph(
+    graph: &Graph,
     start: Position,
-    end_queue_node: &'a QueueNode,
-) -> Vec<&'a GraphNode> {
-    let end_node = graph.get(&end_queue_node.position).unwrap();
-    let mut path: Vec<&GraphNode> = vec![end_node];
-    let mut current = end_queue_node;
+    end: Position,
+    min_steps: u32,
+    max_steps: u32,
+) -> Option<u32> {
+    let crucible = Crucible {
+        graph,
+        end,
+        min_steps,
+        max_steps,
+    };
 
-    while current.position != start {
-        let key: StateKey = (*current).into();
+    let initial_state = CrucibleState {
+        position: start,
+        direction: None,
+        steps: 0,
+    };
 
-        let queue_node = came_from.get(&key).unwrap();
-        let node = graph.get(&queue_node.position).unwrap();
-        path.push(&node);
+    let result = a_star(&crucible, initial_state)?;
 
-        current = queue_node;
-    }
+    // draw_path_in_graph(graph, &result.path);
 
-    path
+    Some(result.cost)
 }
 
 #[allow(dead_code)]
-fn draw_path_in_graph(graph: &Graph, path: &Vec<&GraphNode>) {
-    let (x_max, y_max) = graph.size;
+fn draw_path_in_graph(graph: &Graph, path: &[CrucibleState]) {
+    let (x_max, y_max) = graph.end_position();
 
-    let positions: Vec<Position> = path.iter().map(|value| value.position).collect();
+    let positions: Vec<Position> = path.iter().map(|state| state.position).collect();
 
     for node in &positions {
         println!("node: {:?}", node);
     }
 
-    println!("");
+    println!();
     for y in 0..=y_max {
         for x in 0..=x_max {
             let position = (x, y);
@@ -143,39 +114,47 @@ fn draw_path_in_graph(graph: &Graph, path: &Vec<&GraphNode>) {
                 _ => print!("{}", graph.get(&position).unwrap().value),
             }
         }
-        println!("");
+        println!();
     }
-    println!("");
+    println!();
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::ParseError;
 
     #[test]
     fn finds_shortest_path_in_graph() {
         let text = "2413432311323\n3215453535623\n3255245654254\n3446585845452\n4546657867536\n1438598798454\n4457876987766\n3637877979653\n4654967986887\n4564679986453\n1224686865563\n2546548887735\n4322674655533";
 
-        let graph = Graph::from_text(text);
-        let result = find_shortest_path_in_graph(&graph, (0, 0), graph.size, 1, 3);
+        let graph = Graph::from_text(text).unwrap();
+        let result = find_shortest_path_in_graph(&graph, (0, 0), graph.end_position(), 1, 3);
 
-        assert_eq!(graph.size, (12, 12));
+        assert_eq!(graph.end_position(), (12, 12));
         assert_eq!(graph.len(), 169);
         assert_eq!(result, Some(102));
     }
 
+    #[test]
+    fn rejects_non_digit_heat_loss() {
+        let error = Graph::from_text("241\n3a1").unwrap_err();
+
+        assert_eq!(error, ParseError::new(2, 2, "unexpected symbol 'a'"));
+    }
+
     #[test]
     fn finds_shortest_path_in_graph_with_minimum_steps() {
         let text = "111111111111\n999999999991\n999999999991\n999999999991\n999999999991";
 
-        let graph = Graph::from_text(text);
-        let result = find_shortest_path_in_graph(&graph, (0, 0), graph.size, 4, 10);
+        let graph = Graph::from_text(text).unwrap();
+        let result = find_shortest_path_in_graph(&graph, (0, 0), graph.end_position(), 4, 10);
         assert_eq!(result, Some(71));
 
         let text = "2413432311323\n3215453535623\n3255245654254\n3446585845452\n4546657867536\n1438598798454\n4457876987766\n3637877979653\n4654967986887\n4564679986453\n1224686865563\n2546548887735\n4322674655533";
 
-        let graph = Graph::from_text(text);
-        let result = find_shortest_path_in_graph(&graph, (0, 0), graph.size, 4, 10);
+        let graph = Graph::from_text(text).unwrap();
+        let result = find_shortest_path_in_graph(&graph, (0, 0), graph.end_position(), 4, 10);
         assert_eq!(result, Some(94));
     }
 }
@@ -1,6 +1,6 @@
-use std::collections::HashMap;
-
 use super::map_2d::{Direction, Position};
+use common::ParseError;
+use grid::Grid;
 
 #[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
 pub struct GraphNode {
@@ -17,29 +17,16 @@ impl GraphNode {
 impl GraphNode {
     pub fn find_connected_positions(
         &self,
-        from_direction: Direction,
+        from_direction: Option<Direction>,
     ) -> Vec<(Direction, Position)> {
-        let (x, y) = self.position;
-
-        let mut positions = vec![(Direction::E, (x + 1, y)), (Direction::S, (x, y + 1))];
-
-        if x > 0 {
-            positions.push((Direction::W, (x - 1, y)))
-        }
-
-        if y > 0 {
-            positions.push((Direction::N, (x, y - 1)))
-        }
-
-        // avoid doubling back
-        positions
+        [Direction::E, Direction::S, Direction::W, Direction::N]
             .into_iter()
-            .filter(|(direction, _)| match from_direction {
-                Direction::E => direction != &Direction::W,
-                Direction::W => direction != &Direction::E,
-                Direction::N => direction != &Direction::S,
-                Direction::S => direction != &Direction::N,
-                _ => true,
+            // avoid doubling back
+            .filter(|direction| Some(direction.opposite()) != from_direction)
+            .filter_map(|direction| {
+                direction
+                    .step(&self.position)
+                    .map(|position| (direction, position))
             })
             .collect()
     }
@@ -47,29 +34,23 @@ impl GraphNode {
 
 #[derive(Debug)]
 pub struct Graph {
-    nodes: HashMap<Position, GraphNode>,
-    pub size: (usize, usize),
+    nodes: Grid<GraphNode>,
 }
 
 impl Graph {
-    pub fn from_text(text: &str) -> Graph {
-        let mut nodes: HashMap<Position, GraphNode> = HashMap::new();
-        let mut size = (0, 0);
-
-        for (y, line) in text.lines().enumerate() {
-            for (x, symbol) in line.chars().enumerate() {
-                let position = (x, y);
-
-                let node = GraphNode::new(position, symbol.to_string().parse().unwrap());
-
-                size.0 = x;
-                nodes.insert(position, node);
-            }
-
-            size.1 = y;
+    pub fn from_text(text: &str) -> Result<Graph, ParseError> {
+        let nodes = Grid::parse(text, |position, symbol| {
+            symbol
+                .to_digit(10)
+                .map(|value| GraphNode::new(position, value))
+        })?;
+
+        // the path runs from the top left to the bottom right block, so there must be one
+        if nodes.size().0 == 0 {
+            return Err(ParseError::new(1, 1, "expected at least one city block"));
         }
 
-        Graph { nodes, size }
+        Ok(Graph { nodes })
     }
 }
 
@@ -78,9 +59,15 @@ impl Graph {
         self.nodes.get(position)
     }
 
+    pub fn end_position(&self) -> Position {
+        let (width, height) = self.nodes.size();
+
+        (width - 1, height - 1)
+    }
+
     #[cfg(test)]
     pub fn len(&self) -> usize {
-        self.nodes.len()
+        self.nodes.positions().count()
     }
 }
 
@@ -92,7 +79,7 @@ mod tests {
     fn finds_all_connecting_positions() {
         let node = GraphNode::new((5, 5), 5);
 
-        let positions = node.find_connected_positions(Direction::E);
+        let positions = node.find_connected_positions(Some(Direction::E));
 
         assert_eq!(
             positions,
@@ -103,4 +90,14 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn rejects_empty_city() {
+        let error = Graph::from_text("").unwrap_err();
+
+        assert_eq!(
+            error,
+            ParseError::new(1, 1, "expected at least one city block")
+        );
+    }
 }
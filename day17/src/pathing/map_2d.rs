@@ -1,10 +1 @@
-pub type Position = (usize, usize);
-
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-pub enum Direction {
-    N,
-    E,
-    S,
-    W,
-    None,
-}
+pub use grid::{Direction, Position};
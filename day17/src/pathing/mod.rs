@@ -1,7 +1,6 @@
 mod a_star;
 mod graph;
 mod map_2d;
-mod priority_queue;
 
 pub use a_star::find_shortest_path_in_graph;
 pub use graph::Graph;
@@ -2,17 +2,6 @@ use crate::position_2d::{Direction, Position};
 
 use super::DigInstruction;
 
-impl From<&str> for Direction {
-    fn from(value: &str) -> Self {
-        match value {
-            "U" => Direction::Up,
-            "D" => Direction::Down,
-            "L" => Direction::Left,
-            _ => Direction::Right,
-        }
-    }
-}
-
 pub fn instructions_to_vertices(instructions: &Vec<DigInstruction>) -> Vec<Position> {
     let mut result: Vec<Position> = vec![];
 
@@ -37,12 +26,3 @@ fn direction_to_increment(direction: &Direction) -> Position {
         Direction::Right => (1, 0),
     }
 }
-
-pub fn count_perimeter_points(instructions: &Vec<DigInstruction>) -> usize {
-    let result: isize = instructions
-        .iter()
-        .map(|instruction| instruction.steps)
-        .sum();
-
-    result as usize
-}
@@ -1,66 +1,91 @@
 use crate::position_2d::Direction;
+use common::{parse_lines, parse_number, ParseError};
 
-use super::DigInstruction;
+use super::{DigInstruction, DigPlan};
 
 impl DigInstruction {
-    pub fn from_text(text: &str) -> Self {
-        let components: Vec<&str> = text.split_whitespace().collect();
+    pub fn from_text(text: &str) -> Result<Self, ParseError> {
+        let (direction, steps, _) = split_instruction(text)?;
 
-        let direction = Direction::from(*components.get(0).unwrap());
-        let steps = components.get(1).unwrap().parse().unwrap();
-
-        DigInstruction { direction, steps }
+        Ok(DigInstruction {
+            direction: parse_direction(text, direction)?,
+            steps: parse_number(text, steps)?,
+        })
     }
 
-    pub fn from_text_by_color(text: &str) -> Self {
-        let components: Vec<&str> = text.split_whitespace().collect();
+    pub fn from_text_by_color(text: &str) -> Result<Self, ParseError> {
+        let (_, _, color) = split_instruction(text)?;
+
+        let (direction, steps) = convert_color_to_instruction(text, color)?;
 
-        let color = components
-            .get(2)
-            .unwrap()
-            .to_string()
-            .chars()
-            .skip(1)
-            .take(7)
-            .collect();
+        Ok(DigInstruction { direction, steps })
+    }
+}
 
-        let (direction, steps) = convert_color_to_instruction(color);
+fn split_instruction(text: &str) -> Result<(&str, &str, &str), ParseError> {
+    let components: Vec<&str> = text.split_whitespace().collect();
+
+    match components[..] {
+        [direction, steps, color] => Ok((direction, steps, color)),
+        _ => Err(ParseError::at(
+            text,
+            text,
+            "expected '<direction> <steps> (#<color>)'",
+        )),
+    }
+}
 
-        DigInstruction { direction, steps }
+fn parse_direction(text: &str, value: &str) -> Result<Direction, ParseError> {
+    match value {
+        "U" => Ok(Direction::Up),
+        "D" => Ok(Direction::Down),
+        "L" => Ok(Direction::Left),
+        "R" => Ok(Direction::Right),
+        _ => Err(ParseError::at(
+            text,
+            value,
+            format!("invalid direction '{value}'"),
+        )),
     }
 }
 
-fn convert_color_to_instruction(color: String) -> (Direction, isize) {
-    let color_components: Vec<char> = color.chars().collect();
+fn convert_color_to_instruction(text: &str, color: &str) -> Result<(Direction, i64), ParseError> {
+    let invalid_color = || ParseError::at(text, color, format!("invalid color '{color}'"));
 
-    let direction = match *color_components.last().unwrap() {
-        '0' => Direction::Right,
-        '1' => Direction::Down,
-        '2' => Direction::Left,
-        '3' | _ => Direction::Up,
+    let hex = color
+        .strip_prefix("(#")
+        .and_then(|color| color.strip_suffix(')'))
+        .filter(|hex| hex.len() == 6 && hex.chars().all(|value| value.is_ascii_hexdigit()))
+        .ok_or_else(invalid_color)?;
+
+    let (hex_steps, hex_direction) = hex.split_at(5);
+
+    let direction = match hex_direction {
+        "0" => Direction::Right,
+        "1" => Direction::Down,
+        "2" => Direction::Left,
+        "3" => Direction::Up,
+        _ => return Err(invalid_color()),
     };
 
-    let hex_steps: String = color_components
-        .iter()
-        .skip(1)
-        .take(5)
-        .map(|value| format!("{}", value))
-        .collect();
+    let steps = i64::from_str_radix(hex_steps, 16).map_err(|_| invalid_color())?;
 
-    let steps = isize::from_str_radix(&hex_steps, 16).unwrap();
+    Ok((direction, steps))
+}
 
-    (direction, steps)
+pub fn parse_instructions(input: &str) -> Result<Vec<DigInstruction>, ParseError> {
+    parse_lines(input, DigInstruction::from_text)
 }
 
-pub fn parse_instructions(input: &str) -> Vec<DigInstruction> {
-    input.lines().map(DigInstruction::from_text).collect()
+pub fn parse_instructions_by_color(input: &str) -> Result<Vec<DigInstruction>, ParseError> {
+    parse_lines(input, DigInstruction::from_text_by_color)
 }
 
-pub fn parse_instructions_by_color(input: &str) -> Vec<DigInstruction> {
-    input
-        .lines()
-        .map(DigInstruction::from_text_by_color)
-        .collect()
+pub fn parse_dig_plan(input: &str) -> Result<DigPlan, ParseError> {
+    Ok(DigPlan {
+        instructions: parse_instructions(input)?,
+        color_instructions: parse_instructions_by_color(input)?,
+    })
 }
 
 #[cfg(test)]
@@ -71,7 +96,7 @@ mod tests {
     fn parses_instruction() {
         let input = "R 6 (#70c710)";
 
-        let result = DigInstruction::from_text(input);
+        let result = DigInstruction::from_text(input).unwrap();
 
         assert_eq!(result.direction, Direction::Right);
         assert_eq!(result.steps, 6);
@@ -81,9 +106,18 @@ mod tests {
     fn parses_instruction_using_color() {
         let input = "R 6 (#70c710)";
 
-        let result = DigInstruction::from_text_by_color(input);
+        let result = DigInstruction::from_text_by_color(input).unwrap();
 
         assert_eq!(result.direction, Direction::Right);
         assert_eq!(result.steps, 461937);
     }
+
+    #[test]
+    fn rejects_malformed_instructions() {
+        let error = parse_dig_plan("R 6 (#70c710)\nX 5 (#0dc571)").unwrap_err();
+        assert_eq!(error, ParseError::new(2, 1, "invalid direction 'X'"));
+
+        let error = parse_dig_plan("R 6 (#70c710)\nD 5 (#0dc574)").unwrap_err();
+        assert_eq!(error, ParseError::new(2, 5, "invalid color '(#0dc574)'"));
+    }
 }
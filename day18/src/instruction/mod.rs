@@ -2,6 +2,9 @@ mod apply;
 mod create;
 mod model;
 
-pub use apply::{count_perimeter_points, instructions_to_vertices};
+pub use apply::instructions_to_vertices;
+pub use create::parse_dig_plan;
+pub use model::{DigInstruction, DigPlan};
+
+#[cfg(test)]
 pub use create::{parse_instructions, parse_instructions_by_color};
-pub use model::DigInstruction;
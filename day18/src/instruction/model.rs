@@ -1,6 +1,14 @@
 use crate::position_2d::Direction;
 
+#[derive(Debug)]
 pub struct DigInstruction {
-    pub steps: isize,
+    pub steps: i64,
     pub direction: Direction,
 }
+
+// the same plan read from the direction and steps, and from the color codes
+#[derive(Debug)]
+pub struct DigPlan {
+    pub instructions: Vec<DigInstruction>,
+    pub color_instructions: Vec<DigInstruction>,
+}
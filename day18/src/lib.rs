@@ -0,0 +1,64 @@
+mod instruction;
+mod position_2d;
+
+use common::{Answer, ParseError, Solution};
+use instruction::{instructions_to_vertices, parse_dig_plan, DigInstruction, DigPlan};
+use math::lattice_points;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = DigPlan;
+
+    fn parse(text: &str) -> Result<DigPlan, ParseError> {
+        parse_dig_plan(text)
+    }
+
+    fn part1(input: &DigPlan) -> Answer {
+        find_dug_out_area_by_formula(&input.instructions).into()
+    }
+
+    fn part2(input: &DigPlan) -> Answer {
+        find_dug_out_area_by_formula(&input.color_instructions).into()
+    }
+}
+
+// the trench is every lattice point on or inside the polygon traced by the dig instructions
+fn find_dug_out_area_by_formula(instructions: &Vec<DigInstruction>) -> i128 {
+    let vertices = instructions_to_vertices(instructions);
+
+    lattice_points(&vertices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instruction::{parse_instructions, parse_instructions_by_color};
+    use common::check_examples;
+
+    #[test]
+    fn determines_dug_out_area() {
+        let text = "R 6 (#70c710)\nD 5 (#0dc571)\nL 2 (#5713f0)\nD 2 (#d2c081)\nR 2 (#59c680)\nD 2 (#411b91)\nL 5 (#8ceee2)\nU 2 (#caa173)\nL 1 (#1b58a2)\nU 2 (#caa171)\nR 2 (#7807d2)\nU 3 (#a77fa3)\nL 2 (#015232)\nU 2 (#7a21e3)";
+
+        let instructions = parse_instructions(text).unwrap();
+        let result = find_dug_out_area_by_formula(&instructions);
+
+        assert_eq!(result, 62);
+    }
+
+    #[test]
+    fn determines_dug_out_area_for_color_instructions() {
+        let text = "R 6 (#70c710)\nD 5 (#0dc571)\nL 2 (#5713f0)\nD 2 (#d2c081)\nR 2 (#59c680)\nD 2 (#411b91)\nL 5 (#8ceee2)\nU 2 (#caa173)\nL 1 (#1b58a2)\nU 2 (#caa171)\nR 2 (#7807d2)\nU 3 (#a77fa3)\nL 2 (#015232)\nU 2 (#7a21e3)";
+
+        let instructions = parse_instructions_by_color(text).unwrap();
+
+        let result = find_dug_out_area_by_formula(&instructions);
+
+        assert_eq!(result, 952408144115);
+    }
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day18>(include_str!("../readme.md"));
+    }
+}
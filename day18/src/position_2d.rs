@@ -1,4 +1,4 @@
-pub type Position = (isize, isize);
+pub type Position = (i64, i64);
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum Direction {
@@ -0,0 +1,130 @@
+mod constants;
+mod part;
+mod workflow;
+
+use common::{Answer, ParseError, Solution};
+use part::{parse_parts, Part, PartCombination};
+use std::collections::HashMap;
+use workflow::{parse_workflows, Workflow, WorkflowResult};
+
+pub struct Day19;
+
+#[derive(Debug)]
+pub struct PartSorting {
+    workflows: HashMap<String, Workflow>,
+    parts: Vec<Part>,
+}
+
+impl Solution for Day19 {
+    type Input = PartSorting;
+
+    fn parse(text: &str) -> Result<PartSorting, ParseError> {
+        parse_part_sorting(text)
+    }
+
+    fn part1(input: &PartSorting) -> Answer {
+        find_accepted_component_rating(&input.workflows, &input.parts).into()
+    }
+
+    fn part2(input: &PartSorting) -> Answer {
+        find_accepted_combinations(&input.workflows).into()
+    }
+}
+
+fn parse_part_sorting(text: &str) -> Result<PartSorting, ParseError> {
+    let (workflows, parts) = text.split_once("\n\n").unwrap_or((text, ""));
+
+    Ok(PartSorting {
+        workflows: parse_workflows(workflows)?,
+        parts: parse_parts(parts).map_err(|error| error.within(text, parts))?,
+    })
+}
+
+fn find_accepted_component_rating(workflows: &HashMap<String, Workflow>, parts: &[Part]) -> u32 {
+    let first_workflow = workflows
+        .get("in")
+        .expect("workflow 'in' is checked when parsing");
+
+    parts
+        .iter()
+        .map(|part| {
+            let mut workflow = first_workflow;
+
+            loop {
+                let result = workflow.apply_to_part(part);
+                match result {
+                    WorkflowResult::Accept => return part.get_rating(),
+                    WorkflowResult::Reject => return 0,
+                    WorkflowResult::Next(id) => {
+                        workflow = workflows
+                            .get(&id)
+                            .expect("sent-to workflows are checked when parsing");
+                        continue;
+                    }
+                }
+            }
+        })
+        .sum()
+}
+
+fn find_accepted_combinations(workflows: &HashMap<String, Workflow>) -> u64 {
+    let mut accepted_parts = vec![];
+    let mut parts = vec![PartCombination::new()];
+
+    while let Some(next_part) = parts.pop() {
+        match &next_part.destination {
+            WorkflowResult::Accept => accepted_parts.push(next_part),
+            WorkflowResult::Reject => {}
+            WorkflowResult::Next(id) => {
+                let workflow = workflows
+                    .get(id)
+                    .expect("sent-to workflows are checked when parsing");
+                parts.append(&mut workflow.find_accepted_combinations(&next_part))
+            }
+        }
+    }
+
+    accepted_parts
+        .iter()
+        .map(|part| part.get_combinations())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn finds_accepted_component_rating() {
+        let text= "px{a<2006:qkq,m>2090:A,rfg}\npv{a>1716:R,A}\nlnx{m>1548:A,A}\nrfg{s<537:gd,x>2440:R,A}\nqs{s>3448:A,lnx}\nqkq{x<1416:A,crn}\ncrn{x>2662:A,R}\nin{s<1351:px,qqz}\nqqz{s>2770:qs,m<1801:hdj,R}\ngd{a>3333:R,R}\nhdj{m>838:A,pv}\n\n{x=787,m=2655,a=1222,s=2876}\n{x=1679,m=44,a=2067,s=496}\n{x=2036,m=264,a=79,s=2244}\n{x=2461,m=1339,a=466,s=291}\n{x=2127,m=1623,a=2188,s=1013}";
+
+        let sorting = parse_part_sorting(text).unwrap();
+        let result = find_accepted_component_rating(&sorting.workflows, &sorting.parts);
+
+        assert_eq!(result, 19114);
+    }
+
+    #[test]
+    fn finds_possible_accepted_rating() {
+        let text= "px{a<2006:qkq,m>2090:A,rfg}\npv{a>1716:R,A}\nlnx{m>1548:A,A}\nrfg{s<537:gd,x>2440:R,A}\nqs{s>3448:A,lnx}\nqkq{x<1416:A,crn}\ncrn{x>2662:A,R}\nin{s<1351:px,qqz}\nqqz{s>2770:qs,m<1801:hdj,R}\ngd{a>3333:R,R}\nhdj{m>838:A,pv}";
+
+        let workflows = parse_workflows(text).unwrap();
+        let result = find_accepted_combinations(&workflows);
+
+        assert_eq!(result, 167409079868000);
+    }
+
+    #[test]
+    fn reports_position_of_bad_part() {
+        let error =
+            parse_part_sorting("in{x<10:A,R}\n\n{x=1,m=2,a=3,s=4}\n{x=1,m=2,a=3,s=}").unwrap_err();
+
+        assert_eq!(error, ParseError::new(4, 16, "invalid number ''"));
+    }
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day19>(include_str!("../readme.md"));
+    }
+}
@@ -1,3 +1,5 @@
+use common::ParseError;
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum PartFeature {
     ExtremelyCool,
@@ -7,12 +9,25 @@ pub enum PartFeature {
 }
 
 impl PartFeature {
-    pub fn from_symbol(symbol: char) -> PartFeature {
+    pub const ALL: [PartFeature; 4] = [
+        PartFeature::ExtremelyCool,
+        PartFeature::Musical,
+        PartFeature::Aerodynamic,
+        PartFeature::Shiny,
+    ];
+
+    pub fn from_symbol(symbol: &str) -> Option<PartFeature> {
         match symbol {
-            'a' => PartFeature::Aerodynamic,
-            'x' => PartFeature::ExtremelyCool,
-            's' => PartFeature::Shiny,
-            'm' | _ => PartFeature::Musical,
+            "a" => Some(PartFeature::Aerodynamic),
+            "x" => Some(PartFeature::ExtremelyCool),
+            "s" => Some(PartFeature::Shiny),
+            "m" => Some(PartFeature::Musical),
+            _ => None,
         }
     }
 }
+
+pub fn parse_part_feature(text: &str, symbol: &str) -> Result<PartFeature, ParseError> {
+    PartFeature::from_symbol(symbol)
+        .ok_or_else(|| ParseError::at(text, symbol, format!("unknown category '{symbol}'")))
+}
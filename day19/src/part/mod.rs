@@ -1,7 +1,8 @@
 mod feature;
+#[allow(clippy::module_inception)]
 mod part;
 mod part_combination;
 
-pub use feature::PartFeature;
+pub use feature::{parse_part_feature, PartFeature};
 pub use part::{parse_parts, Part};
 pub use part_combination::PartCombination;
@@ -1,4 +1,5 @@
-use super::PartFeature;
+use super::{feature::parse_part_feature, PartFeature};
+use common::{parse_lines, parse_number, ParseError};
 use std::collections::HashMap;
 
 #[derive(Debug)]
@@ -7,21 +8,36 @@ pub struct Part {
 }
 
 impl Part {
-    pub fn from_text(text: &str) -> Part {
-        let text: String = text.chars().skip(1).take(text.len() - 2).collect();
+    pub fn from_text(text: &str) -> Result<Part, ParseError> {
+        let ratings = text
+            .strip_prefix('{')
+            .and_then(|inner| inner.strip_suffix('}'))
+            .ok_or_else(|| ParseError::at(text, text, "expected '{x=..,m=..,a=..,s=..}'"))?;
 
         let mut features = HashMap::new();
 
-        text.split(',').for_each(|inner| {
-            let symbol = inner.chars().nth(0).unwrap();
-            let key = PartFeature::from_symbol(symbol);
+        for inner in ratings.split(',') {
+            let (symbol, value) = inner
+                .split_once('=')
+                .ok_or_else(|| ParseError::at(text, inner, "expected '<category>=<rating>'"))?;
 
-            let value = inner.chars().skip(2).collect::<String>().parse().unwrap();
+            let key = parse_part_feature(text, symbol)?;
 
-            features.insert(key, value);
-        });
+            features.insert(key, parse_number(text, value)?);
+        }
 
-        Part { features }
+        if PartFeature::ALL
+            .iter()
+            .any(|feature| !features.contains_key(feature))
+        {
+            return Err(ParseError::at(
+                text,
+                text,
+                "expected ratings for x, m, a and s",
+            ));
+        }
+
+        Ok(Part { features })
     }
 }
 
@@ -31,8 +47,8 @@ impl Part {
     }
 }
 
-pub fn parse_parts(text: &str) -> Vec<Part> {
-    text.lines().map(Part::from_text).collect()
+pub fn parse_parts(text: &str) -> Result<Vec<Part>, ParseError> {
+    parse_lines(text, Part::from_text)
 }
 
 #[cfg(test)]
@@ -41,8 +57,17 @@ mod tests {
 
     #[test]
     fn constructs_part_from_text() {
-        let part = Part::from_text("{x=1072,m=503,a=517,s=1973}");
+        let part = Part::from_text("{x=1072,m=503,a=517,s=1973}").unwrap();
 
         assert_eq!(part.features.len(), 4);
     }
+
+    #[test]
+    fn rejects_malformed_parts() {
+        let error = Part::from_text("{x=1072,q=503,a=517,s=1973}").unwrap_err();
+        assert_eq!(error, ParseError::new(1, 9, "unknown category 'q'"));
+
+        let error = Part::from_text("{x=1072,m=503,a=517}").unwrap_err();
+        assert_eq!(error.message, "expected ratings for x, m, a and s");
+    }
 }
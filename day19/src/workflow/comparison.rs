@@ -1,7 +1,8 @@
 use crate::{
     constants::{MAX_RATING, MIN_RATING},
-    part::PartFeature,
+    part::{parse_part_feature, PartFeature},
 };
+use common::{parse_number, ParseError};
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum ComparisonOperator {
@@ -10,9 +11,9 @@ pub enum ComparisonOperator {
 }
 
 impl ComparisonOperator {
-    pub fn from_symbol(symbol: char) -> ComparisonOperator {
+    pub fn from_symbol(symbol: &str) -> ComparisonOperator {
         match symbol {
-            '>' => ComparisonOperator::GreaterThan,
+            ">" => ComparisonOperator::GreaterThan,
             _ => ComparisonOperator::LessThan,
         }
     }
@@ -26,23 +27,19 @@ pub struct WorkflowComparison {
 }
 
 impl WorkflowComparison {
-    pub fn from_text(text: &str) -> WorkflowComparison {
-        let operator_index = text.find(|char| char == '>' || char == '<').unwrap();
-
-        let operator = ComparisonOperator::from_symbol(text.chars().nth(operator_index).unwrap());
-
-        let rhs = text
-            .chars()
-            .skip(operator_index + 1)
-            .collect::<String>()
-            .parse()
-            .unwrap();
-
-        WorkflowComparison {
-            lhs: PartFeature::from_symbol(text.chars().nth(0).unwrap()),
-            operator,
-            rhs,
-        }
+    pub fn from_text(text: &str) -> Result<WorkflowComparison, ParseError> {
+        let operator_index = text
+            .find(['>', '<'])
+            .ok_or_else(|| ParseError::at(text, text, "expected '<category><operator><rating>'"))?;
+
+        let (lhs, rest) = text.split_at(operator_index);
+        let (operator, rhs) = rest.split_at(1);
+
+        Ok(WorkflowComparison {
+            lhs: parse_part_feature(text, lhs)?,
+            operator: ComparisonOperator::from_symbol(operator),
+            rhs: parse_number(text, rhs)?,
+        })
     }
 }
 
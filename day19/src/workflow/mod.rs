@@ -1,7 +1,8 @@
 mod comparison;
 mod step;
+#[allow(clippy::module_inception)]
 mod workflow;
 
 pub use comparison::{ComparisonOperator, WorkflowComparison};
 pub use step::WorkflowStep;
-pub use workflow::{parse_workflows, WorkflowResult};
+pub use workflow::{parse_workflows, Workflow, WorkflowResult};
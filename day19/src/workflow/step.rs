@@ -1,5 +1,6 @@
 use super::{ComparisonOperator, WorkflowComparison, WorkflowResult};
 use crate::part::{Part, PartCombination};
+use common::ParseError;
 
 #[derive(Debug)]
 pub struct WorkflowStep {
@@ -8,23 +9,20 @@ pub struct WorkflowStep {
 }
 
 impl WorkflowStep {
-    pub fn from_text(text: &str) -> WorkflowStep {
-        let components: Vec<&str> = text.split(':').collect();
-
-        let target = WorkflowResult::from_text(components.last().unwrap());
-
-        match components.len() == 1 {
-            true => WorkflowStep {
+    pub fn from_text(text: &str) -> Result<WorkflowStep, ParseError> {
+        match text.split_once(':') {
+            None => Ok(WorkflowStep {
                 comparison: None,
-                target,
-            },
-            _ => {
-                let comparison_text = components.first().unwrap();
-
-                WorkflowStep {
-                    comparison: Some(WorkflowComparison::from_text(comparison_text)),
-                    target,
-                }
+                target: WorkflowResult::from_text(text),
+            }),
+            Some((comparison_text, target)) => {
+                let comparison = WorkflowComparison::from_text(comparison_text)
+                    .map_err(|error| error.within(text, comparison_text))?;
+
+                Ok(WorkflowStep {
+                    comparison: Some(comparison),
+                    target: WorkflowResult::from_text(target),
+                })
             }
         }
     }
@@ -38,11 +36,11 @@ impl WorkflowStep {
 
         let WorkflowComparison { lhs, operator, rhs } = &self.comparison.clone().unwrap();
 
-        let characteristic = part.features.get(&lhs).unwrap();
+        let characteristic = part.features.get(lhs).unwrap();
 
         let result = match operator {
-            ComparisonOperator::GreaterThan => characteristic > &rhs,
-            _ => characteristic < &rhs,
+            ComparisonOperator::GreaterThan => characteristic > rhs,
+            _ => characteristic < rhs,
         };
 
         match result {
@@ -97,7 +95,7 @@ mod tests {
 
     #[test]
     fn constructs_workflow_step_from_text() {
-        let step = WorkflowStep::from_text("a<2168:czk");
+        let step = WorkflowStep::from_text("a<2168:czk").unwrap();
 
         assert!(step.comparison.is_some());
 
@@ -1,5 +1,6 @@
 use super::WorkflowStep;
 use crate::part::{Part, PartCombination};
+use common::{parse_lines, ParseError};
 use std::collections::HashMap;
 
 #[derive(Debug, PartialEq, Clone)]
@@ -26,33 +27,29 @@ pub struct Workflow {
 }
 
 impl Workflow {
-    pub fn from_text(text: &str) -> Workflow {
-        let steps_start = text.find('{').unwrap();
-        let id = text.chars().take(steps_start).collect();
-
-        let steps_start = steps_start + 1;
-        let steps_text: String = text
-            .chars()
-            .skip(steps_start)
-            .take(text.len() - steps_start - 1)
-            .collect();
+    pub fn from_text(text: &str) -> Result<Workflow, ParseError> {
+        let (id, steps_text) = text
+            .strip_suffix('}')
+            .and_then(|text| text.split_once('{'))
+            .ok_or_else(|| ParseError::at(text, text, "expected '<id>{<steps>}'"))?;
 
         let steps = steps_text
             .split(',')
-            .map(|text| WorkflowStep::from_text(text))
-            .collect();
+            .map(|step| WorkflowStep::from_text(step).map_err(|error| error.within(text, step)))
+            .collect::<Result<Vec<WorkflowStep>, ParseError>>()?;
 
-        Workflow { id, steps }
+        Ok(Workflow {
+            id: id.to_string(),
+            steps,
+        })
     }
 }
 
 impl Workflow {
     pub fn apply_to_part(&self, part: &Part) -> WorkflowResult {
         for step in &self.steps {
-            let result = step.apply_to_part(part);
-
-            if result.is_some() {
-                return result.unwrap();
+            if let Some(result) = step.apply_to_part(part) {
+                return result;
             }
         }
 
@@ -83,15 +80,40 @@ impl Workflow {
     }
 }
 
-pub fn parse_workflows(text: &str) -> HashMap<String, Workflow> {
-    let mut result = HashMap::new();
+pub fn parse_workflows(text: &str) -> Result<HashMap<String, Workflow>, ParseError> {
+    let workflows = parse_lines(text, Workflow::from_text)?;
 
-    text.lines().for_each(|inner| {
-        let workflow = Workflow::from_text(inner);
-        result.insert(workflow.id.to_string(), workflow);
-    });
+    // every sent-to workflow must exist, as must the one parts start in
+    for line in text.lines() {
+        let unknown = step_targets(line).find(|target| {
+            !matches!(*target, "A" | "R") && !workflows.iter().any(|other| &other.id == target)
+        });
+
+        if let Some(id) = unknown {
+            return Err(ParseError::at(text, id, format!("unknown workflow '{id}'")));
+        }
+    }
+
+    if !workflows.iter().any(|workflow| workflow.id == "in") {
+        return Err(ParseError::new(1, 1, "missing workflow 'in'"));
+    }
 
-    result
+    Ok(workflows
+        .into_iter()
+        .map(|workflow| (workflow.id.to_string(), workflow))
+        .collect())
+}
+
+// the target of each step of a workflow line that has already been parsed
+fn step_targets(line: &str) -> impl Iterator<Item = &str> {
+    let steps = line
+        .strip_suffix('}')
+        .and_then(|line| line.split_once('{'))
+        .map_or("", |(_, steps)| steps);
+
+    steps
+        .split(',')
+        .map(|step| step.split_once(':').map_or(step, |(_, target)| target))
 }
 
 #[cfg(test)]
@@ -100,15 +122,34 @@ mod tests {
 
     #[test]
     fn constructs_workflow_from_text() {
-        let workflow = Workflow::from_text("dcz{a<2168:czk,a>3385:xnc,x>889:gpr,nmv}");
+        let workflow = Workflow::from_text("dcz{a<2168:czk,a>3385:xnc,x>889:gpr,nmv}").unwrap();
 
         assert_eq!(workflow.id, "dcz");
         assert_eq!(workflow.steps.len(), 4);
     }
 
+    #[test]
+    fn rejects_malformed_workflows() {
+        let error = parse_workflows("in{a<2006:A,R}\npx{a<x:A,R}").unwrap_err();
+        assert_eq!(error, ParseError::new(2, 6, "invalid number 'x'"));
+
+        let error = parse_workflows("in{a<2006:qkq,R}").unwrap_err();
+        assert_eq!(error.message, "unknown workflow 'qkq'");
+
+        let error = parse_workflows(
+            "in{a<2006:A,px}
+px{s>10:R,qs}",
+        )
+        .unwrap_err();
+        assert_eq!(error, ParseError::new(2, 11, "unknown workflow 'qs'"));
+
+        let error = parse_workflows("px{a<2006:A,R}").unwrap_err();
+        assert_eq!(error.message, "missing workflow 'in'");
+    }
+
     #[test]
     fn finds_accepted_combinations() {
-        let workflow = Workflow::from_text("dcz{a<2168:czk,a>3385:xnc,x>889:gpr,nmv}");
+        let workflow = Workflow::from_text("dcz{a<2168:czk,a>3385:xnc,x>889:gpr,nmv}").unwrap();
 
         let part = PartCombination::new();
         let result = workflow.find_accepted_combinations(&part);
@@ -116,7 +157,7 @@ mod tests {
         assert_eq!(result.len(), 4);
 
         assert_eq!(
-            result.get(0).unwrap(),
+            result.first().unwrap(),
             &PartCombination::with_values(
                 (1, 2167),
                 (1, 4000),
@@ -162,7 +203,7 @@ mod tests {
 
     #[test]
     fn finds_accepted_combinations_other_workflow() {
-        let workflow = Workflow::from_text("px{a<2006:qkq,m>2090:A,rfg}");
+        let workflow = Workflow::from_text("px{a<2006:qkq,m>2090:A,rfg}").unwrap();
 
         let part = PartCombination::new();
         let result = workflow.find_accepted_combinations(&part);
@@ -170,7 +211,7 @@ mod tests {
         assert_eq!(result.len(), 3);
 
         assert_eq!(
-            result.get(0).unwrap(),
+            result.first().unwrap(),
             &PartCombination::with_values(
                 (1, 2005),
                 (1, 4000),
@@ -1,56 +1,104 @@
-use std::cmp::max;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+};
 
-#[derive(Debug)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct CubeDraw {
-    pub red: u16,
-    pub green: u16,
-    pub blue: u16,
+    // colours that were not drawn are left out
+    pub counts: BTreeMap<String, u32>,
 }
 
 impl CubeDraw {
-    pub fn new(red: u16, green: u16, blue: u16) -> CubeDraw {
-        CubeDraw { red, green, blue }
-    }
+    pub fn new<'a>(counts: impl IntoIterator<Item = (&'a str, u32)>) -> CubeDraw {
+        let mut draw = CubeDraw::default();
 
-    pub fn default() -> CubeDraw {
-        CubeDraw {
-            red: 0,
-            green: 0,
-            blue: 0,
+        for (colour, count) in counts {
+            draw.add(colour, count);
         }
+
+        draw
+    }
+
+    pub fn count(&self, colour: &str) -> u32 {
+        self.counts.get(colour).copied().unwrap_or(0)
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.counts.keys().map(String::as_str)
+    }
+}
+
+impl Display for CubeDraw {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let counts: Vec<String> = self
+            .counts
+            .iter()
+            .map(|(colour, count)| format!("{count} {colour}"))
+            .collect();
+
+        write!(f, "{}", counts.join(", "))
     }
 }
 
 impl CubeDraw {
-    pub fn combine_with(&self, other: CubeDraw) -> CubeDraw {
-        CubeDraw {
-            red: self.red + other.red,
-            green: self.green + other.green,
-            blue: self.blue + other.blue,
+    pub fn add(&mut self, colour: &str, count: u32) {
+        let total = self.counts.entry(colour.to_string()).or_default();
+        *total = total.checked_add(count).expect("cube count overflows u32");
+    }
+
+    // none when the colour's total would not fit in a u32
+    pub fn checked_add(mut self, colour: &str, count: u32) -> Option<CubeDraw> {
+        let total = self.count(colour).checked_add(count)?;
+        self.counts.insert(colour.to_string(), total);
+
+        Some(self)
+    }
+
+    pub fn combine_with(mut self, other: CubeDraw) -> CubeDraw {
+        for (colour, count) in other.counts {
+            self.add(&colour, count);
         }
+
+        self
     }
 
-    pub fn find_highest(&self, other: &CubeDraw) -> CubeDraw {
-        CubeDraw {
-            red: max(self.red, other.red),
-            green: max(self.green, other.green),
-            blue: max(self.blue, other.blue),
+    pub fn find_highest(mut self, other: &CubeDraw) -> CubeDraw {
+        for (colour, count) in &other.counts {
+            let highest = self.counts.entry(colour.clone()).or_default();
+            *highest = (*highest).max(*count);
         }
+
+        self
     }
 
-    pub fn cube(&self) -> u16 {
-        self.red * self.green * self.blue
+    // a colour that is not in the draw stays out of it
+    pub fn reduced(&self, colour: &str, by: u32) -> CubeDraw {
+        let mut reduced = self.clone();
+        if let Some(count) = reduced.counts.get_mut(colour) {
+            *count = count.saturating_sub(by);
+        }
+
+        reduced
+    }
+
+    // product of the counts of the given colours, so a colour that was never drawn makes it 0;
+    // none when the product does not fit in u128
+    pub fn cube<'a>(&self, colours: impl IntoIterator<Item = &'a str>) -> Option<u128> {
+        colours.into_iter().try_fold(1_u128, |product, colour| {
+            product.checked_mul(self.count(colour) as u128)
+        })
     }
 }
 
 #[derive(Debug)]
 pub struct CubeGame {
-    pub id: u16,
+    pub id: u32,
     pub draws: Vec<CubeDraw>,
 }
 
 impl CubeGame {
-    pub fn new(id: u16, draws: Vec<CubeDraw>) -> CubeGame {
+    pub fn new(id: u32, draws: Vec<CubeDraw>) -> CubeGame {
         CubeGame { id, draws }
     }
 
@@ -63,26 +111,79 @@ impl CubeGame {
     }
 }
 
+// every colour drawn in any of the games
+pub fn find_colours(games: &[CubeGame]) -> BTreeSet<&str> {
+    games
+        .iter()
+        .flat_map(|game| &game.draws)
+        .flat_map(CubeDraw::colours)
+        .collect()
+}
+
 pub type GameState = CubeDraw;
 
 impl GameState {
+    // colours missing from the bag hold no cubes
     pub fn is_game_draw_possible(&self, draw: &CubeDraw) -> bool {
-        self.red >= draw.red && self.green >= draw.green && self.blue >= draw.blue
+        draw.counts
+            .iter()
+            .all(|(colour, count)| self.count(colour) >= *count)
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod test_find_digit {
     use super::*;
 
     #[test]
     fn is_game_draw_possible_works() {
-        let state = GameState::new(12, 13, 14);
+        let state = GameState::new([("red", 12), ("green", 13), ("blue", 14)]);
 
-        let draw = CubeDraw::new(14, 3, 15);
+        let draw = CubeDraw::new([("red", 14), ("green", 3), ("blue", 15)]);
         assert_eq!(state.is_game_draw_possible(&draw), false);
 
-        let draw = CubeDraw::new(4, 2, 6);
+        let draw = CubeDraw::new([("red", 4), ("green", 2), ("blue", 6)]);
         assert_eq!(state.is_game_draw_possible(&draw), true);
+
+        let draw = CubeDraw::new([("red", 1), ("violet", 1)]);
+        assert_eq!(state.is_game_draw_possible(&draw), false);
+    }
+
+    #[test]
+    fn cube_works_over_any_number_of_colours() {
+        let colours = [
+            "red", "orange", "yellow", "green", "blue", "indigo", "violet", "white",
+        ];
+        let draw = CubeDraw::new(colours.map(|colour| (colour, 10_000)));
+
+        assert_eq!(draw.cube(colours), Some(10_u128.pow(32)));
+        assert_eq!(draw.cube(["red", "black"]), Some(0));
+
+        let draw = CubeDraw::new(colours.map(|colour| (colour, 100_000)));
+        assert_eq!(draw.cube(colours), None);
+    }
+
+    #[test]
+    fn adds_counts_without_overflowing() {
+        let draw = CubeDraw::new([("red", 3)]);
+
+        assert_eq!(
+            draw.clone().checked_add("red", 4),
+            Some(CubeDraw::new([("red", 7)]))
+        );
+        assert_eq!(draw.checked_add("red", u32::MAX), None);
+    }
+
+    #[test]
+    fn reduced_leaves_absent_colours_out() {
+        let draw = CubeDraw::new([("red", 3), ("green", 2)]);
+
+        assert_eq!(
+            draw.reduced("red", 5),
+            CubeDraw::new([("red", 0), ("green", 2)])
+        );
+        assert_eq!(draw.reduced("blue", 1), draw);
+        assert_eq!(draw.reduced("blue", 1).to_string(), "2 green, 3 red");
     }
 }
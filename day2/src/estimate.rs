@@ -0,0 +1,376 @@
+use crate::cube::{CubeDraw, CubeGame};
+
+// cubes of each colour the prior allows when no other limit is given
+pub const DEFAULT_MAX_PER_COLOUR: u32 = 30;
+
+#[derive(Debug)]
+pub struct BagPosterior {
+    pub colours: Vec<String>,
+    // probability of each number of cubes, per colour in the order of `colours`
+    marginals: Vec<Vec<(u32, f64)>>,
+    // counts in the order of `colours`
+    most_likely: Vec<u32>,
+}
+
+// Posterior over the bag contents of a game, assuming each draw takes cubes from the full bag
+// without replacement and puts them back afterwards. The prior is uniform over bags holding up
+// to `max_per_colour` cubes of each colour seen in the game.
+//
+// The likelihood of a bag is a factor per colour times a factor of the bag size, so summing or
+// maximising over bags only needs to walk the colours while tracking the size so far.
+pub fn estimate_bag(game: &CubeGame, max_per_colour: u32) -> BagPosterior {
+    let highest = game.find_maximum_draws();
+    let colours: Vec<String> = highest.colours().map(String::from).collect();
+
+    let largest_bag: u32 = colours
+        .iter()
+        .map(|colour| highest.count(colour).max(max_per_colour))
+        .sum();
+    let ln_factorials = ln_factorials(largest_bag);
+
+    // indexed by count; bags with fewer cubes of a colour than were seen at once are impossible
+    let colour_factors: Vec<Vec<f64>> = colours
+        .iter()
+        .map(|colour| {
+            let seen = highest.count(colour);
+
+            (0..=seen.max(max_per_colour))
+                .map(|count| match count < seen {
+                    true => f64::NEG_INFINITY,
+                    false => ln_colour_factor(game, colour, count, &ln_factorials),
+                })
+                .collect()
+        })
+        .collect();
+
+    let size_factors: Vec<f64> = (0..=largest_bag)
+        .map(|size| ln_size_factor(game, size, &ln_factorials))
+        .collect();
+
+    let marginals = (0..colours.len())
+        .map(|index| marginal(&colour_factors, index, &size_factors))
+        .collect();
+
+    BagPosterior {
+        colours,
+        marginals,
+        most_likely: most_likely_counts(&colour_factors, &size_factors),
+    }
+}
+
+// ln of the number of ways the draws could pick their cubes of the colour from `count` of them
+fn ln_colour_factor(game: &CubeGame, colour: &str, count: u32, ln_factorials: &[f64]) -> f64 {
+    game.draws
+        .iter()
+        .map(|draw| ln_binomial(count, draw.count(colour), ln_factorials))
+        .sum()
+}
+
+// ln of one over the number of ways the draws could pick their cubes from a bag of `size`
+fn ln_size_factor(game: &CubeGame, size: u32, ln_factorials: &[f64]) -> f64 {
+    game.draws
+        .iter()
+        .map(|draw| -ln_binomial(size, draw.counts.values().sum(), ln_factorials))
+        .sum()
+}
+
+// ln of the summed weight of the counts of the given colours, indexed by their total
+fn ln_totals<'a>(colour_factors: impl IntoIterator<Item = &'a Vec<f64>>) -> Vec<f64> {
+    colour_factors
+        .into_iter()
+        .fold(vec![0.0], |totals, factors| {
+            let mut combined = vec![f64::NEG_INFINITY; totals.len() + factors.len() - 1];
+
+            for (total, ln_total) in totals.iter().enumerate() {
+                for (count, ln_factor) in factors.iter().enumerate() {
+                    combined[total + count] = ln_add(combined[total + count], ln_total + ln_factor);
+                }
+            }
+
+            combined
+        })
+}
+
+fn marginal(colour_factors: &[Vec<f64>], index: usize, size_factors: &[f64]) -> Vec<(u32, f64)> {
+    let others = ln_totals(
+        colour_factors
+            .iter()
+            .enumerate()
+            .filter(|(other, _)| *other != index)
+            .map(|(_, factors)| factors),
+    );
+
+    let weights: Vec<f64> = colour_factors[index]
+        .iter()
+        .enumerate()
+        .map(|(count, ln_factor)| {
+            let rest = others
+                .iter()
+                .enumerate()
+                .map(|(total, ln_total)| ln_total + size_factors[total + count])
+                .fold(f64::NEG_INFINITY, ln_add);
+
+            ln_factor + rest
+        })
+        .collect();
+
+    let ln_sum = weights.iter().copied().fold(f64::NEG_INFINITY, ln_add);
+
+    weights
+        .iter()
+        .enumerate()
+        .filter(|(_, weight)| weight.is_finite())
+        .map(|(count, weight)| (count as u32, (weight - ln_sum).exp()))
+        .collect()
+}
+
+fn most_likely_counts(colour_factors: &[Vec<f64>], size_factors: &[f64]) -> Vec<u32> {
+    // best weight and counts of the colours so far, indexed by their total
+    let mut best: Vec<(f64, Vec<u32>)> = vec![(0.0, vec![])];
+
+    for factors in colour_factors {
+        let mut next = vec![(f64::NEG_INFINITY, vec![]); best.len() + factors.len() - 1];
+
+        for (total, (ln_weight, counts)) in best.iter().enumerate() {
+            for (count, ln_factor) in factors.iter().enumerate() {
+                let weight = ln_weight + ln_factor;
+
+                if weight > next[total + count].0 {
+                    let mut counts = counts.clone();
+                    counts.push(count as u32);
+                    next[total + count] = (weight, counts);
+                }
+            }
+        }
+
+        best = next;
+    }
+
+    best.into_iter()
+        .enumerate()
+        .map(|(size, (ln_weight, counts))| (ln_weight + size_factors[size], counts))
+        .max_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, counts)| counts)
+        .expect("posterior without bags")
+}
+
+// ln(e^a + e^b)
+fn ln_add(a: f64, b: f64) -> f64 {
+    match a.max(b) {
+        f64::NEG_INFINITY => f64::NEG_INFINITY,
+        high => high + ((a - high).exp() + (b - high).exp()).ln(),
+    }
+}
+
+fn ln_factorials(limit: u32) -> Vec<f64> {
+    let mut table = vec![0.0];
+
+    for number in 1..=limit {
+        table.push(table[number as usize - 1] + (number as f64).ln());
+    }
+
+    table
+}
+
+fn ln_binomial(n: u32, k: u32, ln_factorials: &[f64]) -> f64 {
+    match k <= n {
+        true => {
+            ln_factorials[n as usize] - ln_factorials[k as usize] - ln_factorials[(n - k) as usize]
+        }
+        false => f64::NEG_INFINITY,
+    }
+}
+
+impl BagPosterior {
+    pub fn most_likely_bag(&self) -> CubeDraw {
+        CubeDraw::new(
+            self.colours
+                .iter()
+                .zip(&self.most_likely)
+                .map(|(colour, count)| (colour.as_str(), *count)),
+        )
+    }
+
+    // probability of each number of cubes of the colour
+    pub fn marginal(&self, colour: &str) -> Option<&[(u32, f64)]> {
+        let index = self.colours.iter().position(|known| known == colour)?;
+
+        Some(&self.marginals[index])
+    }
+
+    pub fn mean(&self, colour: &str) -> Option<f64> {
+        let marginal = self.marginal(colour)?;
+
+        Some(
+            marginal
+                .iter()
+                .map(|(count, probability)| *count as f64 * probability)
+                .sum(),
+        )
+    }
+
+    // equal-tailed interval holding at least `mass` of the probability, e.g. 0.9
+    pub fn credible_interval(&self, colour: &str, mass: f64) -> Option<(u32, u32)> {
+        let marginal = self.marginal(colour)?;
+        let tail = (1.0 - mass) / 2.0;
+
+        let quantile = |target: f64| {
+            let mut cumulative = 0.0;
+
+            marginal
+                .iter()
+                .find(|(_, probability)| {
+                    cumulative += probability;
+                    // allow for rounding in the accumulated probabilities
+                    cumulative >= target - 1e-12
+                })
+                .or(marginal.last())
+                .map(|(count, _)| *count)
+        };
+
+        Some((quantile(tail)?, quantile(1.0 - tail)?))
+    }
+
+    // the most likely bag, then the mean and credible interval of every colour
+    pub fn describe(&self, mass: f64) -> String {
+        let mut lines = vec![format!("most likely bag: {}", self.most_likely_bag())];
+
+        for colour in &self.colours {
+            let mean = self.mean(colour).unwrap_or_default();
+            let (low, high) = self.credible_interval(colour, mass).unwrap_or_default();
+
+            lines.push(format!(
+                "{colour}: mean {mean:.2}, {:.0}% between {low} and {high}",
+                mass * 100.0
+            ));
+        }
+
+        lines.join("\n")
+    }
+}
+
+// the estimate for one game of a games file, as `describe` shows it with 90% intervals
+pub fn describe_estimate(
+    games: &[CubeGame],
+    id: u32,
+    max_per_colour: u32,
+) -> Result<String, String> {
+    let game = games
+        .iter()
+        .find(|game| game.id == id)
+        .ok_or(format!("no game with id {id}"))?;
+
+    Ok(estimate_bag(game, max_per_colour).describe(0.9))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_text::text_to_game;
+
+    #[test]
+    fn only_considers_bags_holding_every_draw() {
+        let game = text_to_game("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
+        let posterior = estimate_bag(&game, 8);
+
+        for colour in ["red", "green", "blue"] {
+            let total: f64 = posterior
+                .marginal(colour)
+                .unwrap()
+                .iter()
+                .map(|(_, probability)| probability)
+                .sum();
+            assert!((total - 1.0).abs() < 1e-9);
+        }
+
+        assert_eq!(posterior.marginal("red").unwrap().first().unwrap().0, 4);
+        assert_eq!(posterior.marginal("blue").unwrap().first().unwrap().0, 6);
+        assert_eq!(posterior.marginal("purple"), None);
+    }
+
+    #[test]
+    fn finds_most_likely_bag() {
+        let draws = ["2 red, 2 blue"; 10].join("; ");
+        let game = text_to_game(&format!("Game 1: {draws}")).unwrap();
+        let posterior = estimate_bag(&game, 10);
+
+        assert_eq!(
+            posterior.most_likely_bag(),
+            CubeDraw::new([("red", 2), ("blue", 2)])
+        );
+        assert_eq!(posterior.credible_interval("red", 0.9), Some((2, 2)));
+    }
+
+    #[test]
+    fn rarely_drawn_colours_are_estimated_low() {
+        let mut draws = vec!["3 red"; 15];
+        draws.push("2 red, 1 blue");
+        let game = text_to_game(&format!("Game 1: {}", draws.join("; "))).unwrap();
+        let posterior = estimate_bag(&game, 12);
+
+        let (low, high) = posterior.credible_interval("blue", 0.9).unwrap();
+        assert_eq!(low, 1);
+        assert!(high < 4);
+        assert!(posterior.mean("red").unwrap() > posterior.mean("blue").unwrap());
+    }
+
+    #[test]
+    fn matches_summing_over_every_bag() {
+        let game = text_to_game("Game 1: 2 red, 1 blue; 1 red, 3 green; 1 blue, 1 green").unwrap();
+        let posterior = estimate_bag(&game, 6);
+        let ln_factorials = ln_factorials(18);
+
+        // weight of every bag of up to six cubes per colour, in red, green, blue order
+        let mut bags = vec![];
+        for red in 2..=6 {
+            for green in 3..=6 {
+                for blue in 1..=6 {
+                    let weight = ln_colour_factor(&game, "red", red, &ln_factorials)
+                        + ln_colour_factor(&game, "green", green, &ln_factorials)
+                        + ln_colour_factor(&game, "blue", blue, &ln_factorials)
+                        + ln_size_factor(&game, red + green + blue, &ln_factorials);
+
+                    bags.push(([red, green, blue], weight.exp()));
+                }
+            }
+        }
+        let total: f64 = bags.iter().map(|(_, weight)| weight).sum();
+
+        for (index, colour) in ["red", "green", "blue"].into_iter().enumerate() {
+            for (count, probability) in posterior.marginal(colour).unwrap() {
+                let expected: f64 = bags
+                    .iter()
+                    .filter(|(bag, _)| bag[index] == *count)
+                    .map(|(_, weight)| weight / total)
+                    .sum();
+
+                assert!((probability - expected).abs() < 1e-9);
+            }
+        }
+
+        let (bag, _) = bags.iter().max_by(|(_, a), (_, b)| a.total_cmp(b)).unwrap();
+        assert_eq!(
+            posterior.most_likely_bag(),
+            CubeDraw::new([("red", bag[0]), ("green", bag[1]), ("blue", bag[2])])
+        );
+    }
+
+    #[test]
+    fn describes_estimate_of_game() {
+        let games =
+            vec![text_to_game(&format!("Game 7: {}", ["2 red, 2 blue"; 10].join("; "))).unwrap()];
+
+        assert_eq!(
+            describe_estimate(&games, 7, 10),
+            Ok(String::from(
+                "most likely bag: 2 blue, 2 red\n\
+                 blue: mean 2.06, 90% between 2 and 2\n\
+                 red: mean 2.06, 90% between 2 and 2"
+            ))
+        );
+        assert_eq!(
+            describe_estimate(&games, 8, 10),
+            Err(String::from("no game with id 8"))
+        );
+    }
+}
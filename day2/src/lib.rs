@@ -0,0 +1,57 @@
+mod cube;
+mod estimate;
+mod parse_text;
+mod part1;
+mod part2;
+mod query;
+
+use common::{parse_lines, Answer, ParseError, Solution};
+pub use cube::{CubeDraw, CubeGame};
+pub use estimate::{describe_estimate, estimate_bag, BagPosterior, DEFAULT_MAX_PER_COLOUR};
+use parse_text::text_to_game;
+use part1::solve_part_1;
+use part2::solve_part_2;
+pub use query::{minimal_bag, GameQuery, GameReport, QueryResult, QUERY_USAGE};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<CubeGame>;
+
+    fn parse(text: &str) -> Result<Vec<CubeGame>, ParseError> {
+        parse_lines(text, text_to_game)
+    }
+
+    fn part1(input: &Vec<CubeGame>) -> Answer {
+        solve_part_1(input).into()
+    }
+
+    fn part2(input: &Vec<CubeGame>) -> Answer {
+        solve_part_2(input).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day2>(include_str!("../readme.md"));
+    }
+
+    #[test]
+    fn leaves_overflowing_power_unsolved() {
+        let colours = [
+            "red", "orange", "yellow", "green", "blue", "indigo", "violet", "white",
+        ];
+        let draw: Vec<String> = colours
+            .iter()
+            .map(|colour| format!("100000 {colour}"))
+            .collect();
+        let games = Day2::parse(&format!("Game 1: {}", draw.join(", "))).unwrap();
+
+        assert_eq!(Day2::part2(&games), Answer::Unsolved);
+    }
+}
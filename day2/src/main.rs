@@ -1,12 +1,48 @@
-mod cube;
-mod parse_text;
-mod part1;
-mod part2;
+use common::{print_answers, Solution};
+use day2::{describe_estimate, Day2, GameQuery, DEFAULT_MAX_PER_COLOUR, QUERY_USAGE};
+use std::{env, fs::read_to_string, process::ExitCode};
 
-use part1::solve_part_1;
-use part2::solve_part_2;
+fn main() -> ExitCode {
+    let text = read_to_string("input.txt").unwrap();
+    let args: Vec<String> = env::args().skip(1).collect();
 
-fn main() {
-    solve_part_1("input.txt");
-    solve_part_2("input.txt");
+    if args.is_empty() {
+        print_answers::<Day2>(&text);
+        return ExitCode::SUCCESS;
+    }
+
+    let games = Day2::parse(&text).unwrap();
+
+    // the bag estimate describes a single game rather than checking every game against a bag
+    let output = match args[0].as_str() {
+        "estimate" => parse_estimate(&args[1..])
+            .and_then(|(id, max_per_colour)| describe_estimate(&games, id, max_per_colour)),
+        _ => GameQuery::from_args(&args).map(|query| query.run(&games).to_string()),
+    };
+
+    match output {
+        Ok(output) => println!("{output}"),
+        Err(error) => {
+            eprintln!("error: {error}\n{QUERY_USAGE}");
+            return ExitCode::from(2);
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn parse_estimate(args: &[String]) -> Result<(u32, u32), String> {
+    let parse = |value: &String| {
+        value
+            .parse()
+            .map_err(|_| format!("invalid number '{value}'"))
+    };
+
+    match args {
+        [id] => Ok((parse(id)?, DEFAULT_MAX_PER_COLOUR)),
+        [id, max_per_colour] => Ok((parse(id)?, parse(max_per_colour)?)),
+        _ => Err(String::from(
+            "expected 'estimate <game id> [<max cubes per colour>]'",
+        )),
+    }
 }
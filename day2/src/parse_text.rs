@@ -1,47 +1,64 @@
 use crate::cube::{CubeDraw, CubeGame};
+use common::{parse_number, ParseError};
 
-pub fn text_to_game(text: &str) -> CubeGame {
-    let (name, draws) = text.split_at(text.find(": ").unwrap() + 2);
-    let id = parse_game_id(name).unwrap();
+pub fn text_to_game(text: &str) -> Result<CubeGame, ParseError> {
+    let (name, draws) = text
+        .split_once(": ")
+        .ok_or_else(|| ParseError::at(text, text, "expected 'Game <id>: <draws>'"))?;
 
-    let draws = draws.split("; ").map(parse_draw).collect();
+    let id = parse_game_id(text, name)?;
 
-    CubeGame::new(id, draws)
-}
+    let draws = draws
+        .split("; ")
+        .map(|draw| parse_draw(text, draw))
+        .collect::<Result<Vec<CubeDraw>, ParseError>>()?;
 
-fn parse_game_id(name: &str) -> Option<u16> {
-    let index_start = name.find(' ');
-    let index_end = name.find(':');
+    Ok(CubeGame::new(id, draws))
+}
 
-    if index_start.is_none() || index_end.is_none() {
-        return None;
-    }
+fn parse_game_id(text: &str, name: &str) -> Result<u32, ParseError> {
+    let id = name
+        .strip_prefix("Game ")
+        .ok_or_else(|| ParseError::at(text, name, "expected 'Game <id>'"))?;
 
-    let id = &name[(index_start.unwrap() + 1)..index_end.unwrap()];
+    parse_number(text, id)
+}
 
-    id.parse().ok()
+// a bag is written like a single draw, e.g. "12 red, 13 green, 14 blue"
+pub fn parse_bag(text: &str) -> Result<CubeDraw, ParseError> {
+    parse_draw(text, text)
 }
 
-fn parse_draw(input: &str) -> CubeDraw {
-    let draws = input
+fn parse_draw(text: &str, input: &str) -> Result<CubeDraw, ParseError> {
+    input
         .split(", ")
-        .map(parse_color_draw)
-        .fold(CubeDraw::default(), |previous, next| {
-            previous.combine_with(next)
-        });
+        .try_fold(CubeDraw::default(), |previous, next| {
+            let (count, colour) = parse_color_draw(text, next)?;
 
-    draws
+            previous.checked_add(colour, count).ok_or_else(|| {
+                ParseError::at(text, next, format!("total of {colour} cubes overflows u32"))
+            })
+        })
 }
 
-fn parse_color_draw(input: &str) -> CubeDraw {
-    let (number, color) = input.split_at(input.find(' ').unwrap());
-
-    let number = number.parse().unwrap();
-
-    match color.trim() {
-        "green" => CubeDraw::new(0, number, 0),
-        "blue" => CubeDraw::new(0, 0, number),
-        _ => CubeDraw::new(number, 0, 0),
+fn parse_color_draw<'a>(text: &str, input: &'a str) -> Result<(u32, &'a str), ParseError> {
+    let (number, color) = input.split_once(' ').ok_or_else(|| {
+        ParseError::at(
+            text,
+            input,
+            format!("expected '<count> <colour>', found '{input}'"),
+        )
+    })?;
+
+    let number = parse_number(text, number)?;
+
+    match !color.is_empty() && color.chars().all(char::is_alphabetic) {
+        true => Ok((number, color)),
+        false => Err(ParseError::at(
+            text,
+            color,
+            format!("invalid colour '{color}'"),
+        )),
     }
 }
 
@@ -52,25 +69,65 @@ mod test_find_digit {
     #[test]
     fn correctly_evaluates_game_from_line() {
         let text_line = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
-        let game = text_to_game(text_line);
+        let game = text_to_game(text_line).unwrap();
 
         assert_eq!(game.id, 1);
 
         let higest_draws = game.find_maximum_draws();
 
-        assert_eq!(higest_draws.red, 4);
-        assert_eq!(higest_draws.blue, 6);
-        assert_eq!(higest_draws.green, 2);
+        assert_eq!(higest_draws.count("red"), 4);
+        assert_eq!(higest_draws.count("blue"), 6);
+        assert_eq!(higest_draws.count("green"), 2);
 
         let text_line = "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red";
-        let game = text_to_game(text_line);
+        let game = text_to_game(text_line).unwrap();
 
         assert_eq!(game.id, 4);
 
         let higest_draws = game.find_maximum_draws();
 
-        assert_eq!(higest_draws.red, 14);
-        assert_eq!(higest_draws.blue, 15);
-        assert_eq!(higest_draws.green, 3);
+        assert_eq!(higest_draws.count("red"), 14);
+        assert_eq!(higest_draws.count("blue"), 15);
+        assert_eq!(higest_draws.count("green"), 3);
+    }
+
+    #[test]
+    fn discovers_colours_from_input() {
+        let game = text_to_game("Game 7: 3 teal, 4 red; 2 teal, 1 teal, 5 magenta").unwrap();
+        let highest_draws = game.find_maximum_draws();
+
+        assert_eq!(highest_draws.count("teal"), 3);
+        assert_eq!(highest_draws.count("magenta"), 5);
+        assert_eq!(highest_draws.count("blue"), 0);
+    }
+
+    #[test]
+    fn parses_bags() {
+        let bag = parse_bag("12 red, 13 green, 14 blue").unwrap();
+        assert_eq!(bag.count("green"), 13);
+
+        let error = parse_bag("12 red, 13").unwrap_err();
+        assert_eq!(error.column, 9);
+    }
+
+    #[test]
+    fn rejects_malformed_games() {
+        let error = text_to_game("Game 1: 3 blue, 4 pur-ple").unwrap_err();
+        assert_eq!(error, ParseError::new(1, 19, "invalid colour 'pur-ple'"));
+
+        let error = text_to_game("Game 1: 4294967296 blue").unwrap_err();
+        assert_eq!(error, ParseError::new(1, 9, "invalid number '4294967296'"));
+
+        let error = text_to_game("Game 1: 4000000000 red, 4000000000 red").unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(1, 25, "total of red cubes overflows u32")
+        );
+
+        let error = text_to_game("Game x: 3 blue").unwrap_err();
+        assert_eq!(error, ParseError::new(1, 6, "invalid number 'x'"));
+
+        let error = text_to_game("Game 1: 3blue").unwrap_err();
+        assert_eq!(error.column, 9);
     }
 }
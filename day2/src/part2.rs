@@ -1,20 +1,17 @@
-use crate::parse_text::text_to_game;
-use std::fs::read_to_string;
+use crate::cube::{find_colours, CubeGame};
 
-pub fn solve_part_2(file_path: &str) {
-    let file = read_to_string(file_path).unwrap();
-
-    let mut minimum_cube_sum = 0;
-
-    for line in file.lines() {
-        let game = text_to_game(line);
+// none when a power or their sum does not fit in u128
+pub fn solve_part_2(games: &[CubeGame]) -> Option<u128> {
+    let colours = find_colours(games);
+    let mut minimum_cube_sum: u128 = 0;
 
+    for game in games {
         let highest_draw = game.find_maximum_draws();
 
-        let cube = highest_draw.cube();
+        let cube = highest_draw.cube(colours.iter().copied())?;
 
-        minimum_cube_sum += cube;
+        minimum_cube_sum = minimum_cube_sum.checked_add(cube)?;
     }
 
-    println!("sum of minimum cubes: {minimum_cube_sum}")
+    Some(minimum_cube_sum)
 }
@@ -0,0 +1,239 @@
+use crate::{
+    cube::{find_colours, CubeDraw, CubeGame},
+    parse_text::parse_bag,
+};
+use std::{collections::BTreeSet, fmt::Display};
+
+pub const QUERY_USAGE: &str = "usage:
+  day2 possible <bag>
+  day2 minimal
+  day2 reduce <bag> <colour> <count>
+  day2 estimate <game id> [<max cubes per colour>]
+
+a bag is written like a draw, e.g. \"12 red, 13 green, 14 blue\"";
+
+#[derive(Debug, PartialEq)]
+pub enum GameQuery {
+    // games that could have been played with the bag
+    Possible(CubeDraw),
+    // smallest bag that makes every game possible
+    MinimalBag,
+    // games that are possible with the bag, but not once the colour is reduced
+    Reduced {
+        bag: CubeDraw,
+        colour: String,
+        by: u32,
+    },
+}
+
+impl GameQuery {
+    pub fn from_args(args: &[String]) -> Result<GameQuery, String> {
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+
+        match args[..] {
+            ["possible", bag] => Ok(GameQuery::Possible(read_bag(bag)?)),
+            ["minimal"] => Ok(GameQuery::MinimalBag),
+            ["reduce", bag, colour, by] => Ok(GameQuery::Reduced {
+                bag: read_bag(bag)?,
+                colour: colour.to_string(),
+                by: by.parse().map_err(|_| format!("invalid count '{by}'"))?,
+            }),
+            _ => Err(format!("unknown query '{}'", args.join(" "))),
+        }
+    }
+}
+
+fn read_bag(text: &str) -> Result<CubeDraw, String> {
+    parse_bag(text).map_err(|error| format!("bag: {error}"))
+}
+
+impl GameQuery {
+    pub fn run(&self, games: &[CubeGame]) -> QueryResult {
+        match self {
+            GameQuery::Possible(bag) => {
+                QueryResult::new(bag.clone(), games, |report| report.possible)
+            }
+            GameQuery::MinimalBag => QueryResult::new(minimal_bag(games), games, |_| true),
+            GameQuery::Reduced { bag, colour, by } => {
+                let reduced = bag.reduced(colour, by.to_owned());
+                let possible: BTreeSet<u32> = games
+                    .iter()
+                    .filter(|game| bag.is_game_draw_possible(&game.find_maximum_draws()))
+                    .map(|game| game.id)
+                    .collect();
+
+                QueryResult::new(reduced, games, |report| {
+                    !report.possible && possible.contains(&report.id)
+                })
+            }
+        }
+    }
+}
+
+pub fn minimal_bag(games: &[CubeGame]) -> CubeDraw {
+    games.iter().fold(CubeDraw::default(), |previous, game| {
+        previous.find_highest(&game.find_maximum_draws())
+    })
+}
+
+#[derive(Debug, PartialEq)]
+pub struct GameReport {
+    pub id: u32,
+    pub possible: bool,
+    // power of the smallest bag for this game alone, none when it overflows u128
+    pub power: Option<u128>,
+    // colour with the least room to spare in the bag, or the most missing cubes
+    pub limiting_colour: Option<String>,
+}
+
+impl GameReport {
+    pub fn new(game: &CubeGame, bag: &CubeDraw, colours: &BTreeSet<&str>) -> GameReport {
+        let highest = game.find_maximum_draws();
+
+        let limiting_colour = highest
+            .counts
+            .iter()
+            .min_by_key(|(colour, count)| bag.count(colour) as i64 - **count as i64)
+            .map(|(colour, _)| colour.clone());
+
+        GameReport {
+            id: game.id,
+            possible: bag.is_game_draw_possible(&highest),
+            power: highest.cube(colours.iter().copied()),
+            limiting_colour,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct QueryResult {
+    // bag the games were checked against
+    pub bag: CubeDraw,
+    pub games: Vec<GameReport>,
+}
+
+impl QueryResult {
+    fn new(
+        bag: CubeDraw,
+        games: &[CubeGame],
+        mut select: impl FnMut(&GameReport) -> bool,
+    ) -> QueryResult {
+        let colours = find_colours(games);
+
+        let games = games
+            .iter()
+            .map(|game| GameReport::new(game, &bag, &colours))
+            .filter(|report| select(report))
+            .collect();
+
+        QueryResult { bag, games }
+    }
+
+    pub fn ids(&self) -> Vec<u32> {
+        self.games.iter().map(|report| report.id).collect()
+    }
+}
+
+impl Display for QueryResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "bag: {}", self.bag)?;
+        writeln!(f, "{:<6}{:<10}{:<12}limiting", "id", "possible", "power")?;
+
+        for report in &self.games {
+            let limiting = report.limiting_colour.as_deref().unwrap_or("-");
+
+            writeln!(
+                f,
+                "{:<6}{:<10}{:<12}{limiting}",
+                report.id,
+                report.possible,
+                report
+                    .power
+                    .map_or(String::from("-"), |power| power.to_string())
+            )?;
+        }
+
+        let id_sum: u64 = self.games.iter().map(|report| report.id as u64).sum();
+        write!(f, "{} games, id sum {id_sum}", self.games.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_text::text_to_game;
+
+    fn games() -> Vec<CubeGame> {
+        [
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+            "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue",
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+        ]
+        .into_iter()
+        .map(|line| text_to_game(line).unwrap())
+        .collect()
+    }
+
+    fn args(text: &str) -> Vec<String> {
+        text.split('|').map(String::from).collect()
+    }
+
+    #[test]
+    fn finds_possible_games() {
+        let query = GameQuery::from_args(&args("possible|12 red, 13 green, 14 blue")).unwrap();
+        let result = query.run(&games());
+
+        assert_eq!(result.ids(), vec![1, 2]);
+        assert_eq!(result.games[0].power, Some(48));
+        assert_eq!(result.games[0].limiting_colour.as_deref(), Some("blue"));
+    }
+
+    #[test]
+    fn finds_minimal_bag() {
+        let result = GameQuery::MinimalBag.run(&games());
+
+        assert_eq!(
+            result.bag,
+            CubeDraw::new([("red", 20), ("green", 13), ("blue", 6)])
+        );
+        assert_eq!(result.ids(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn finds_games_made_impossible_by_reduction() {
+        let query = GameQuery::from_args(&args("reduce|12 red, 13 green, 14 blue|blue|9")).unwrap();
+        let result = query.run(&games());
+
+        assert_eq!(result.bag.count("blue"), 5);
+        assert_eq!(result.ids(), vec![1]);
+        assert_eq!(result.games[0].limiting_colour.as_deref(), Some("blue"));
+    }
+
+    #[test]
+    fn rejects_malformed_queries() {
+        let error = GameQuery::from_args(&args("possible|12 red, 13")).unwrap_err();
+        assert_eq!(
+            error,
+            "bag: line 1, column 9: expected '<count> <colour>', found '13'"
+        );
+
+        let error = GameQuery::from_args(&args("reduce|1 red|red|x")).unwrap_err();
+        assert_eq!(error, "invalid count 'x'");
+
+        assert!(GameQuery::from_args(&args("maximal")).is_err());
+    }
+
+    #[test]
+    fn formats_query_results() {
+        let result = GameQuery::MinimalBag.run(&games()[..2]);
+
+        assert_eq!(
+            result.to_string(),
+            "bag: 6 blue, 3 green, 4 red\n\
+             id    possible  power       limiting\n\
+             1     true      48          blue\n\
+             2     true      12          green\n\
+             2 games, id sum 3"
+        );
+    }
+}
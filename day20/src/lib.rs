@@ -0,0 +1,45 @@
+mod module;
+mod module_map;
+mod pulse;
+
+use common::{Answer, ParseError, Solution};
+use module_map::{construct_module_map, process_module_pulses, ModuleMap};
+
+use crate::{module_map::find_minimum_button_pushes_for_pulse, pulse::Pulse};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = ModuleMap;
+
+    fn parse(text: &str) -> Result<ModuleMap, ParseError> {
+        construct_module_map(text)
+    }
+
+    fn part1(input: &ModuleMap) -> Answer {
+        let (low, high) = process_module_pulses(input, Some(1000));
+
+        (low * high).into()
+    }
+
+    fn part2(input: &ModuleMap) -> Answer {
+        let target_pulse = Pulse::new(
+            pulse::PulseIntensity::Low,
+            String::new(),
+            String::from("rx"),
+        );
+
+        find_minimum_button_pushes_for_pulse(input, &target_pulse).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day20>(include_str!("../readme.md"));
+    }
+}
@@ -1,4 +1,5 @@
 mod model;
+#[allow(clippy::module_inception)]
 mod module;
 
 pub use model::{ModuleType, PulseHandler};
@@ -58,6 +58,19 @@ impl Module {
         }
     }
 
+    // flip flop state followed by the last pulse from each input, ordered by input
+    pub fn state(&self) -> (bool, Vec<PulseIntensity>) {
+        let mut inputs: Vec<(&String, &PulseIntensity)> = self.previous_inputs.iter().collect();
+        inputs.sort();
+
+        let intensities = inputs
+            .into_iter()
+            .map(|(_, intensity)| *intensity)
+            .collect();
+
+        (self.turned_on, intensities)
+    }
+
     fn all_inputs_high(&self) -> bool {
         self.previous_inputs
             .values()
@@ -1,56 +1,60 @@
+use common::{parse_lines, ParseError};
 use std::collections::HashMap;
 
 use crate::module::{Module, ModuleType};
 
-pub fn construct_module_map(text: &str) -> HashMap<String, Module> {
+use super::model::ModuleMap;
+
+pub fn construct_module_map(text: &str) -> Result<ModuleMap, ParseError> {
     let mut inputs: HashMap<String, Vec<String>> = HashMap::new();
 
-    let mut modules: HashMap<String, Module> = text
-        .lines()
+    let mut modules: ModuleMap = parse_lines(text, parse_module)?
         .into_iter()
-        .map(|line| {
-            let components: Vec<&str> = line.split_whitespace().collect();
-
-            let name = *components.first().unwrap();
-
-            let id = match name {
-                "broadcaster" => String::from(name),
-                _ => name.chars().skip(1).collect(),
-            };
-
-            let outputs: Vec<String> = components
-                .iter()
-                .skip(2)
-                .map(|value| value.chars().filter(|symbol| symbol != &',').collect())
-                .collect();
-
-            for output in &outputs {
+        .map(|(id, module)| {
+            for output in &module.outputs {
                 let mut target = inputs.get(output).unwrap_or(&vec![]).to_vec();
                 target.push(id.to_string());
 
                 inputs.insert(output.to_string(), target);
             }
 
-            match name {
-                _ if name.contains("&") => (id, Module::new(ModuleType::Conjunction, outputs)),
-                _ if name.contains("%") => (id, Module::new(ModuleType::FlipFlop, outputs)),
-                "broadcaster" => (id, Module::new(ModuleType::Broadcaster, outputs)),
-                _ => (id, Module::new(ModuleType::Untyped, outputs)),
-            }
+            (id, module)
         })
         .collect();
 
     for (id, module) in modules.iter_mut() {
-        let input_modules = inputs.get(id);
-
-        if input_modules.is_none() {
-            continue;
+        if let Some(input_modules) = inputs.get(id) {
+            module.set_inputs(input_modules.to_vec());
         }
-
-        module.set_inputs(input_modules.unwrap().to_vec());
     }
 
-    modules
+    Ok(modules)
+}
+
+fn parse_module(line: &str) -> Result<(String, Module), ParseError> {
+    let (name, outputs) = line
+        .split_once(" -> ")
+        .ok_or_else(|| ParseError::at(line, line, "expected '<module> -> <outputs>'"))?;
+
+    let (module_type, id) = match name.split_at(name.len().min(1)) {
+        ("%", id) => (ModuleType::FlipFlop, id),
+        ("&", id) => (ModuleType::Conjunction, id),
+        _ if name == "broadcaster" => (ModuleType::Broadcaster, name),
+        _ => (ModuleType::Untyped, name),
+    };
+
+    let outputs = outputs
+        .split(',')
+        .map(|output| match output.trim() {
+            "" => Err(ParseError::at(line, output, "expected module name")),
+            output => Ok(output.to_string()),
+        })
+        .collect::<Result<Vec<String>, ParseError>>()?;
+
+    match id {
+        "" => Err(ParseError::at(line, name, "expected module name")),
+        _ => Ok((id.to_string(), Module::new(module_type, outputs))),
+    }
 }
 
 #[cfg(test)]
@@ -61,8 +65,17 @@ mod tests {
     fn constructs_module_map() {
         let text = "broadcaster -> a, b, c\n%a -> b\n%b -> c\n%c -> inv\n&inv -> a";
 
-        let result = construct_module_map(text);
+        let result = construct_module_map(text).unwrap();
 
         assert_eq!(result.len(), 5);
     }
+
+    #[test]
+    fn rejects_malformed_modules() {
+        let error = construct_module_map("broadcaster -> a\n%a -> b,, c").unwrap_err();
+        assert_eq!(error, ParseError::new(2, 9, "expected module name"));
+
+        let error = construct_module_map("broadcaster -> a\n%a b").unwrap_err();
+        assert_eq!(error.line, 2);
+    }
 }
@@ -9,6 +9,18 @@ pub type PulseMap = HashMap<(String, String, PulseIntensity), Vec<u64>>;
 
 pub type ModuleMap = HashMap<String, Module>;
 
+pub type ModuleMapState = Vec<(String, (bool, Vec<PulseIntensity>))>;
+
+pub fn module_map_state(map: &ModuleMap) -> ModuleMapState {
+    let mut state: ModuleMapState = map
+        .iter()
+        .map(|(id, module)| (id.to_string(), module.state()))
+        .collect();
+
+    state.sort();
+    state
+}
+
 pub fn pulse_key(pulse: &Pulse) -> (String, String, PulseIntensity) {
     let Pulse {
         intensity,
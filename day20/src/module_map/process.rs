@@ -1,28 +1,68 @@
-use super::construct_module_map;
-use super::model::{pulse_key, ModuleMap, PulseMap};
+use super::model::{module_map_state, pulse_key, ModuleMap, PulseMap};
 use crate::module::PulseHandler;
 use crate::pulse::{Pulse, PulseIntensity};
+use cycle::CycleDetector;
 use std::collections::HashMap;
 
-pub fn process_module_pulses(text: &str, times: Option<u32>) -> (u32, u32) {
-    let mut map = construct_module_map(text);
+pub fn process_module_pulses(map: &ModuleMap, times: Option<u32>) -> (u32, u32) {
+    let mut map = map.clone();
 
-    let mut low_pulses = 0;
-    let mut high_pulses = 0;
-    let times = times.unwrap_or(1);
+    let times = times.unwrap_or(1) as usize;
+    let mut detector = CycleDetector::new();
+    let mut pushes: Vec<(u32, u32)> = vec![];
 
     for _ in 0..times {
+        // once the modules return to an earlier state, every later push repeats an earlier one
+        if let Some(cycle) = detector.observe(module_map_state(&map)) {
+            let (prefix, repeated) = pushes.split_at(cycle.start);
+            let repeats = (times - cycle.start) / cycle.length;
+            let remainder = (times - cycle.start) % cycle.length;
+
+            let (low, high) = sum_pulses(repeated.iter().copied());
+            let full_cycles = (
+                low.checked_mul(repeats as u32)
+                    .expect("pulse count overflows u32"),
+                high.checked_mul(repeats as u32)
+                    .expect("pulse count overflows u32"),
+            );
+
+            return sum_pulses(
+                prefix
+                    .iter()
+                    .chain(&repeated[..remainder])
+                    .copied()
+                    .chain([full_cycles]),
+            );
+        }
+
         let mut pulses = vec![Pulse::default()];
+        let mut low_pulses = 0;
+        let mut high_pulses = 0;
 
-        while pulses.len() > 0 {
+        while !pulses.is_empty() {
             let (low, high, _) = process_pulse_cycle(&mut map, &mut pulses);
 
             low_pulses += low;
             high_pulses += high;
         }
+
+        pushes.push((low_pulses, high_pulses));
     }
 
-    (low_pulses, high_pulses)
+    sum_pulses(pushes.into_iter())
+}
+
+fn sum_pulses(pushes: impl Iterator<Item = (u32, u32)>) -> (u32, u32) {
+    pushes.fold((0, 0), |(low_pulses, high_pulses), (low, high)| {
+        (
+            low_pulses
+                .checked_add(low)
+                .expect("pulse count overflows u32"),
+            high_pulses
+                .checked_add(high)
+                .expect("pulse count overflows u32"),
+        )
+    })
 }
 
 fn process_pulse_cycle(map: &mut ModuleMap, pulses: &mut Vec<Pulse>) -> (u32, u32, bool) {
@@ -56,7 +96,7 @@ fn handle_pulse(map: &mut ModuleMap, pulse: &Pulse) -> Vec<Pulse> {
 
     let mut new_module = module.unwrap().clone();
 
-    let pulse = new_module.receive_pulse(&pulse);
+    let pulse = new_module.receive_pulse(pulse);
     let outputs = new_module.outputs.to_vec();
 
     map.insert(destination.to_string(), new_module);
@@ -79,11 +119,17 @@ fn handle_pulse(map: &mut ModuleMap, pulse: &Pulse) -> Vec<Pulse> {
 
 pub fn find_pulses_entries(map: &mut ModuleMap, times: u64) -> PulseMap {
     let mut pulse_map = HashMap::new();
+    let mut detector = CycleDetector::new();
 
     for i in 1..=times {
+        // a repeated module state cannot produce pulses that have not been seen before
+        if detector.observe(module_map_state(map)).is_some() {
+            break;
+        }
+
         let mut pulses = vec![Pulse::default()];
 
-        while pulses.len() > 0 {
+        while !pulses.is_empty() {
             find_pulses_in_cycle(map, &mut pulses, &mut pulse_map, i);
         }
     }
@@ -119,12 +165,13 @@ fn find_pulses_in_cycle(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::module_map::construct_module_map;
 
     #[test]
     fn processes_one_pulse_step() {
         let text = "broadcaster -> a, b, c\n%a -> b\n%b -> c\n%c -> inv\n&inv -> a";
 
-        let result = process_module_pulses(text, None);
+        let result = process_module_pulses(&construct_module_map(text).unwrap(), None);
 
         assert_eq!(result, (8, 4));
     }
@@ -133,7 +180,7 @@ mod tests {
     fn processes_one_pulse_step_other_example() {
         let text = "broadcaster -> a\n%a -> inv, con\n&inv -> b\n%b -> con\n&con -> output";
 
-        let result = process_module_pulses(text, None);
+        let result = process_module_pulses(&construct_module_map(text).unwrap(), None);
 
         assert_eq!(result, (4, 4));
     }
@@ -142,11 +189,23 @@ mod tests {
     fn processes_1000_pulse_step() {
         let text = "broadcaster -> a\n%a -> inv, con\n&inv -> b\n%b -> con\n&con -> output";
 
-        let (low, high) = process_module_pulses(text, Some(1000));
+        let (low, high) = process_module_pulses(&construct_module_map(text).unwrap(), Some(1000));
 
         assert_eq!(low, 4250);
         assert_eq!(high, 2750);
 
         assert_eq!(low * high, 11687500);
     }
+
+    #[test]
+    fn sums_repeated_pushes_without_replaying_them() {
+        let text = "broadcaster -> a\n%a -> inv, con\n&inv -> b\n%b -> con\n&con -> output";
+
+        let (low, high) =
+            process_module_pulses(&construct_module_map(text).unwrap(), Some(1_000_001));
+
+        // the four pushes of each cycle send 17 low and 11 high pulses, and the extra push 4 and 4
+        assert_eq!(low, 4_250_004);
+        assert_eq!(high, 2_750_004);
+    }
 }
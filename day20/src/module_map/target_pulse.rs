@@ -1,18 +1,18 @@
+use math::lowest_common_multiple;
 use std::collections::HashMap;
 
 use crate::{
-    math::lowest_common_multiple,
     module::{Module, ModuleType},
     pulse::{Pulse, PulseIntensity},
 };
 
 use super::{
-    construct_module_map, find_pulses_entries,
+    find_pulses_entries,
     model::{pulse_key, ModuleMap, PulseMap},
 };
 
-pub fn find_minimum_button_pushes_for_pulse(text: &str, target_pulse: &Pulse) -> u64 {
-    let mut map = construct_module_map(text);
+pub fn find_minimum_button_pushes_for_pulse(map: &ModuleMap, target_pulse: &Pulse) -> u64 {
+    let mut map = map.clone();
 
     let pulse_map = find_pulses_entries(&mut map, 10_000);
 
@@ -22,7 +22,7 @@ pub fn find_minimum_button_pushes_for_pulse(text: &str, target_pulse: &Pulse) ->
         .keys()
         .map(|id| {
             let pulse = Pulse::new(
-                target_pulse.intensity.clone(),
+                target_pulse.intensity,
                 id.to_string(),
                 target_pulse.destination.clone(),
             );
@@ -64,15 +64,17 @@ fn find_button_pushes_for_pulse(
                 PulseIntensity::Low => PulseIntensity::High,
                 _ => PulseIntensity::Low,
             };
-            lowest_common_multiple(
-                previous
-                    .keys()
-                    .map(|id| {
-                        let pulse = Pulse::new(new_intensity, id.to_string(), origin.to_string());
-                        find_button_pushes_for_pulse(map, pulse_map, &pulse)
-                    })
-                    .collect(),
-            )
+            let pushes: Vec<i128> = previous
+                .keys()
+                .map(|id| {
+                    let pulse = Pulse::new(new_intensity, id.to_string(), origin.to_string());
+                    find_button_pushes_for_pulse(map, pulse_map, &pulse).into()
+                })
+                .collect();
+
+            lowest_common_multiple(&pushes)
+                .and_then(|pushes| u64::try_from(pushes).ok())
+                .expect("button pushes should fit in a u64")
         }
         ModuleType::FlipFlop => 1,
     }
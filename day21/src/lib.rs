@@ -0,0 +1,18 @@
+use crate::map::Map;
+use common::{Answer, ParseError, Solution};
+
+mod map;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Map;
+
+    fn parse(text: &str) -> Result<Map, ParseError> {
+        Map::from_text(text)
+    }
+
+    fn part1(input: &Map) -> Answer {
+        input.reachable_positions(64).into()
+    }
+}
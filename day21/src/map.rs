@@ -1,9 +1,6 @@
-use std::{
-    collections::{HashMap, HashSet},
-    usize,
-};
-
-type Position = (usize, usize);
+use common::ParseError;
+use grid::{Grid, Position};
+use search::{shortest_distances, SearchState};
 
 #[derive(Debug, Clone, Copy)]
 pub enum MapFeature {
@@ -13,31 +10,27 @@ pub enum MapFeature {
 
 #[derive(Debug)]
 pub struct Map {
-    features: HashMap<Position, MapFeature>,
+    features: Grid<MapFeature>,
     start: Position,
 }
 
 impl Map {
-    pub fn from_text(text: &str) -> Map {
-        let mut features = HashMap::new();
-        let mut start = (0, 0);
-
-        text.lines().enumerate().for_each(|(y, line)| {
-            line.chars().enumerate().for_each(|(x, symbol)| {
-                let feature = match symbol {
-                    '.' => MapFeature::Empty,
-                    'S' => {
-                        start = (x, y);
-                        MapFeature::Empty
-                    }
-                    _ => MapFeature::Blocked,
-                };
-
-                features.insert((x, y), feature);
-            })
-        });
+    pub fn from_text(text: &str) -> Result<Map, ParseError> {
+        let mut start = None;
+
+        let features = Grid::parse(text, |position, symbol| match symbol {
+            '.' => Some(MapFeature::Empty),
+            'S' => {
+                start = Some(position);
+                Some(MapFeature::Empty)
+            }
+            '#' => Some(MapFeature::Blocked),
+            _ => None,
+        })?;
 
-        Map { features, start }
+        let start = start.ok_or_else(|| ParseError::new(1, 1, "missing start 'S'"))?;
+
+        Ok(Map { features, start })
     }
 }
 
@@ -47,21 +40,11 @@ impl Map {
     }
 
     pub fn neighboring_positions(&self, position: &Position) -> Vec<Position> {
-        let (x, y) = *position;
-        let mut neighbors = vec![];
-
-        if x > 0 {
-            neighbors.push((x - 1, y));
-        }
-
-        if y > 0 {
-            neighbors.push((x, y - 1));
-        }
-
-        neighbors.push((x, y + 1));
-        neighbors.push((x + 1, y));
-
-        neighbors
+        self.features
+            .neighbors(position)
+            .into_iter()
+            .map(|(_, neighbor)| neighbor)
+            .collect()
     }
 
     pub fn accessible_neighboring_positions(&self, position: &Position) -> Vec<Position> {
@@ -75,35 +58,47 @@ impl Map {
     }
 
     pub fn reachable_positions(&self, steps: usize) -> usize {
-        let start = self.start;
-        let mut current: HashSet<Position> = HashSet::from([start]);
-
-        for _ in 1..=steps {
-            let positions = current.clone();
-            current.clear();
-
-            for position in positions {
-                self.accessible_neighboring_positions(&position)
-                    .iter()
-                    .for_each(|position| {
-                        current.insert(*position);
-                    });
-            }
-        }
+        let steps = steps as u32;
 
-        current.len()
+        // a plot reached in fewer steps can be revisited by stepping back and forth
+        shortest_distances(self, self.start)
+            .values()
+            .filter(|&&distance| distance <= steps && distance % 2 == steps % 2)
+            .count()
+    }
+}
+
+impl SearchState<Map> for Position {
+    fn successors(&self, map: &Map) -> Vec<(Self, u32)> {
+        map.accessible_neighboring_positions(self)
+            .into_iter()
+            .map(|position| (position, 1))
+            .collect()
+    }
+
+    fn is_goal(&self, _map: &Map) -> bool {
+        false
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Map;
+    use super::*;
 
     #[test]
     fn calculates_reachable_positions() {
         let text = "...........\n.....###.#.\n.###.##..#.\n..#.#...#..\n....#.#....\n.##..S####.\n.##..#...#.\n.......##..\n.##.#.####.\n.##..##.##.\n...........";
-        let map = Map::from_text(text);
+        let map = Map::from_text(text).unwrap();
 
         assert_eq!(map.reachable_positions(6), 16);
     }
+
+    #[test]
+    fn rejects_unexpected_symbols_and_missing_start() {
+        let error = Map::from_text("..S\n.x.").unwrap_err();
+        assert_eq!(error, ParseError::new(2, 2, "unexpected symbol 'x'"));
+
+        let error = Map::from_text("...\n.#.").unwrap_err();
+        assert_eq!(error.message, "missing start 'S'");
+    }
 }
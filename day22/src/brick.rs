@@ -1,8 +1,9 @@
+use common::{parse_lines, parse_number, ParseError};
 use std::cmp::{max, min};
 
 pub type Position3D = (isize, isize, isize);
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Brick {
     pub id: String,
     pub start: Position3D,
@@ -10,14 +11,16 @@ pub struct Brick {
 }
 
 impl Brick {
-    pub fn from_text(text: &str) -> Self {
-        let components: Vec<&str> = text.split('~').collect();
+    pub fn from_text(text: &str) -> Result<Self, ParseError> {
+        let (start, end) = text
+            .split_once('~')
+            .ok_or_else(|| ParseError::at(text, text, "expected '<x>,<y>,<z>~<x>,<y>,<z>'"))?;
 
-        Brick {
+        Ok(Brick {
             id: text.to_string(),
-            start: position_3d_from_text(components.get(0).unwrap()),
-            end: position_3d_from_text(components.get(1).unwrap()),
-        }
+            start: position_3d_from_text(text, start)?,
+            end: position_3d_from_text(text, end)?,
+        })
     }
 }
 
@@ -33,7 +36,7 @@ impl Brick {
         least_self - greatest_other == 1
     }
 
-    pub fn find_available_coordinate_below<'a>(&self, other: &'a Vec<Brick>) -> isize {
+    pub fn find_available_coordinate_below(&self, other: &[Brick]) -> isize {
         let least_self = min(self.start.2, self.end.2);
 
         let coordinate = self
@@ -73,7 +76,7 @@ impl Brick {
         }
     }
 
-    pub fn find_overlapping<'a>(&self, other: &'a Vec<Brick>) -> Vec<&'a Brick> {
+    pub fn find_overlapping<'a>(&self, other: &'a [Brick]) -> Vec<&'a Brick> {
         other
             .iter()
             .filter(|other| self.overlaps_with(other))
@@ -115,10 +118,7 @@ fn find_position_range(start: isize, end: isize) -> Vec<isize> {
 
 impl PartialOrd for Brick {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        let least_self = min(self.start.2, self.end.2);
-        let least_other = min(other.start.2, other.end.2);
-
-        least_self.partial_cmp(&least_other)
+        Some(self.cmp(other))
     }
 }
 
@@ -131,27 +131,26 @@ impl Ord for Brick {
     }
 }
 
-fn position_3d_from_text(text: &str) -> Position3D {
-    let components: Vec<&str> = text.split(',').collect();
-
-    (
-        string_to_coordinate(components.get(0).unwrap()),
-        string_to_coordinate(components.get(1).unwrap()),
-        string_to_coordinate(components.get(2).unwrap()),
-    )
-}
+fn position_3d_from_text(text: &str, position: &str) -> Result<Position3D, ParseError> {
+    let components: Vec<&str> = position.split(',').collect();
 
-fn string_to_coordinate(text: &str) -> isize {
-    text.to_string().parse().unwrap()
+    match components[..] {
+        [x, y, z] => Ok((
+            parse_number(text, x)?,
+            parse_number(text, y)?,
+            parse_number(text, z)?,
+        )),
+        _ => Err(ParseError::at(text, position, "expected '<x>,<y>,<z>'")),
+    }
 }
 
-pub fn parse_bricks(text: &str) -> Vec<Brick> {
-    text.lines().map(|line| Brick::from_text(line)).collect()
+pub fn parse_bricks(text: &str) -> Result<Vec<Brick>, ParseError> {
+    parse_lines(text, Brick::from_text)
 }
 
 #[cfg(test)]
 pub fn assign_brick_ids(bricks: Vec<Brick>) -> Vec<Brick> {
-    let ids = vec!['a', 'b', 'c', 'd', 'e', 'f', 'g'];
+    let ids = ['a', 'b', 'c', 'd', 'e', 'f', 'g'];
 
     bricks
         .into_iter()
@@ -171,7 +170,7 @@ mod tests {
     fn parses_brick_from_text() {
         let input = "1,0,1~1,2,1";
 
-        let brick = Brick::from_text(input);
+        let brick = Brick::from_text(input).unwrap();
 
         assert_eq!(brick.start, (1, 0, 1));
         assert_eq!(brick.end, (1, 2, 1));
@@ -181,7 +180,7 @@ mod tests {
     fn parses_bricks_from_text() {
         let input = "1,0,1~1,2,1\n0,0,2~2,0,2\n0,2,3~2,2,3\n0,0,4~0,2,4\n2,0,5~2,2,5\n0,1,6~2,1,6\n1,1,8~1,1,9";
 
-        let result = parse_bricks(input);
+        let result = parse_bricks(input).unwrap();
         assert_eq!(result.len(), 7);
 
         let brick = result.get(6).unwrap();
@@ -189,4 +188,11 @@ mod tests {
         assert_eq!(brick.start, (1, 1, 8));
         assert_eq!(brick.end, (1, 1, 9));
     }
+
+    #[test]
+    fn rejects_malformed_bricks() {
+        let error = parse_bricks("1,0,1~1,2,1\n0,0,2~2,0").unwrap_err();
+
+        assert_eq!(error, ParseError::new(2, 7, "expected '<x>,<y>,<z>'"));
+    }
 }
@@ -6,7 +6,7 @@ pub struct BrickRelationships {
     pub directly_above: HashMap<String, Vec<String>>,
 }
 
-pub fn find_supported_bricks(bricks: &Vec<Brick>) -> BrickRelationships {
+pub fn find_supported_bricks(bricks: &[Brick]) -> BrickRelationships {
     let mut directly_below = HashMap::new();
     let mut directly_above = HashMap::new();
 
@@ -16,9 +16,7 @@ pub fn move_bricks_to_rest(bricks: Vec<Brick>) -> Vec<Brick> {
     not_resting.sort();
     not_resting.reverse();
 
-    while not_resting.len() > 0 {
-        let mut brick = not_resting.pop().unwrap();
-
+    while let Some(mut brick) = not_resting.pop() {
         let z = brick.find_available_coordinate_below(&resting);
         brick.move_to_z(z);
 
@@ -37,7 +35,7 @@ mod tests {
     fn moves_all_bricks_to_rest() {
         let input = "1,0,1~1,2,1\n0,0,2~2,0,2\n0,2,3~2,2,3\n0,0,4~0,2,4\n2,0,5~2,2,5\n0,1,6~2,1,6\n1,1,8~1,1,9";
 
-        let bricks = parse_bricks(input);
+        let bricks = parse_bricks(input).unwrap();
         let bricks = assign_brick_ids(bricks);
 
         let result = move_bricks_to_rest(bricks);
@@ -0,0 +1,35 @@
+mod map;
+mod pathing;
+
+use common::{Answer, ParseError, Solution};
+use map::Map;
+
+use crate::pathing::find_longest_path_in_map;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Map;
+
+    fn parse(text: &str) -> Result<Map, ParseError> {
+        Map::from_text(text)
+    }
+
+    fn part1(input: &Map) -> Answer {
+        let start = input.find_start_position();
+        let end = input.find_end_position();
+
+        find_longest_path_in_map(input, &start, &end).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day23>(include_str!("../readme.md"));
+    }
+}
@@ -1,14 +1,6 @@
-use std::collections::HashMap;
-
-#[derive(Debug, PartialEq, Clone)]
-pub enum Direction {
-    N,
-    E,
-    S,
-    W,
-}
-
-pub type Position = (usize, usize);
+use common::ParseError;
+pub use grid::Position;
+use grid::{Direction, Grid};
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum MapFeature {
@@ -18,14 +10,15 @@ pub enum MapFeature {
 }
 
 impl MapFeature {
-    pub fn from_text(symbol: char) -> Self {
+    pub fn from_symbol(symbol: char) -> Option<Self> {
         match symbol {
-            '^' => MapFeature::Slope(Direction::N),
-            'v' => MapFeature::Slope(Direction::S),
-            '<' => MapFeature::Slope(Direction::W),
-            '>' => MapFeature::Slope(Direction::E),
-            '.' => MapFeature::Empty,
-            _ => MapFeature::Blocked,
+            '^' => Some(MapFeature::Slope(Direction::N)),
+            'v' => Some(MapFeature::Slope(Direction::S)),
+            '<' => Some(MapFeature::Slope(Direction::W)),
+            '>' => Some(MapFeature::Slope(Direction::E)),
+            '.' => Some(MapFeature::Empty),
+            '#' => Some(MapFeature::Blocked),
+            _ => None,
         }
     }
 }
@@ -63,29 +56,18 @@ impl MapFeature {
 
 #[derive(Debug)]
 pub struct Map {
-    nodes: HashMap<Position, MapFeature>,
-    pub size: (usize, usize),
+    nodes: Grid<MapFeature>,
 }
 
 impl Map {
-    pub fn from_text(text: &str) -> Map {
-        let mut nodes: HashMap<Position, MapFeature> = HashMap::new();
-        let mut size = (0, 0);
-
-        for (y, line) in text.lines().enumerate() {
-            for (x, symbol) in line.chars().enumerate() {
-                let position = (x, y);
-
-                let feature = MapFeature::from_text(symbol.to_string().parse().unwrap());
+    pub fn from_text(text: &str) -> Result<Map, ParseError> {
+        let nodes = Grid::parse(text, |_, symbol| MapFeature::from_symbol(symbol))?;
 
-                size.0 = x;
-                nodes.insert(position, feature);
-            }
-
-            size.1 = y;
+        if nodes.height() == 0 {
+            return Err(ParseError::new(1, 1, "empty map"));
         }
 
-        Map { nodes, size }
+        Ok(Map { nodes })
     }
 }
 
@@ -95,29 +77,20 @@ impl Map {
     }
 
     pub fn find_start_position(&self) -> Position {
-        for i in 0..self.size.0 {
-            let position = (i, 0);
-            let node = self.get(&position).unwrap();
-
-            if node == &MapFeature::Empty {
-                return position;
-            }
-        }
-
-        (0, 0)
+        self.find_empty_position_in_row(0).unwrap_or((0, 0))
     }
 
     pub fn find_end_position(&self) -> Position {
-        for i in (0..self.size.0).rev() {
-            let position = (i, self.size.1);
-            let node = self.get(&position).unwrap();
-
-            if node == &MapFeature::Empty {
-                return position;
-            }
-        }
+        self.find_empty_position_in_row(self.nodes.height() - 1)
+            .unwrap_or((0, 0))
+    }
 
-        (0, 0)
+    fn find_empty_position_in_row(&self, y: usize) -> Option<Position> {
+        self.nodes
+            .row(y)
+            .iter()
+            .position(|node| node == &MapFeature::Empty)
+            .map(|x| (x, y))
     }
 
     pub fn find_connecting_positions(&self, position: &Position) -> Vec<Position> {
@@ -140,28 +113,17 @@ impl Map {
 
     fn find_adjacent_positions(&self, position: &Position) -> Vec<Position> {
         let node = self.get(position).unwrap();
-        let (x, y) = *position;
 
         match node {
-            MapFeature::Slope(direction) => match direction {
-                Direction::N => vec![(x, y - 1)],
-                Direction::S => vec![(x, y + 1)],
-                Direction::E => vec![(x + 1, y)],
-                Direction::W => vec![(x - 1, y)],
-            },
-            _ => {
-                let mut positions = vec![(x + 1, y), (x, y + 1)];
-
-                if x > 0 {
-                    positions.push((x - 1, y));
-                }
-
-                if y > 0 {
-                    positions.push((x, y - 1));
-                }
-
-                positions
+            MapFeature::Slope(direction) => {
+                self.nodes.step(position, *direction).into_iter().collect()
             }
+            _ => self
+                .nodes
+                .neighbors(position)
+                .into_iter()
+                .map(|(_, neighbor)| neighbor)
+                .collect(),
         }
     }
 }
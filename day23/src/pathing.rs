@@ -15,21 +15,19 @@ pub fn find_longest_path_in_map(map: &Map, start: &Position, end: &Position) ->
     let mut paths: Vec<Path> = vec![Path::new(vec![*start])];
     let mut settled_paths: Vec<Path> = vec![];
 
-    while paths.len() > 0 {
-        let path = paths.pop().unwrap();
-
+    while let Some(path) = paths.pop() {
         let tip = path.visited.last().unwrap();
 
         let neighbors: Vec<Position> = map
             .find_connecting_positions(tip)
             .iter()
-            .filter_map(|position| match path.visited.contains(&position) {
+            .filter_map(|position| match path.visited.contains(position) {
                 true => None,
                 _ => Some(*position),
             })
             .collect();
 
-        if neighbors.len() == 0 {
+        if neighbors.is_empty() {
             continue;
         }
 
@@ -58,11 +56,12 @@ pub fn find_longest_path_in_map(map: &Map, start: &Position, end: &Position) ->
 #[cfg(test)]
 mod tests {
     use super::*;
+    use common::ParseError;
 
     #[test]
     fn finds_longest_path_in_test_map() {
         let text = "##.###\n#.>..#\n###.##\n#...##\n#.####";
-        let graph = Map::from_text(&text);
+        let graph = Map::from_text(text).unwrap();
 
         let start = graph.find_start_position();
         let end = graph.find_end_position();
@@ -74,7 +73,7 @@ mod tests {
     #[test]
     fn finds_longest_path_in_map() {
         let text = "#.#####################\n#.......#########...###\n#######.#########.#.###\n###.....#.>.>.###.#.###\n###v#####.#v#.###.#.###\n###.>...#.#.#.....#...#\n###v###.#.#.#########.#\n###...#.#.#.......#...#\n#####.#.#.#######.#.###\n#.....#.#.#.......#...#\n#.#####.#.#.#########v#\n#.#...#...#...###...>.#\n#.#.#v#######v###.###v#\n#...#.>.#...>.>.#.###.#\n#####v#.#.###v#.#.###.#\n#.....#...#...#.#.#...#\n#.#########.###.#.#.###\n#...###...#...#...#.###\n###.###.#.###v#####v###\n#...#...#.#.>.>.#.>.###\n#.###.###.#.###.#.#v###\n#.....###...###...#...#\n#####################.#";
-        let graph = Map::from_text(&text);
+        let graph = Map::from_text(text).unwrap();
 
         let start = graph.find_start_position();
         let end = graph.find_end_position();
@@ -82,4 +81,11 @@ mod tests {
         let result = find_longest_path_in_map(&graph, &start, &end);
         assert_eq!(result, 94);
     }
+
+    #[test]
+    fn rejects_unexpected_symbols() {
+        let error = Map::from_text("#.#\n#x#").unwrap_err();
+
+        assert_eq!(error, ParseError::new(2, 2, "unexpected symbol 'x'"));
+    }
 }
@@ -1,23 +1,30 @@
 use crate::{line::find_particle_intersection_2d, particle::Particle};
+use math::{Intersection, Rational};
 
-type TestArea = ((f32, f32), (f32, f32));
+type TestArea = ((i128, i128), (i128, i128));
 
-pub fn find_particle_intersections(particles: &Vec<Particle>, bounding_area: TestArea) -> u32 {
+pub fn find_particle_intersections(particles: &[Particle], bounding_area: TestArea) -> u32 {
     let mut intersections = 0;
     let (bounds_x, bounds_y) = bounding_area;
 
     for a in 0..particles.len() {
-        for b in a..particles.len() {
+        for b in (a + 1)..particles.len() {
             let particle_a = particles.get(a).unwrap();
             let particle_b = particles.get(b).unwrap();
 
-            let (x_intersect, y_intersect, t1, t2) =
-                find_particle_intersection_2d(particle_a, particle_b);
+            let Some(Intersection {
+                position: (x_intersect, y_intersect),
+                parameters: (t1, t2),
+            }) = find_particle_intersection_2d(particle_a, particle_b)
+            else {
+                continue;
+            };
 
             let x_within_bounds = is_within_bounds(x_intersect, bounds_x);
             let y_within_bounds = is_within_bounds(y_intersect, bounds_y);
+            let in_future = t1 > 0.into() && t2 > 0.into();
 
-            if x_within_bounds && y_within_bounds && t1 > 0.0 && t2 > 0.0 {
+            if x_within_bounds && y_within_bounds && in_future {
                 intersections += 1;
             }
         }
@@ -26,10 +33,10 @@ pub fn find_particle_intersections(particles: &Vec<Particle>, bounding_area: Tes
     intersections
 }
 
-fn is_within_bounds(value: f32, bounds: (f32, f32)) -> bool {
+fn is_within_bounds(value: Rational, bounds: (i128, i128)) -> bool {
     let (min, max) = bounds;
 
-    value >= min && value <= max
+    value >= min.into() && value <= max.into()
 }
 
 #[cfg(test)]
@@ -42,9 +49,9 @@ mod tests {
     fn finds_number_of_particle_intersections() {
         let text = "19, 13, 30 @ -2, 1, -2\n18, 19, 22 @ -1, -1, -2\n20, 25, 34 @ -2, -2, -4\n12, 31, 28 @ -1, -2, -1\n20, 19, 15 @ 1, -5, -3";
 
-        let particles = parse_particles(text);
+        let particles = parse_particles(text).unwrap();
 
-        let test_area = ((7.0, 27.0), (7.0, 27.0));
+        let test_area = ((7, 27), (7, 27));
         let result = find_particle_intersections(&particles, test_area);
 
         assert_eq!(result, 2);
@@ -0,0 +1,26 @@
+mod intersections;
+mod line;
+mod particle;
+
+use common::{Answer, ParseError, Solution};
+use intersections::find_particle_intersections;
+use particle::{parse_particles, Particle};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Particle>;
+
+    fn parse(text: &str) -> Result<Vec<Particle>, ParseError> {
+        parse_particles(text)
+    }
+
+    fn part1(input: &Vec<Particle>) -> Answer {
+        let test_area = (
+            (200000000000000, 400000000000000),
+            (200000000000000, 400000000000000),
+        );
+
+        find_particle_intersections(input, test_area).into()
+    }
+}
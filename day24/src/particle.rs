@@ -1,4 +1,6 @@
-pub type Vector3D = (f32, f32, f32);
+use common::{parse_lines, parse_number, ParseError};
+
+pub type Vector3D = (i64, i64, i64);
 
 #[derive(Debug)]
 pub struct Particle {
@@ -7,27 +9,37 @@ pub struct Particle {
 }
 
 impl Particle {
-    pub fn from_text(text: &str) -> Self {
-        let components: Vec<&str> = text.split(" @ ").collect();
+    pub fn from_text(text: &str) -> Result<Self, ParseError> {
+        let (position, velocity) = text
+            .split_once('@')
+            .ok_or_else(|| ParseError::at(text, text, "expected '<position> @ <velocity>'"))?;
 
-        Self {
-            position: vector_3d_from_text(components.get(0).unwrap()),
-            velocity: vector_3d_from_text(components.get(1).unwrap()),
-        }
+        Ok(Self {
+            position: vector_3d_from_text(text, position)?,
+            velocity: vector_3d_from_text(text, velocity)?,
+        })
     }
 }
 
-fn vector_3d_from_text(text: &str) -> Vector3D {
-    let components: Vec<String> = text.split(", ").map(|value| value.to_string()).collect();
-    (
-        components.get(0).unwrap().parse().unwrap(),
-        components.get(1).unwrap().parse().unwrap(),
-        components.get(2).unwrap().parse().unwrap(),
-    )
+fn vector_3d_from_text(text: &str, vector: &str) -> Result<Vector3D, ParseError> {
+    let components: Vec<&str> = vector.split(',').collect();
+
+    match components[..] {
+        [x, y, z] => Ok((
+            parse_number(text, x)?,
+            parse_number(text, y)?,
+            parse_number(text, z)?,
+        )),
+        _ => Err(ParseError::at(
+            text,
+            vector.trim(),
+            "expected '<x>, <y>, <z>'",
+        )),
+    }
 }
 
-pub fn parse_particles(text: &str) -> Vec<Particle> {
-    text.lines().map(|line| Particle::from_text(line)).collect()
+pub fn parse_particles(text: &str) -> Result<Vec<Particle>, ParseError> {
+    parse_lines(text, Particle::from_text)
 }
 
 #[cfg(test)]
@@ -38,9 +50,18 @@ mod tests {
     fn parses_particle_from_text() {
         //
         let text = "19, 13, 30 @ -2, 1, -2";
-        let particle = Particle::from_text(text);
+        let particle = Particle::from_text(text).unwrap();
+
+        assert_eq!(particle.position, (19, 13, 30));
+        assert_eq!(particle.velocity, (-2, 1, -2));
+    }
+
+    #[test]
+    fn rejects_malformed_particles() {
+        let error = Particle::from_text("19, 13, 30 @ -2, x, -2").unwrap_err();
+        assert_eq!(error, ParseError::new(1, 18, "invalid number 'x'"));
 
-        assert_eq!(particle.position, (19.0, 13.0, 30.0));
-        assert_eq!(particle.velocity, (-2.0, 1.0, -2.0));
+        let error = Particle::from_text("19, 13 @ -2, 1, -2").unwrap_err();
+        assert_eq!(error.column, 1);
     }
 }
@@ -0,0 +1,61 @@
+use grid::Position;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Adjacency {
+    // up, down, left and right
+    Four,
+    // including diagonals
+    #[default]
+    Eight,
+    // every cell at most k steps away in any direction, diagonals included
+    Radius(usize),
+}
+
+impl Adjacency {
+    // neighbours of the position within a schematic of the given size
+    pub fn neighbours(
+        &self,
+        position: &Position,
+        (width, height): (usize, usize),
+    ) -> Vec<Position> {
+        let (x, y) = *position;
+
+        let radius = match self {
+            Adjacency::Four | Adjacency::Eight => 1,
+            Adjacency::Radius(radius) => *radius,
+        };
+
+        let mut neighbours = vec![];
+
+        for ny in y.saturating_sub(radius)..=(y + radius).min(height.saturating_sub(1)) {
+            for nx in x.saturating_sub(radius)..=(x + radius).min(width.saturating_sub(1)) {
+                let diagonal = nx != x && ny != y;
+
+                if (nx, ny) == (x, y) || (diagonal && self == &Adjacency::Four) {
+                    continue;
+                }
+
+                neighbours.push((nx, ny));
+            }
+        }
+
+        neighbours
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_neighbours_within_bounds() {
+        assert_eq!(
+            Adjacency::Four.neighbours(&(1, 1), (3, 3)),
+            vec![(1, 0), (0, 1), (2, 1), (1, 2)]
+        );
+        assert_eq!(Adjacency::Eight.neighbours(&(0, 0), (3, 3)).len(), 3);
+        assert_eq!(Adjacency::Eight.neighbours(&(1, 1), (3, 3)).len(), 8);
+        assert_eq!(Adjacency::Radius(2).neighbours(&(2, 2), (10, 10)).len(), 24);
+        assert_eq!(Adjacency::Radius(2).neighbours(&(0, 0), (10, 10)).len(), 8);
+    }
+}
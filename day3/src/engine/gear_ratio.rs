@@ -1,73 +1,123 @@
-use super::model::{Engine, EngineLine, EngineSymbol};
-
-impl EngineLine {
-    pub fn get_gear_ratio(&self, previous: Option<&EngineLine>, next: Option<&EngineLine>) -> u32 {
-        let symbols = &self.symbols();
-        let own_line = Some(self);
-
-        let lines = vec![&own_line, &previous, &next];
+use super::{
+    adjacency::Adjacency,
+    gear_rules::GearRules,
+    model::{Engine, EngineNumber},
+};
+use grid::Position;
+
+#[derive(Debug, PartialEq)]
+pub struct SymbolReport<'a> {
+    pub symbol: char,
+    pub position: Position,
+    pub numbers: Vec<&'a EngineNumber>,
+    // whether the symbol has a rule and the required number of adjacent numbers
+    pub is_gear: bool,
+    // 0 unless the symbol has a rule and the required number of adjacent numbers
+    pub contribution: u128,
+}
 
-        symbols
+impl Engine {
+    // one report per symbol occurrence, in reading order
+    pub fn symbol_reports(&self, rules: &GearRules, adjacency: Adjacency) -> Vec<SymbolReport<'_>> {
+        self.symbols()
             .iter()
-            .map(|symbol| find_gear_ratio(symbol, &lines))
-            .sum()
+            .map(|(symbol, position)| {
+                let numbers = self.adjacent_numbers(position, adjacency);
+
+                let rule = rules
+                    .rule_for(*symbol)
+                    .filter(|rule| rule.count == numbers.len());
+
+                let contribution = match rule {
+                    Some(rule) => {
+                        let values: Vec<u128> = numbers.iter().map(|number| number.value).collect();
+                        rule.aggregation.apply(&values)
+                    }
+                    None => 0,
+                };
+
+                SymbolReport {
+                    symbol: *symbol,
+                    position: *position,
+                    numbers,
+                    is_gear: rule.is_some(),
+                    contribution,
+                }
+            })
+            .collect()
     }
 
-    fn find_adjacent_numbers(&self, index: usize) -> Vec<u32> {
-        let found = self
-            .numbers()
+    pub fn gear_score(&self, rules: &GearRules, adjacency: Adjacency) -> u128 {
+        self.symbol_reports(rules, adjacency)
             .iter()
-            .filter(|(_, start, length)| {
-                let start = *start;
-                let end = start + length - 1;
-
-                end + 1 >= index && start <= index + 1
+            .fold(0, |sum: u128, report| {
+                sum.checked_add(report.contribution)
+                    .expect("gear score overflows u128")
             })
-            .map(|(value, _, _)| *value)
-            .collect();
+    }
 
-        found
+    pub fn calculate_gear_ratio(&self) -> u128 {
+        self.gear_score(&GearRules::default(), Adjacency::Eight)
     }
 }
 
-impl Engine {
-    pub fn calculate_gear_ratio(&self) -> u32 {
-        let lines = &self.lines;
-        let mut sum = 0;
-
-        for (index, line) in lines.iter().enumerate() {
-            let previous = self.get_previous_line(index);
-            let next = lines.get(index + 1);
-
-            let ratio = line.get_gear_ratio(previous, next);
+pub fn format_symbol_reports(reports: &[SymbolReport]) -> String {
+    reports
+        .iter()
+        .map(|report| {
+            let (x, y) = report.position;
+            let numbers: Vec<String> = report
+                .numbers
+                .iter()
+                .map(|number| number.value.to_string())
+                .collect();
+
+            format!(
+                "{} at {x},{y}: [{}] -> {}\n",
+                report.symbol,
+                numbers.join(", "),
+                report.contribution
+            )
+        })
+        .collect()
+}
 
-            sum += ratio
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        sum
+    fn engine() -> Engine {
+        Engine::new(String::from(
+            "467..114..\n...*......\n..35..633.\n......#...\n617*......",
+        ))
+        .unwrap()
     }
-}
 
-fn find_gear_ratio((_, index): &EngineSymbol, lines: &Vec<&Option<&EngineLine>>) -> u32 {
-    let numbers = find_adjacent_numbers(*index, lines);
+    #[test]
+    fn applies_rules_per_symbol() {
+        let engine = engine();
 
-    match numbers.len() == 2 {
-        false => 0,
-        true => numbers.iter().fold(1, |previous, next| previous * next),
-    }
-}
+        let rules = GearRules::from_text("* 2 product\n# 1 max").unwrap();
+        assert_eq!(engine.gear_score(&rules, Adjacency::Eight), 16345 + 633);
 
-fn find_adjacent_numbers(index: usize, lines: &Vec<&Option<&EngineLine>>) -> Vec<u32> {
-    lines
-        .iter()
-        .flat_map(|line| find_numbers_in_line(index, **line))
-        .collect()
-}
+        let rules = GearRules::from_text("* 1 sum").unwrap();
+        assert_eq!(engine.gear_score(&rules, Adjacency::Eight), 617);
 
-fn find_numbers_in_line(index: usize, line: Option<&EngineLine>) -> Vec<u32> {
-    if line.is_none() {
-        return vec![];
+        let rules = GearRules::from_text("* 2 sum").unwrap();
+        assert_eq!(engine.gear_score(&rules, Adjacency::Eight), 502);
     }
 
-    line.unwrap().find_adjacent_numbers(index)
+    #[test]
+    fn reports_every_symbol() {
+        let engine = engine();
+        let rules = GearRules::from_text("* 2 product").unwrap();
+        let reports = engine.symbol_reports(&rules, Adjacency::Eight);
+
+        assert_eq!(
+            format_symbol_reports(&reports),
+            "* at 3,1: [467, 35] -> 16345\n\
+             # at 6,3: [633] -> 0\n\
+             * at 3,4: [617] -> 0\n"
+        );
+    }
 }
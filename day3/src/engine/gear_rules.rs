@@ -0,0 +1,178 @@
+use common::{parse_number, ParseError};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregation {
+    Product,
+    Sum,
+    Max,
+}
+
+impl Aggregation {
+    pub fn apply(&self, values: &[u128]) -> u128 {
+        let overflow = "gear contribution overflows u128";
+
+        match self {
+            Aggregation::Product => values
+                .iter()
+                .try_fold(1_u128, |product, value| product.checked_mul(*value))
+                .expect(overflow),
+            Aggregation::Sum => values
+                .iter()
+                .try_fold(0_u128, |sum, value| sum.checked_add(*value))
+                .expect(overflow),
+            Aggregation::Max => values.iter().copied().max().unwrap_or(0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GearRule {
+    // exact number of adjacent numbers the symbol needs to count
+    pub count: usize,
+    pub aggregation: Aggregation,
+}
+
+impl GearRule {
+    pub fn new(count: usize, aggregation: Aggregation) -> GearRule {
+        GearRule { count, aggregation }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GearRules {
+    rules: BTreeMap<char, GearRule>,
+    // applies to symbols without a rule of their own
+    fallback: Option<GearRule>,
+}
+
+impl Default for GearRules {
+    // any symbol next to exactly two numbers multiplies them
+    fn default() -> GearRules {
+        GearRules {
+            rules: BTreeMap::new(),
+            fallback: Some(GearRule::new(2, Aggregation::Product)),
+        }
+    }
+}
+
+impl GearRules {
+    // one '<symbol|any> <count> <product|sum|max>' rule per line, e.g. "* 2 product";
+    // there are no comments, as '#' is a symbol like any other
+    pub fn from_text(text: &str) -> Result<GearRules, ParseError> {
+        let mut rules = BTreeMap::new();
+        let mut fallback = None;
+
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [symbol, count, aggregation] = fields[..] else {
+                return Err(ParseError::at(
+                    text,
+                    line,
+                    "expected '<symbol|any> <count> <product|sum|max>'",
+                ));
+            };
+
+            let aggregation = match aggregation {
+                "product" => Aggregation::Product,
+                "sum" => Aggregation::Sum,
+                "max" => Aggregation::Max,
+                _ => {
+                    return Err(ParseError::at(
+                        text,
+                        aggregation,
+                        format!("unknown aggregation '{aggregation}'"),
+                    ))
+                }
+            };
+
+            let rule = GearRule::new(parse_number(text, count)?, aggregation);
+
+            let duplicate = match symbol {
+                "any" => fallback.replace(rule).is_some(),
+                _ => match symbol.chars().collect::<Vec<char>>()[..] {
+                    [symbol] => rules.insert(symbol, rule).is_some(),
+                    _ => {
+                        return Err(ParseError::at(
+                            text,
+                            symbol,
+                            format!("expected a single symbol, found '{symbol}'"),
+                        ))
+                    }
+                },
+            };
+
+            if duplicate {
+                return Err(ParseError::at(
+                    text,
+                    symbol,
+                    format!("duplicate rule for '{symbol}'"),
+                ));
+            }
+        }
+
+        Ok(GearRules { rules, fallback })
+    }
+}
+
+impl GearRules {
+    pub fn rule_for(&self, symbol: char) -> Option<&GearRule> {
+        self.rules.get(&symbol).or(self.fallback.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rules_per_symbol() {
+        let rules = GearRules::from_text("* 2 product\n\n# 3 sum\n").unwrap();
+
+        assert_eq!(
+            rules.rule_for('*'),
+            Some(&GearRule::new(2, Aggregation::Product))
+        );
+        assert_eq!(
+            rules.rule_for('#'),
+            Some(&GearRule::new(3, Aggregation::Sum))
+        );
+        assert_eq!(rules.rule_for('+'), None);
+
+        let rules = GearRules::from_text("+ 1 max\nany 2 sum").unwrap();
+        assert_eq!(
+            rules.rule_for('+'),
+            Some(&GearRule::new(1, Aggregation::Max))
+        );
+        assert_eq!(
+            rules.rule_for('%'),
+            Some(&GearRule::new(2, Aggregation::Sum))
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_rules() {
+        let error = GearRules::from_text("* 2 product\n+ 2 mean").unwrap_err();
+        assert_eq!(error, ParseError::new(2, 5, "unknown aggregation 'mean'"));
+
+        let error = GearRules::from_text("* 2 product\n* 3 sum").unwrap_err();
+        assert_eq!(error, ParseError::new(2, 1, "duplicate rule for '*'"));
+
+        let error = GearRules::from_text("** 2 sum").unwrap_err();
+        assert_eq!(error.column, 1);
+
+        assert!(GearRules::from_text("* two sum").is_err());
+    }
+
+    #[test]
+    fn aggregates_values() {
+        assert_eq!(Aggregation::Product.apply(&[3, 4, 5]), 60);
+        assert_eq!(Aggregation::Sum.apply(&[3, 4, 5]), 12);
+        assert_eq!(Aggregation::Max.apply(&[3, 4, 5]), 5);
+    }
+}
@@ -1,5 +1,12 @@
+mod adjacency;
 mod gear_ratio;
+mod gear_rules;
 mod model;
 mod part_score;
+mod render;
 
-pub use model::Engine;
+pub use adjacency::Adjacency;
+pub use gear_ratio::{format_symbol_reports, SymbolReport};
+pub use gear_rules::{Aggregation, GearRule, GearRules};
+pub use model::{Engine, EngineNumber, EngineSymbol};
+pub use render::{Mark, RenderFormat};
@@ -1,127 +1,179 @@
-use std::cmp::min;
-
-// character, position
-pub type EngineSymbol = (char, usize);
-
-// value, start, length
-pub type EngineNumber = (u32, usize, usize);
-
-pub struct EngineLine {
-    numbers: Vec<EngineNumber>,
-    symbols: Vec<EngineSymbol>,
-}
-
-impl EngineLine {
-    pub fn new(text: &str) -> EngineLine {
-        EngineLine {
-            numbers: find_numbers_in_text(text),
-            symbols: find_symbols_in_text(text),
-        }
-    }
+use super::adjacency::Adjacency;
+use common::ParseError;
+use grid::{Grid, Position};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EngineNumber {
+    pub value: u128,
+    // leftmost digit
+    pub position: Position,
+    pub length: usize,
 }
 
-impl EngineLine {
-    pub fn numbers(&self) -> &Vec<EngineNumber> {
-        &self.numbers
-    }
+impl EngineNumber {
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        let (x, y) = self.position;
 
-    pub fn symbols(&self) -> &Vec<EngineSymbol> {
-        &self.symbols
+        (x..x + self.length).map(move |x| (x, y))
     }
 }
 
+// character, position
+pub type EngineSymbol = (char, Position);
+
+#[derive(Debug)]
 pub struct Engine {
-    pub lines: Vec<EngineLine>,
+    cells: Grid<char>,
+    numbers: Vec<EngineNumber>,
+    // index into `numbers` of the number covering each cell
+    number_indices: Grid<Option<usize>>,
+    symbols: Vec<EngineSymbol>,
 }
 
 impl Engine {
-    pub fn new(text: String) -> Engine {
-        Engine {
-            lines: text.lines().map(EngineLine::new).collect(),
+    pub fn new(text: String) -> Result<Engine, ParseError> {
+        let cells = Grid::parse(&text, |_, symbol| {
+            symbol.is_ascii_graphic().then_some(symbol)
+        })?;
+
+        let mut numbers = vec![];
+        let mut number_indices = Grid::filled(cells.width(), cells.height(), None);
+        let mut symbols = vec![];
+
+        for (y, line) in text.lines().enumerate() {
+            let row_numbers =
+                find_numbers_in_text(line).map_err(|error| error.within(&text, line))?;
+
+            for (value, x, length) in row_numbers {
+                let number = EngineNumber {
+                    value,
+                    position: (x, y),
+                    length,
+                };
+
+                for position in number.positions() {
+                    number_indices.set(&position, Some(numbers.len()));
+                }
+
+                numbers.push(number);
+            }
+
+            let row_symbols = find_symbols_in_text(line);
+            symbols.extend(row_symbols.into_iter().map(|(symbol, x)| (symbol, (x, y))));
         }
+
+        Ok(Engine {
+            cells,
+            numbers,
+            number_indices,
+            symbols,
+        })
     }
 }
 
 impl Engine {
-    pub fn get_previous_line(&self, index: usize) -> Option<&EngineLine> {
-        match index > 0 {
-            true => self.lines.get(index - 1),
-            false => None,
-        }
+    pub fn size(&self) -> (usize, usize) {
+        self.cells.size()
     }
-}
-
-fn find_numbers_in_text(text: &str) -> Vec<EngineNumber> {
-    let mut result = vec![];
-    let mut cursor = 0;
-    let length = text.len();
-
-    let characters: Vec<char> = text.chars().collect();
-
-    while cursor < length {
-        let character = characters.get(cursor);
-        if character.is_none() {
-            break;
-        }
 
-        let character = character.unwrap();
-        if character.is_ascii_digit() {
-            let end = min(cursor + 5, length);
-            let (value, length) = extract_number(&text[cursor..end]);
-
-            result.push((value, cursor, length));
-            cursor += length;
-            continue;
-        }
-
-        cursor += 1;
+    pub fn get(&self, position: &Position) -> Option<char> {
+        self.cells.get(position).copied()
     }
 
-    result
-}
+    pub fn numbers(&self) -> &[EngineNumber] {
+        &self.numbers
+    }
 
-fn extract_number(text: &str) -> (u32, usize) {
-    let mut digits: String = String::new();
-    let mut length: usize = 0;
+    pub fn symbols(&self) -> &[EngineSymbol] {
+        &self.symbols
+    }
 
-    for character in text.chars() {
-        if !character.is_ascii_digit() {
-            break;
-        }
+    pub fn number_at(&self, position: &Position) -> Option<&EngineNumber> {
+        let index = self.number_indices.get(position)?.as_ref()?;
 
-        digits.push(character);
-        length += 1;
+        self.numbers.get(*index)
     }
 
-    let value = digits.parse::<u32>();
+    // each number is listed once, however many of its digits are adjacent
+    pub fn adjacent_numbers(
+        &self,
+        position: &Position,
+        adjacency: Adjacency,
+    ) -> Vec<&EngineNumber> {
+        let mut indices: Vec<usize> = adjacency
+            .neighbours(position, self.size())
+            .iter()
+            .filter_map(|neighbour| *self.number_indices.get(neighbour)?)
+            .collect();
+
+        indices.sort();
+        indices.dedup();
+
+        indices
+            .into_iter()
+            .map(|index| &self.numbers[index])
+            .collect()
+    }
 
-    match value {
-        Ok(value) => (value, length),
-        Err(_) => (0, 0),
+    pub fn adjacent_symbols(
+        &self,
+        number: &EngineNumber,
+        adjacency: Adjacency,
+    ) -> Vec<EngineSymbol> {
+        let mut positions: Vec<Position> = number
+            .positions()
+            .flat_map(|position| adjacency.neighbours(&position, self.size()))
+            .collect();
+
+        // sort by row first, matching the order of `symbols`
+        positions.sort_by_key(|(x, y)| (*y, *x));
+        positions.dedup();
+
+        positions
+            .into_iter()
+            .filter_map(|position| {
+                let symbol = self.get(&position)?;
+                is_symbol(symbol).then_some((symbol, position))
+            })
+            .collect()
     }
 }
 
-fn find_symbols_in_text(text: &str) -> Vec<EngineSymbol> {
+fn find_numbers_in_text(text: &str) -> Result<Vec<(u128, usize, usize)>, ParseError> {
     let mut result = vec![];
     let mut cursor = 0;
 
     let characters: Vec<char> = text.chars().collect();
 
-    while cursor < text.len() {
-        let character = characters.get(cursor);
-        if character.is_none() {
-            break;
+    while cursor < characters.len() {
+        if !characters[cursor].is_ascii_digit() {
+            cursor += 1;
+            continue;
         }
 
-        let character = *character.unwrap();
-        if is_symbol(character) {
-            result.push((character, cursor));
-        }
+        let length = characters[cursor..]
+            .iter()
+            .take_while(|character| character.is_ascii_digit())
+            .count();
+
+        let digits: String = characters[cursor..cursor + length].iter().collect();
+        let value = digits.parse::<u128>().map_err(|_| {
+            ParseError::new(1, cursor + 1, format!("number '{digits}' is too large"))
+        })?;
 
-        cursor += 1;
+        result.push((value, cursor, length));
+        cursor += length;
     }
 
-    result
+    Ok(result)
+}
+
+fn find_symbols_in_text(text: &str) -> Vec<(char, usize)> {
+    text.chars()
+        .enumerate()
+        .filter(|(_, character)| is_symbol(*character))
+        .map(|(position, character)| (character, position))
+        .collect()
 }
 
 pub fn is_symbol(character: char) -> bool {
@@ -133,15 +185,21 @@ mod test_parser {
     use super::*;
 
     #[test]
-    fn extracts_number_from_string_discarding_tail() {
-        assert_eq!(extract_number("169...."), (169, 3));
-        assert_eq!(extract_number(".69...."), (0, 0));
+    fn finds_numbers_of_any_length() {
+        let result = find_numbers_in_text("1234567890123.7..42").unwrap();
+        assert_eq!(
+            result,
+            vec![(1234567890123, 0, 13), (7, 14, 1), (42, 17, 2)]
+        );
+
+        let error = find_numbers_in_text(&format!("..{}", "9".repeat(40))).unwrap_err();
+        assert_eq!(error.column, 3);
     }
 
     #[test]
     fn finds_numbers_in_text() {
         let input_text = "....737.....608..........362...336....642....606";
-        let result = find_numbers_in_text(input_text);
+        let result = find_numbers_in_text(input_text).unwrap();
 
         assert_eq!(
             result,
@@ -167,7 +225,7 @@ mod test_parser {
     #[test]
     fn finds_part_score_for_text_line() {
         let text = "..592.....\n......755.\n...$.*....";
-        let engine = Engine::new(String::from(text));
+        let engine = Engine::new(String::from(text)).unwrap();
 
         assert_eq!(engine.calculate_part_score(), 755);
     }
@@ -175,7 +233,7 @@ mod test_parser {
     #[test]
     fn calculates_part_score_for_engine() {
         let schematic = String::from("467..114..\n...*......\n..35..633.\n......#...\n617*......\n.....+.58.\n..592.....\n......755.\n...$.*....\n.664.598..");
-        let engine = Engine::new(schematic);
+        let engine = Engine::new(schematic).unwrap();
 
         assert_eq!(engine.calculate_part_score(), 4361);
     }
@@ -184,8 +242,36 @@ mod test_parser {
     fn finds_gear_ratio() {
         let schematic = String::from("467..114..\n..._......\n..35..633.\n......#...\n617_......\n.....+.58.\n..592.....\n......755.\n...$._....\n.664.598..");
 
-        let engine = Engine::new(schematic);
+        let engine = Engine::new(schematic).unwrap();
 
         assert_eq!(engine.calculate_gear_ratio(), 467835);
     }
+
+    #[test]
+    fn looks_up_numbers_by_cell() {
+        let engine = Engine::new(String::from("467..114..\n...*......\n..35..633.")).unwrap();
+
+        assert_eq!(engine.number_at(&(1, 0)).unwrap().value, 467);
+        assert_eq!(engine.number_at(&(3, 0)), None);
+        assert_eq!(engine.number_at(&(3, 2)).unwrap().position, (2, 2));
+
+        let values = |adjacency| -> Vec<u128> {
+            engine
+                .adjacent_numbers(&(3, 1), adjacency)
+                .iter()
+                .map(|number| number.value)
+                .collect()
+        };
+
+        assert_eq!(values(Adjacency::Eight), vec![467, 35]);
+        assert_eq!(values(Adjacency::Four), vec![35]);
+        assert_eq!(values(Adjacency::Radius(2)), vec![467, 114, 35]);
+    }
+
+    #[test]
+    fn rejects_unexpected_symbols() {
+        let error = Engine::new(String::from("467..114..\n..\t*......")).unwrap_err();
+
+        assert_eq!(error, ParseError::new(2, 3, "unexpected symbol '\t'"));
+    }
 }
@@ -1,63 +1,25 @@
-use super::model::{Engine, EngineLine, EngineNumber};
+use super::{
+    adjacency::Adjacency,
+    model::{Engine, EngineNumber},
+};
 
-impl EngineLine {
-    pub fn get_part_score(&self, previous: Option<&EngineLine>, next: Option<&EngineLine>) -> u32 {
-        let numbers = &self.numbers();
-        let own_line = Some(self);
-
-        let lines = vec![&own_line, &previous, &next];
-
-        numbers
+impl Engine {
+    // numbers next to at least one symbol
+    pub fn part_numbers(&self, adjacency: Adjacency) -> Vec<&EngineNumber> {
+        self.numbers()
             .iter()
-            .filter(|number| is_number_adjacent_to_symbol(number, &lines))
-            .fold(0, |sum: u32, (value, _, _)| sum + value)
+            .filter(|number| !self.adjacent_symbols(number, adjacency).is_empty())
+            .collect()
     }
-}
-
-impl Engine {
-    pub fn calculate_part_score(&self) -> u32 {
-        let lines = &self.lines;
-        let mut sum = 0;
 
-        for (index, line) in lines.iter().enumerate() {
-            let previous = self.get_previous_line(index);
-            let next = lines.get(index + 1);
-
-            let score = line.get_part_score(previous, next);
-
-            sum += score
-        }
-
-        sum
-    }
-}
-
-fn is_number_adjacent_to_symbol(number: &EngineNumber, lines: &Vec<&Option<&EngineLine>>) -> bool {
-    let (_, start, length) = number;
-
-    let start = match *start >= 1 {
-        true => start - 1,
-        false => 0,
-    };
-
-    let end = start + length + 1;
-
-    let found = lines
-        .iter()
-        .any(|line| has_symbol_at_positions(line.as_deref(), start, end));
-
-    found
-}
-
-fn has_symbol_at_positions(line: Option<&EngineLine>, start: usize, end: usize) -> bool {
-    if line.is_none() {
-        return false;
+    pub fn calculate_part_score(&self) -> u128 {
+        self.part_numbers(Adjacency::Eight)
+            .iter()
+            .fold(0, |sum: u128, number| {
+                sum.checked_add(number.value)
+                    .expect("part score overflows u128")
+            })
     }
-
-    line.unwrap()
-        .symbols()
-        .iter()
-        .any(|(_, position)| position >= &start && position <= &end)
 }
 
 #[cfg(test)]
@@ -65,11 +27,17 @@ mod test_parser {
     use super::*;
 
     #[test]
-    fn determines_if_text_has_symbols_in_positions() {
-        let line = EngineLine::new(".......+...38");
-
-        assert_eq!(has_symbol_at_positions(Some(&line), 0, 4), false);
-        assert_eq!(has_symbol_at_positions(Some(&line), 5, 8), true);
-        assert_eq!(has_symbol_at_positions(Some(&line), 10, 15), false);
+    fn determines_if_numbers_have_adjacent_symbols() {
+        let engine = Engine::new(String::from(".......+...38\n12..........7")).unwrap();
+        let values = |adjacency| -> Vec<u128> {
+            engine
+                .part_numbers(adjacency)
+                .iter()
+                .map(|number| number.value)
+                .collect()
+        };
+
+        assert_eq!(values(Adjacency::Eight), vec![]);
+        assert_eq!(values(Adjacency::Radius(4)), vec![38]);
     }
 }
@@ -0,0 +1,178 @@
+use super::{adjacency::Adjacency, gear_rules::GearRules, model::Engine};
+use grid::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mark {
+    Empty,
+    PartNumber,
+    // numbers without an adjacent symbol, left out of the part score
+    OtherNumber,
+    // symbols contributing to the gear score
+    Gear,
+    Symbol,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderFormat {
+    Ansi,
+    Html,
+}
+
+impl RenderFormat {
+    pub fn from_text(text: &str) -> Option<RenderFormat> {
+        match text {
+            "ansi" => Some(RenderFormat::Ansi),
+            "html" => Some(RenderFormat::Html),
+            _ => None,
+        }
+    }
+
+    fn open(&self, mark: Mark) -> &'static str {
+        match (self, mark) {
+            (_, Mark::Empty) => "",
+            (RenderFormat::Ansi, Mark::PartNumber) => "\x1b[32m",
+            (RenderFormat::Ansi, Mark::OtherNumber) => "\x1b[31m",
+            (RenderFormat::Ansi, Mark::Gear) => "\x1b[1;33m",
+            (RenderFormat::Ansi, Mark::Symbol) => "\x1b[36m",
+            (RenderFormat::Html, Mark::PartNumber) => "<span class=\"part\">",
+            (RenderFormat::Html, Mark::OtherNumber) => "<span class=\"other\">",
+            (RenderFormat::Html, Mark::Gear) => "<span class=\"gear\">",
+            (RenderFormat::Html, Mark::Symbol) => "<span class=\"symbol\">",
+        }
+    }
+
+    fn close(&self, mark: Mark) -> &'static str {
+        match (self, mark) {
+            (_, Mark::Empty) => "",
+            (RenderFormat::Ansi, _) => "\x1b[0m",
+            (RenderFormat::Html, _) => "</span>",
+        }
+    }
+
+    fn escape(&self, symbol: char) -> String {
+        match (self, symbol) {
+            (RenderFormat::Html, '&') => String::from("&amp;"),
+            (RenderFormat::Html, '<') => String::from("&lt;"),
+            (RenderFormat::Html, '>') => String::from("&gt;"),
+            _ => symbol.to_string(),
+        }
+    }
+}
+
+impl Engine {
+    pub fn marks(&self, rules: &GearRules, adjacency: Adjacency) -> Grid<Mark> {
+        let (width, height) = self.size();
+        let mut marks = Grid::filled(width, height, Mark::Empty);
+
+        for number in self.numbers() {
+            let mark = match self.adjacent_symbols(number, adjacency).is_empty() {
+                true => Mark::OtherNumber,
+                false => Mark::PartNumber,
+            };
+
+            for position in number.positions() {
+                marks.set(&position, mark);
+            }
+        }
+
+        for report in self.symbol_reports(rules, adjacency) {
+            let mark = match report.is_gear {
+                true => Mark::Gear,
+                false => Mark::Symbol,
+            };
+
+            marks.set(&report.position, mark);
+        }
+
+        marks
+    }
+
+    pub fn render(&self, format: RenderFormat, rules: &GearRules, adjacency: Adjacency) -> String {
+        let marks = self.marks(rules, adjacency);
+        let (width, height) = self.size();
+
+        let mut output = String::new();
+
+        if format == RenderFormat::Html {
+            output.push_str("<pre class=\"schematic\">\n");
+        }
+
+        for y in 0..height {
+            let mut current = Mark::Empty;
+
+            for x in 0..width {
+                let position = (x, y);
+                let mark = marks.get(&position).copied().unwrap_or(Mark::Empty);
+
+                // consecutive cells with the same mark share one span
+                if mark != current {
+                    output.push_str(format.close(current));
+                    output.push_str(format.open(mark));
+                    current = mark;
+                }
+
+                let symbol = self.get(&position).unwrap_or('.');
+                output.push_str(&format.escape(symbol));
+            }
+
+            output.push_str(format.close(current));
+            output.push('\n');
+        }
+
+        if format == RenderFormat::Html {
+            output.push_str("</pre>\n");
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn engine() -> Engine {
+        Engine::new(String::from("467..114..\n...*......\n..35.&633.")).unwrap()
+    }
+
+    #[test]
+    fn marks_numbers_and_symbols() {
+        let marks = engine().marks(&GearRules::default(), Adjacency::Eight);
+
+        assert_eq!(marks.get(&(0, 0)), Some(&Mark::PartNumber));
+        assert_eq!(marks.get(&(5, 0)), Some(&Mark::OtherNumber));
+        assert_eq!(marks.get(&(3, 1)), Some(&Mark::Gear));
+        assert_eq!(marks.get(&(5, 2)), Some(&Mark::Symbol));
+        assert_eq!(marks.get(&(4, 2)), Some(&Mark::Empty));
+    }
+
+    #[test]
+    fn marks_gears_whose_ratio_is_zero() {
+        let engine = Engine::new(String::from("2*0")).unwrap();
+        let marks = engine.marks(&GearRules::default(), Adjacency::Eight);
+
+        assert_eq!(marks.get(&(1, 0)), Some(&Mark::Gear));
+    }
+
+    #[test]
+    fn renders_html() {
+        let html = engine().render(RenderFormat::Html, &GearRules::default(), Adjacency::Eight);
+
+        assert_eq!(
+            html,
+            "<pre class=\"schematic\">\n\
+             <span class=\"part\">467</span>..<span class=\"other\">114</span>..\n\
+             ...<span class=\"gear\">*</span>......\n\
+             ..<span class=\"part\">35</span>.<span class=\"symbol\">&amp;</span><span class=\"part\">633</span>.\n\
+             </pre>\n"
+        );
+    }
+
+    #[test]
+    fn renders_ansi() {
+        let text = engine().render(RenderFormat::Ansi, &GearRules::default(), Adjacency::Eight);
+        let first_line = text.lines().next().unwrap();
+
+        assert_eq!(first_line, "\x1b[32m467\x1b[0m..\x1b[31m114\x1b[0m..");
+    }
+}
@@ -0,0 +1,36 @@
+mod engine;
+
+use common::{Answer, ParseError, Solution};
+pub use engine::{
+    format_symbol_reports, Adjacency, Aggregation, Engine, EngineNumber, EngineSymbol, GearRule,
+    GearRules, Mark, RenderFormat, SymbolReport,
+};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Engine;
+
+    fn parse(text: &str) -> Result<Engine, ParseError> {
+        Engine::new(text.to_string())
+    }
+
+    fn part1(input: &Engine) -> Answer {
+        input.calculate_part_score().into()
+    }
+
+    fn part2(input: &Engine) -> Answer {
+        input.calculate_gear_ratio().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day3>(include_str!("../readme.md"));
+    }
+}
@@ -1,21 +1,35 @@
-mod engine;
-
-use engine::Engine;
-use std::fs::read_to_string;
+use common::{print_answers, Solution};
+use day3::{format_symbol_reports, Adjacency, Day3, Engine, GearRules, RenderFormat};
+use std::{env, fs::read_to_string};
 
 fn main() {
-    let file_path = "input.txt";
-    let schematic = read_to_string(file_path).unwrap();
-
-    let engine = Engine::new(schematic);
+    let text = read_to_string("input.txt").unwrap();
+    let args: Vec<String> = env::args().skip(1).collect();
 
-    let part_score = engine.calculate_part_score();
+    let engine = Day3::parse(&text).unwrap();
 
-    println!("grand total: {part_score}");
-    assert_eq!(part_score, 528819);
-
-    let gear_ratio = engine.calculate_gear_ratio();
+    match args.iter().map(String::as_str).collect::<Vec<&str>>()[..] {
+        // --rules <path> reports every symbol under the gear rules in the file
+        ["--rules", path] => {
+            let rules = GearRules::from_text(&read_to_string(path).unwrap()).unwrap();
+            print_reports(&engine, &rules);
+        }
+        // --render <ansi|html> marks part numbers, other numbers, gears and symbols
+        ["--render", format] => {
+            let format = RenderFormat::from_text(format).expect("expected 'ansi' or 'html'");
+            print!(
+                "{}",
+                engine.render(format, &GearRules::default(), Adjacency::Eight)
+            );
+        }
+        _ => print_answers::<Day3>(&text),
+    }
+}
 
-    println!("grand total: {gear_ratio}");
-    assert_eq!(gear_ratio, 80403602);
+fn print_reports(engine: &Engine, rules: &GearRules) {
+    print!(
+        "{}",
+        format_symbol_reports(&engine.symbol_reports(rules, Adjacency::Eight))
+    );
+    println!("gear score: {}", engine.gear_score(rules, Adjacency::Eight));
 }
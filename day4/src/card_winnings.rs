@@ -1,58 +1,140 @@
-use crate::scratch_card::ScratchCard;
-use std::{collections::HashMap, fs::read_to_string};
+use crate::{rules::PrizeRule, scratch_card::ScratchCard};
 
-pub fn get_total_cards_won(file_path: &str) -> usize {
-    let text = read_to_string(file_path).unwrap();
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardBreakdown {
+    pub id: u32,
+    pub matches: usize,
+    // the original card included
+    pub copies: u128,
+    // copies of later cards won by all copies of this one
+    pub generated: u128,
+}
 
-    let mut resolved_cards: Vec<u32> = Vec::new();
-    let mut unresolved_cards: Vec<u32> = Vec::new();
-    let mut winnings_by_id: HashMap<u32, u32> = HashMap::new();
+pub fn get_total_cards_won(cards: &[ScratchCard], rule: PrizeRule) -> u128 {
+    resolve_cards(cards, rule)
+        .iter()
+        .fold(0, |total: u128, card| {
+            total
+                .checked_add(card.copies)
+                .expect("card count overflows u128")
+        })
+}
 
-    text.lines().for_each(|line| {
-        let scratch_card = ScratchCard::new(line);
-        let id = scratch_card.id;
-        let score = scratch_card.find_scoring_numbers().len();
+// the deck is ordered by id; cards only win copies of cards further down the deck, or of
+// cards already scratched when the prize wraps around, so a single pass settles every count
+pub fn resolve_cards(cards: &[ScratchCard], rule: PrizeRule) -> Vec<CardBreakdown> {
+    let mut deck: Vec<usize> = (0..cards.len()).collect();
+    deck.sort_by_key(|index| cards[*index].id);
 
-        winnings_by_id.insert(id, score as u32);
-        unresolved_cards.push(id)
-    });
+    let mut breakdown: Vec<CardBreakdown> = cards
+        .iter()
+        .map(|card| CardBreakdown {
+            id: card.id,
+            matches: card.find_scoring_numbers().len(),
+            copies: 1,
+            generated: 0,
+        })
+        .collect();
 
-    resolve_cards(&mut unresolved_cards, &mut resolved_cards, &winnings_by_id);
+    for (position, index) in deck.iter().enumerate() {
+        let CardBreakdown {
+            matches, copies, ..
+        } = breakdown[*index];
 
-    resolved_cards.len()
-}
+        let won = rule.won_positions(position, matches, deck.len());
 
-fn resolve_cards(
-    unresolved_cards: &mut Vec<u32>,
-    resolved_cards: &mut Vec<u32>,
-    winnings_by_id: &HashMap<u32, u32>,
-) {
-    while unresolved_cards.len() > 0 {
-        let next = unresolved_cards.pop().unwrap_or(0);
-        if next == 0 {
-            break;
+        for won_position in &won {
+            let target = &mut breakdown[deck[*won_position]];
+            target.copies = target
+                .copies
+                .checked_add(copies)
+                .expect("card count overflows u128");
         }
 
-        resolved_cards.push(next);
+        breakdown[*index].generated = copies
+            .checked_mul(won.len() as u128)
+            .expect("card count overflows u128");
+    }
 
-        let winnings = *winnings_by_id.get(&next).unwrap_or(&0);
-        if winnings == 0 {
-            continue;
-        }
+    breakdown
+}
 
-        let mut won_cards = get_won_cards(next, winnings);
-        unresolved_cards.append(&mut won_cards);
-    }
+pub fn format_breakdown(breakdown: &[CardBreakdown]) -> String {
+    let header = format!("{:<6}{:<9}{:<12}generated\n", "card", "matches", "copies");
+
+    let rows: String = breakdown
+        .iter()
+        .map(|card| {
+            format!(
+                "{:<6}{:<9}{:<12}{}\n",
+                card.id, card.matches, card.copies, card.generated
+            )
+        })
+        .collect();
+
+    header + &rows
 }
 
-fn get_won_cards(id: u32, winnings: u32) -> Vec<u32> {
-    let mut result = vec![];
-    let mut add = 1;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::parse_lines;
 
-    while add <= winnings {
-        result.push(id + add);
-        add += 1;
+    fn cards() -> Vec<ScratchCard> {
+        let text = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+        parse_lines(text, ScratchCard::new).unwrap()
     }
 
-    result
+    #[test]
+    fn counts_copies_per_card() {
+        let breakdown = resolve_cards(&cards(), PrizeRule::CapAtDeckEnd);
+        let copies: Vec<u128> = breakdown.iter().map(|card| card.copies).collect();
+
+        assert_eq!(copies, vec![1, 2, 4, 8, 14, 1]);
+        assert_eq!(breakdown[1].matches, 2);
+        assert_eq!(breakdown[1].generated, 4);
+        assert_eq!(get_total_cards_won(&cards(), PrizeRule::CapAtDeckEnd), 30);
+    }
+
+    #[test]
+    fn resolves_cards_in_any_order() {
+        let mut cards = cards();
+        cards.reverse();
+
+        assert_eq!(get_total_cards_won(&cards, PrizeRule::CapAtDeckEnd), 30);
+    }
+
+    #[test]
+    fn wraps_prizes_around_the_deck() {
+        let breakdown = resolve_cards(&cards()[2..], PrizeRule::Wraparound);
+        let copies: Vec<u128> = breakdown.iter().map(|card| card.copies).collect();
+
+        // card 3 wins 4 and 5, card 4 wins 5, card 6, 5 win nothing
+        assert_eq!(copies, vec![1, 2, 4, 1]);
+
+        let cards = cards();
+        let breakdown = resolve_cards(&cards[..2], PrizeRule::Wraparound);
+        let copies: Vec<u128> = breakdown.iter().map(|card| card.copies).collect();
+
+        // card 1 has 4 matches and wins 2, 1, 2, 1; card 2 then hands its 3 copies to 1 and 2
+        assert_eq!(copies, vec![6, 6]);
+    }
+
+    #[test]
+    fn formats_breakdown() {
+        let breakdown = resolve_cards(&cards()[4..], PrizeRule::CapAtDeckEnd);
+
+        assert_eq!(
+            format_breakdown(&breakdown),
+            "card  matches  copies      generated\n\
+             5     0        1           0\n\
+             6     0        1           0\n"
+        );
+    }
 }
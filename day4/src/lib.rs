@@ -0,0 +1,39 @@
+mod card_scores;
+mod card_winnings;
+mod rules;
+mod scratch_card;
+
+pub use card_scores::get_all_card_scores;
+pub use card_winnings::{format_breakdown, get_total_cards_won, resolve_cards, CardBreakdown};
+use common::{parse_lines, Answer, ParseError, Solution};
+pub use rules::{PrizeRule, ScoringRule};
+pub use scratch_card::ScratchCard;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<ScratchCard>;
+
+    fn parse(text: &str) -> Result<Vec<ScratchCard>, ParseError> {
+        parse_lines(text, ScratchCard::new)
+    }
+
+    fn part1(input: &Vec<ScratchCard>) -> Answer {
+        get_all_card_scores(input, ScoringRule::default()).into()
+    }
+
+    fn part2(input: &Vec<ScratchCard>) -> Answer {
+        get_total_cards_won(input, PrizeRule::default()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day4>(include_str!("../readme.md"));
+    }
+}
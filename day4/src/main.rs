@@ -1,19 +1,77 @@
-mod card_scores;
-mod card_winnings;
-mod scratch_card;
+use common::{print_answers, Solution};
+use day4::{
+    format_breakdown, get_all_card_scores, get_total_cards_won, resolve_cards, Day4, PrizeRule,
+    ScoringRule,
+};
+use std::{env, fs::read_to_string, process::ExitCode};
 
-use card_scores::get_all_card_scores;
-use card_winnings::get_total_cards_won;
+const USAGE: &str =
+    "usage: day4 [--scoring <doubling|linear|fibonacci>] [--prize <cap|wraparound>] [--breakdown]";
 
-fn main() {
-    let file_path = "input.txt";
-    let score = get_all_card_scores(file_path);
+#[derive(Default)]
+struct Options {
+    scoring: Option<ScoringRule>,
+    prize: Option<PrizeRule>,
+    // lists the copies each card ends up with and hands out
+    breakdown: bool,
+}
+
+fn parse_options(args: &[String]) -> Result<Options, String> {
+    let mut options = Options::default();
+
+    let mut args = args.iter().map(String::as_str);
+
+    while let Some(argument) = args.next() {
+        match argument {
+            "--scoring" => {
+                let value = args.next().unwrap_or_default();
+                options.scoring = Some(
+                    ScoringRule::from_text(value)
+                        .ok_or(format!("unknown scoring rule '{value}'"))?,
+                );
+            }
+            "--prize" => {
+                let value = args.next().unwrap_or_default();
+                options.prize = Some(
+                    PrizeRule::from_text(value).ok_or(format!("unknown prize rule '{value}'"))?,
+                );
+            }
+            "--breakdown" => options.breakdown = true,
+            _ => return Err(format!("unexpected argument '{argument}'")),
+        }
+    }
+
+    Ok(options)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let options = match parse_options(&args) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("error: {error}\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
 
-    println!("total score: {score}");
-    assert_eq!(score, 21105);
+    let text = read_to_string("input.txt").unwrap();
 
-    let cards_won = get_total_cards_won(file_path);
+    match (options.breakdown, options.scoring, options.prize) {
+        (true, _, prize) => {
+            let cards = Day4::parse(&text).unwrap();
+            let breakdown = resolve_cards(&cards, prize.unwrap_or_default());
+            print!("{}", format_breakdown(&breakdown));
+        }
+        (false, None, None) => print_answers::<Day4>(&text),
+        (false, scoring, prize) => {
+            let cards = Day4::parse(&text).unwrap();
+            let scores = get_all_card_scores(&cards, scoring.unwrap_or_default());
+            let won = get_total_cards_won(&cards, prize.unwrap_or_default());
+            println!("part 1: {scores}");
+            println!("part 2: {won}");
+        }
+    }
 
-    println!("total cards won: {cards_won}");
-    assert_eq!(cards_won, 5329815);
+    ExitCode::SUCCESS
 }
@@ -0,0 +1,101 @@
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScoringRule {
+    // 1 point for the first match, doubled for every further match
+    #[default]
+    Doubling,
+    // 1 point per match
+    Linear,
+    // 1, 2, 3, 5, 8, ... points, each score the sum of the previous two
+    Fibonacci,
+}
+
+impl ScoringRule {
+    pub fn from_text(text: &str) -> Option<ScoringRule> {
+        match text {
+            "doubling" => Some(ScoringRule::Doubling),
+            "linear" => Some(ScoringRule::Linear),
+            "fibonacci" => Some(ScoringRule::Fibonacci),
+            _ => None,
+        }
+    }
+
+    pub fn score(&self, matches: usize) -> u128 {
+        if matches == 0 {
+            return 0;
+        }
+
+        let overflow = "card score overflows u128";
+
+        match self {
+            ScoringRule::Doubling => u32::try_from(matches - 1)
+                .ok()
+                .and_then(|exponent| 1_u128.checked_shl(exponent))
+                .expect(overflow),
+            ScoringRule::Linear => matches as u128,
+            ScoringRule::Fibonacci => {
+                let (mut previous, mut current) = (1_u128, 1_u128);
+
+                for _ in 1..matches {
+                    (previous, current) = (current, current.checked_add(previous).expect(overflow));
+                }
+
+                current
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PrizeRule {
+    // copies of the next N cards, where N is the number of matches; none past the end of the deck
+    #[default]
+    CapAtDeckEnd,
+    // copies of the next N cards, continuing from the start of the deck; copies of cards that
+    // were already scratched are kept, but not scratched again
+    Wraparound,
+}
+
+impl PrizeRule {
+    pub fn from_text(text: &str) -> Option<PrizeRule> {
+        match text {
+            "cap" => Some(PrizeRule::CapAtDeckEnd),
+            "wraparound" => Some(PrizeRule::Wraparound),
+            _ => None,
+        }
+    }
+
+    // deck positions of the cards won by the card at `position`
+    pub fn won_positions(&self, position: usize, matches: usize, deck_size: usize) -> Vec<usize> {
+        let ahead = (1..=matches).map(|offset| position + offset);
+
+        match self {
+            PrizeRule::CapAtDeckEnd => ahead.filter(|won| won < &deck_size).collect(),
+            PrizeRule::Wraparound => ahead.map(|won| won % deck_size).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scores_matches() {
+        let scores = |rule: ScoringRule| {
+            (0..=5)
+                .map(|matches| rule.score(matches))
+                .collect::<Vec<u128>>()
+        };
+
+        assert_eq!(scores(ScoringRule::Doubling), vec![0, 1, 2, 4, 8, 16]);
+        assert_eq!(scores(ScoringRule::Linear), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(scores(ScoringRule::Fibonacci), vec![0, 1, 2, 3, 5, 8]);
+    }
+
+    #[test]
+    fn finds_won_positions() {
+        assert_eq!(PrizeRule::CapAtDeckEnd.won_positions(3, 3, 5), vec![4]);
+        assert_eq!(PrizeRule::Wraparound.won_positions(3, 3, 5), vec![4, 0, 1]);
+        assert_eq!(PrizeRule::Wraparound.won_positions(0, 0, 5), vec![]);
+    }
+}
@@ -1,3 +1,7 @@
+use crate::rules::ScoringRule;
+use common::{parse_number, ParseError};
+
+#[derive(Debug)]
 pub struct ScratchCard {
     pub id: u32,
     numbers: Vec<u32>,
@@ -5,50 +9,71 @@ pub struct ScratchCard {
 }
 
 impl ScratchCard {
-    pub fn new(text: &str) -> ScratchCard {
-        let (id_part, numbers_part) = text.split_at(8);
+    pub fn new(text: &str) -> Result<ScratchCard, ParseError> {
+        let (id_part, numbers_part) = text
+            .split_once(':')
+            .ok_or_else(|| ParseError::at(text, text, "expected 'Card <id>: <numbers>'"))?;
 
-        let id: u32 = id_part.split(' ').last().unwrap().parse().unwrap_or(0);
+        let id = id_part
+            .strip_prefix("Card")
+            .ok_or_else(|| ParseError::at(text, id_part, "expected 'Card <id>'"))?;
 
-        let parts: Vec<&str> = numbers_part.split('|').collect();
-        let part1 = parts.get(0);
-        let part2 = parts.get(1);
+        let (winning_numbers, numbers) = numbers_part.split_once('|').ok_or_else(|| {
+            ParseError::at(
+                text,
+                numbers_part,
+                "expected '<winning numbers> | <numbers>'",
+            )
+        })?;
 
-        ScratchCard {
-            id,
-            winning_numbers: find_numbers_in_text(part1.unwrap_or(&"")),
-            numbers: find_numbers_in_text(part2.unwrap_or(&"")),
-        }
+        Ok(ScratchCard {
+            id: parse_number(text, id)?,
+            winning_numbers: find_numbers_in_text(text, winning_numbers)?,
+            numbers: find_numbers_in_text(text, numbers)?,
+        })
     }
 }
 
 impl ScratchCard {
-    pub fn find_score(&self) -> u32 {
+    pub fn find_score(&self, rule: ScoringRule) -> u128 {
         let scoring_numbers = self.find_scoring_numbers();
 
-        calculate_score_for_numbers(&scoring_numbers)
+        rule.score(scoring_numbers.len())
     }
 
     pub fn find_scoring_numbers(&self) -> Vec<u32> {
         self.numbers
             .iter()
             .filter(|number| self.winning_numbers.contains(number))
-            .map(|number| *number)
+            .copied()
             .collect()
     }
 }
 
-fn calculate_score_for_numbers(scoring_numbers: &Vec<u32>) -> u32 {
-    scoring_numbers.iter().fold(0, |score, _| {
-        if score == 0 {
-            return 1;
-        }
-        score * 2
-    })
+fn find_numbers_in_text(text: &str, part: &str) -> Result<Vec<u32>, ParseError> {
+    part.split_whitespace()
+        .map(|value| parse_number(text, value))
+        .collect()
 }
 
-fn find_numbers_in_text(text: &str) -> Vec<u32> {
-    text.split_whitespace()
-        .map(|value| value.parse::<u32>().unwrap_or(0))
-        .collect()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_scratch_card() {
+        let card = ScratchCard::new("Card   3:  1 21 53 | 69 82  1").unwrap();
+
+        assert_eq!(card.id, 3);
+        assert_eq!(card.find_scoring_numbers(), vec![1]);
+    }
+
+    #[test]
+    fn rejects_malformed_scratch_cards() {
+        let error = ScratchCard::new("Card 1: 41 4x | 83").unwrap_err();
+        assert_eq!(error, ParseError::new(1, 12, "invalid number '4x'"));
+
+        let error = ScratchCard::new("Card 1: 41 48 83").unwrap_err();
+        assert_eq!(error.column, 8);
+    }
 }
@@ -0,0 +1,344 @@
+use crate::{
+    conversion_map::{ConversionMap, Link},
+    interval_set::IntervalSet,
+};
+use common::{parse_number, ParseError};
+use search::{dijkstra, SearchState};
+use std::collections::{BTreeMap, VecDeque};
+
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Vec<i64>,
+    // directed graph of categories, one map per edge
+    maps: BTreeMap<Link, ConversionMap>,
+}
+
+impl Almanac {
+    pub fn from_text(text: &str) -> Result<Almanac, ParseError> {
+        let sections = split_sections(text);
+        let mut sections = sections.iter().copied();
+
+        let seeds_section = sections.next().unwrap_or(text);
+        let seeds = seeds_section
+            .strip_prefix("seeds:")
+            .ok_or_else(|| ParseError::at(text, seeds_section, "expected 'seeds: <numbers>'"))?
+            .split_whitespace()
+            .map(|seed| parse_number(text, seed))
+            .collect::<Result<Vec<i64>, ParseError>>()?;
+
+        let mut maps = BTreeMap::new();
+
+        for section in sections {
+            let (link, map) = ConversionMap::from_section(section)
+                .map_err(|error| error.within(text, section))?;
+
+            if maps.contains_key(&link) {
+                return Err(ParseError::at(
+                    text,
+                    section,
+                    format!(
+                        "duplicate map from '{}' to '{}'",
+                        link.source, link.destination
+                    ),
+                ));
+            }
+
+            maps.insert(link, map);
+        }
+
+        let almanac = Almanac { seeds, maps };
+
+        match almanac.count_shortest_routes("seed", "location") {
+            0 => {
+                return Err(ParseError::at(
+                    text,
+                    seeds_section,
+                    "no maps lead from 'seed' to 'location'",
+                ))
+            }
+            1 => {}
+            _ => {
+                return Err(ParseError::at(
+                    text,
+                    seeds_section,
+                    "several routes of maps lead from 'seed' to 'location'",
+                ))
+            }
+        }
+
+        Ok(almanac)
+    }
+}
+
+impl Almanac {
+    // maps to apply in order, taking the fewest conversions if there are several routes; none
+    // when several routes take the fewest, as they may convert differently
+    pub fn route(&self, source: &str, destination: &str) -> Option<Vec<&ConversionMap>> {
+        if self.count_shortest_routes(source, destination) != 1 {
+            return None;
+        }
+
+        let graph = CategoryGraph {
+            almanac: self,
+            destination,
+        };
+
+        let result = dijkstra(&graph, source.to_string())?;
+
+        result
+            .path
+            .windows(2)
+            .map(|link| self.maps.get(&Link::new(&link[0], &link[1])))
+            .collect()
+    }
+
+    // breadth first, so every category's count is complete before it leads anywhere; the
+    // count saturates rather than overflowing on many branches
+    fn count_shortest_routes(&self, source: &str, destination: &str) -> usize {
+        let mut routes: BTreeMap<&str, (usize, usize)> = BTreeMap::from([(source, (0, 1))]);
+        let mut queue = VecDeque::from([source]);
+
+        while let Some(category) = queue.pop_front() {
+            let (distance, count) = routes[category];
+
+            if category == destination {
+                continue;
+            }
+
+            for link in self.maps.keys().filter(|link| link.source == category) {
+                match routes.get_mut(link.destination.as_str()) {
+                    None => {
+                        routes.insert(&link.destination, (distance + 1, count));
+                        queue.push_back(&link.destination);
+                    }
+                    Some((next_distance, next_count)) if *next_distance == distance + 1 => {
+                        *next_count = next_count.saturating_add(count);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        routes.get(destination).map_or(0, |(_, count)| *count)
+    }
+
+    // the maps along the route composed into one, so that each conversion is a single lookup
+    pub fn chain(&self, source: &str, destination: &str) -> Option<ConversionMap> {
+        let route = self.route(source, destination)?;
+
+        Some(
+            route
+                .iter()
+                .fold(ConversionMap::default(), |chain, map| chain.compose(map)),
+        )
+    }
+
+    // searches the route on every call, so converting many values is cheaper through one chain
+    pub fn convert(&self, value: i64, source: &str, destination: &str) -> Option<i64> {
+        let route = self.route(source, destination)?;
+
+        Some(route.iter().fold(value, |value, map| map.convert(value)))
+    }
+
+    // searches the route on every call too, like convert
+    pub fn convert_ranges(
+        &self,
+        ranges: &IntervalSet,
+        source: &str,
+        destination: &str,
+    ) -> Option<IntervalSet> {
+        let route = self.route(source, destination)?;
+
+        Some(
+            route
+                .iter()
+                .fold(ranges.clone(), |ranges, map| map.convert_ranges(&ranges)),
+        )
+    }
+}
+
+// groups of lines between lines that are empty or only whitespace, as slices of the text;
+// validation splits the almanac the same way
+pub fn split_sections(text: &str) -> Vec<&str> {
+    let mut sections = vec![];
+    let mut current: Option<(usize, usize)> = None;
+    let mut offset = 0;
+
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+
+        match (content.trim().is_empty(), current) {
+            (true, Some((start, end))) => {
+                sections.push(&text[start..end]);
+                current = None;
+            }
+            (true, None) => {}
+            (false, current_section) => {
+                let start = current_section.map_or(offset, |(start, _)| start);
+                current = Some((start, offset + content.len()));
+            }
+        }
+
+        offset += line.len();
+    }
+
+    sections.extend(current.map(|(start, end)| &text[start..end]));
+
+    sections
+}
+
+struct CategoryGraph<'a> {
+    almanac: &'a Almanac,
+    destination: &'a str,
+}
+
+impl SearchState<CategoryGraph<'_>> for String {
+    fn successors(&self, graph: &CategoryGraph) -> Vec<(Self, u32)> {
+        graph
+            .almanac
+            .maps
+            .keys()
+            .filter(|link| &link.source == self)
+            .map(|link| (link.destination.clone(), 1))
+            .collect()
+    }
+
+    fn is_goal(&self, graph: &CategoryGraph) -> bool {
+        self == graph.destination
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::parse_examples;
+
+    const ALMANAC: &str = "seeds: 79 14
+
+water-to-humidity map:
+10 0 5
+
+seed-to-water map:
+50 98 2
+52 50 48
+
+humidity-to-location map:
+0 10 3
+
+water-to-light map:
+1 2 3";
+
+    #[test]
+    fn splits_sections_on_blank_lines() {
+        let text = "seeds: 1\n\n  \na\nb\n \t\nc\r\n\r\nd\n";
+
+        assert_eq!(split_sections(text), vec!["seeds: 1", "a\nb", "c", "d"]);
+        assert_eq!(split_sections(""), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn parses_almanac() {
+        let almanac = Almanac::from_text(ALMANAC).unwrap();
+
+        assert_eq!(almanac.seeds, vec![79, 14]);
+        assert_eq!(almanac.maps.len(), 4);
+        assert_eq!(almanac.convert(79, "seed", "water"), Some(81));
+    }
+
+    #[test]
+    fn converts_between_reachable_categories() {
+        let almanac = Almanac::from_text(ALMANAC).unwrap();
+
+        assert_eq!(almanac.route("seed", "location").unwrap().len(), 3);
+        assert_eq!(almanac.convert(2, "water", "humidity"), Some(12));
+        assert_eq!(almanac.convert(99, "seed", "location"), Some(51));
+        assert_eq!(almanac.convert(1, "seed", "seed"), Some(1));
+        assert_eq!(almanac.convert(1, "light", "water"), None);
+        assert_eq!(almanac.convert(1, "seed", "fertilizer"), None);
+    }
+
+    #[test]
+    fn chains_maps_along_route() {
+        let almanac = Almanac::from_text(ALMANAC).unwrap();
+        let chain = almanac.chain("seed", "location").unwrap();
+
+        for seed in 0..120 {
+            assert_eq!(
+                Some(chain.convert(seed)),
+                almanac.convert(seed, "seed", "location")
+            );
+        }
+
+        // locations 0 to 2 are reached both from seeds converting through humidity 10 to 12 and
+        // from seeds keeping their number
+        assert!(chain.inverse().is_none());
+    }
+
+    #[test]
+    fn finds_seeds_reaching_location() {
+        let readme = include_str!("../readme.md");
+        let example = &parse_examples(readme).unwrap()[0];
+        let almanac = Almanac::from_text(&example.input).unwrap();
+
+        let inverse = almanac
+            .chain("seed", "location")
+            .unwrap()
+            .inverse()
+            .unwrap();
+
+        assert_eq!(inverse.convert(35), 13);
+        assert_eq!(inverse.convert(46), 82);
+    }
+
+    #[test]
+    fn reports_position_of_bad_map_line() {
+        let error = Almanac::from_text("seeds: 79 14\n\nseed-to-soil map:\n50 98 x").unwrap_err();
+
+        assert_eq!(error, ParseError::new(4, 7, "invalid number 'x'"));
+    }
+
+    #[test]
+    fn rejects_missing_and_duplicate_links() {
+        let error = Almanac::from_text("seeds: 79\n\nseed-to-soil map:\n50 98 2").unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(1, 1, "no maps lead from 'seed' to 'location'")
+        );
+
+        let error = Almanac::from_text(
+            "seeds: 79\n\nseed-to-location map:\n50 98 2\n\nseed-to-location map:\n1 2 3",
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(6, 1, "duplicate map from 'seed' to 'location'")
+        );
+    }
+
+    #[test]
+    fn rejects_several_shortest_routes() {
+        let text = "seeds: 79\n\nseed-to-soil map:\n1 2 3\n\nseed-to-water map:\n4 5 6\n\n\
+                    soil-to-location map:\n7 8 9\n\nwater-to-location map:\n1 2 3";
+
+        let error = Almanac::from_text(text).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(
+                1,
+                1,
+                "several routes of maps lead from 'seed' to 'location'"
+            )
+        );
+
+        // a longer route besides the shortest one is not ambiguous
+        let text = "seeds: 79\n\nseed-to-location map:\n1 2 3\n\nseed-to-soil map:\n4 5 6\n\n\
+                    soil-to-location map:\n7 8 9\n\nsoil-to-water map:\n1 1 1\n\n\
+                    soil-to-light map:\n1 1 1\n\nwater-to-heat map:\n1 1 1\n\nlight-to-heat map:\n1 1 1";
+        let almanac = Almanac::from_text(text).unwrap();
+
+        assert_eq!(almanac.route("seed", "location").unwrap().len(), 1);
+        assert_eq!(almanac.convert(2, "seed", "location"), Some(1));
+        assert!(almanac.route("soil", "heat").is_none());
+        assert_eq!(almanac.convert(1, "soil", "heat"), None);
+    }
+}
@@ -1,157 +1,262 @@
-use std::cmp::min;
-
-// start, end
-pub type Range = (i64, i64);
+use crate::interval_set::{Interval, IntervalSet};
+use common::{parse_lines, parse_number, ParseError};
 
 #[derive(Debug)]
-struct ConversionRange {
-    source_start: i64,
-    source_end: i64,
-    add: i64,
+pub struct ConversionRange {
+    pub source: Interval,
+    pub add: i64,
 }
 
 impl ConversionRange {
-    pub fn new(input: &str) -> ConversionRange {
-        let numbers: Vec<&str> = input.split(' ').collect();
+    pub fn new(input: &str) -> Result<ConversionRange, ParseError> {
+        let numbers = input
+            .split_whitespace()
+            .map(|number| parse_number(input, number))
+            .collect::<Result<Vec<i64>, ParseError>>()?;
+
+        let [destination_start, source_start, length] = numbers[..] else {
+            return Err(ParseError::at(
+                input,
+                input,
+                "expected '<destination start> <source start> <length>'",
+            ));
+        };
+
+        Ok(ConversionRange {
+            source: Interval::with_length(source_start, length),
+            add: destination_start - source_start,
+        })
+    }
+}
+
+// categories a map converts between, named by its "<source>-to-<destination> map:" header
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Link {
+    pub source: String,
+    pub destination: String,
+}
 
-        let source_start: i64 = numbers.get(1).unwrap_or(&"0").parse().unwrap();
-        let destination_start: i64 = numbers.get(0).unwrap_or(&"0").parse().unwrap();
-        let length: i64 = numbers.get(2).unwrap_or(&"0").parse().unwrap();
+impl Link {
+    pub fn new(source: &str, destination: &str) -> Link {
+        Link {
+            source: source.to_string(),
+            destination: destination.to_string(),
+        }
+    }
 
-        ConversionRange {
-            add: destination_start - source_start,
-            source_start,
-            source_end: source_start + length - 1,
+    pub fn from_header(header: &str) -> Result<Link, ParseError> {
+        let expected = "expected '<source>-to-<destination> map:'";
+
+        let (source, destination) = header
+            .strip_suffix(" map:")
+            .and_then(|names| names.split_once("-to-"))
+            .ok_or_else(|| ParseError::at(header, header, expected))?;
+
+        let is_name = |name: &str| !name.is_empty() && !name.contains(char::is_whitespace);
+        if !is_name(source) || !is_name(destination) {
+            return Err(ParseError::at(header, header, expected));
         }
+
+        Ok(Link::new(source, destination))
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct ConversionMap {
+    // sorted by source start, without overlaps
     ranges: Vec<ConversionRange>,
 }
 
 impl ConversionMap {
-    pub fn from_formatted_text(text: Option<&str>) -> ConversionMap {
-        let map_text: String = text
-            .unwrap_or(&"")
-            .lines()
-            .skip(1)
-            .fold(String::new(), |previous, next| previous + next + "\n");
+    // a section headed by e.g. "seed-to-soil map:"
+    pub fn from_section(text: &str) -> Result<(Link, ConversionMap), ParseError> {
+        let (header, ranges) = text.split_once('\n').unwrap_or((text, ""));
 
-        ConversionMap::from_text(map_text)
+        let link = Link::from_header(header).map_err(|error| error.within(text, header))?;
+        let map = ConversionMap::from_text(ranges).map_err(|error| error.within(text, ranges))?;
+
+        Ok((link, map))
     }
 
-    pub fn from_text(text: String) -> ConversionMap {
-        let ranges = text
-            .lines()
-            .filter(|line| line.split(' ').collect::<Vec<&str>>().len() == 3)
-            .map(|line| ConversionRange::new(line))
-            .collect();
+    pub fn from_text(text: &str) -> Result<ConversionMap, ParseError> {
+        let ranges = parse_lines(text, ConversionRange::new)?;
 
-        ConversionMap { ranges }
+        Ok(ConversionMap::from_ranges(ranges))
+    }
+
+    // where listed ranges overlap, the one listed first wins
+    fn from_ranges(listed: Vec<ConversionRange>) -> ConversionMap {
+        let mut map = ConversionMap::default();
+
+        for range in listed {
+            let uncovered: Vec<ConversionRange> = map
+                .segments(range.source)
+                .into_iter()
+                .filter(|(_, covered)| covered.is_none())
+                .map(|(source, _)| ConversionRange {
+                    source,
+                    add: range.add,
+                })
+                .collect();
+
+            map.ranges.extend(uncovered);
+            map.ranges.sort_by_key(|range| range.source);
+        }
+
+        map
     }
 }
 
 impl ConversionMap {
     pub fn convert(&self, input: i64) -> i64 {
-        let range = self
-            .ranges
-            .iter()
-            .find(|range| range.source_start <= input && range.source_end >= input);
-
-        if range.is_none() {
-            return input;
+        match find_range_containing_value(&self.ranges, input) {
+            Some(range) => convert_by_range(input, range),
+            None => input,
         }
-
-        convert_by_range(input, range.unwrap())
     }
 
-    pub fn convert_ranges(&self, ranges: Vec<Range>) -> Vec<Range> {
-        ranges
-            .iter()
-            .map(|range| self.convert_range(*range))
-            .flatten()
-            .collect()
+    pub fn convert_ranges(&self, input: &IntervalSet) -> IntervalSet {
+        IntervalSet::new(
+            input
+                .intervals()
+                .iter()
+                .flat_map(|interval| self.segments(*interval))
+                .map(|(segment, range)| segment.shifted(range.map_or(0, |range| range.add))),
+        )
     }
 
-    pub fn convert_range(&self, input: Range) -> Vec<Range> {
-        if self.ranges.len() == 0 {
-            return vec![input];
-        }
-
-        let mut result: Vec<Range> = vec![];
-        let ranges: &Vec<ConversionRange> = &self.ranges;
-        let (initial_range_start, initial_range_end) = input;
-
-        let mut current = initial_range_start;
-
-        while current <= initial_range_end {
-            let matched_range = find_range_containing_value(ranges, current);
-
-            if matched_range.is_some() {
-                let matched_range = matched_range.unwrap();
-                let end = min(matched_range.source_end, initial_range_end);
+    pub fn convert_range(&self, input: Interval) -> IntervalSet {
+        self.convert_ranges(&IntervalSet::new([input]))
+    }
 
-                result.push((
-                    convert_by_range(current, matched_range),
-                    convert_by_range(end, matched_range),
-                ));
+    // splits the input into parts converted by a single range, or by none
+    fn segments(&self, input: Interval) -> Vec<(Interval, Option<&ConversionRange>)> {
+        let mut segments = vec![];
+        let mut current = input.start;
 
-                current = end + 1;
-                continue;
-            }
+        // first range that does not end before the input
+        let mut index = self
+            .ranges
+            .partition_point(|range| range.source.end <= input.start);
+
+        while current < input.end {
+            let segment = match self.ranges.get(index) {
+                Some(range) if range.source.start <= current => {
+                    index += 1;
+                    let end = range.source.end.min(input.end);
+                    (Interval::new(current, end), Some(range))
+                }
+                Some(range) if range.source.start < input.end => {
+                    (Interval::new(current, range.source.start), None)
+                }
+                _ => (Interval::new(current, input.end), None),
+            };
+
+            current = segment.0.end;
+            segments.push(segment);
+        }
 
-            let next_range = find_next_range(ranges, current);
+        segments
+    }
 
-            if next_range.is_some() {
-                let next_range = next_range.unwrap();
-                let end = next_range.source_start - 1;
+    // smallest interval holding every source and destination, outside of which the map is identity
+    fn bounds(&self) -> Option<Interval> {
+        let start = self
+            .ranges
+            .iter()
+            .map(|range| range.source.start.min(range.source.start + range.add))
+            .min()?;
+        let end = self
+            .ranges
+            .iter()
+            .map(|range| range.source.end.max(range.source.end + range.add))
+            .max()?;
 
-                result.push((initial_range_start, end));
-                current = end + 1;
+        Some(Interval::new(start, end))
+    }
+}
 
-                continue;
+impl ConversionMap {
+    // single map converting like `self` followed by `next`
+    pub fn compose(&self, next: &ConversionMap) -> ConversionMap {
+        let bounds =
+            [self.bounds(), next.bounds()]
+                .into_iter()
+                .flatten()
+                .reduce(|bounds, other| {
+                    Interval::new(bounds.start.min(other.start), bounds.end.max(other.end))
+                });
+
+        let Some(bounds) = bounds else {
+            return ConversionMap::default();
+        };
+
+        let mut ranges: Vec<ConversionRange> = vec![];
+
+        for (segment, first) in self.segments(bounds) {
+            let add = first.map_or(0, |range| range.add);
+
+            for (next_segment, second) in next.segments(segment.shifted(add)) {
+                let total = add + second.map_or(0, |range| range.add);
+
+                if total == 0 {
+                    continue;
+                }
+
+                let source = next_segment.shifted(-add);
+
+                match ranges.last_mut() {
+                    Some(last) if last.add == total && last.source.end == source.start => {
+                        last.source.end = source.end;
+                    }
+                    _ => ranges.push(ConversionRange { source, add: total }),
+                }
             }
-
-            result.push((current, initial_range_end));
-            break;
         }
 
-        result
+        ConversionMap { ranges }
     }
-}
 
-fn find_next_range(ranges: &Vec<ConversionRange>, start: i64) -> Option<&ConversionRange> {
-    ranges.iter().fold(None, |previous, next| {
-        if next.source_start < start {
-            return previous;
-        }
+    // map from destinations back to sources, if no two sources share a destination
+    pub fn inverse(&self) -> Option<ConversionMap> {
+        let Some(bounds) = self.bounds() else {
+            return Some(ConversionMap::default());
+        };
+
+        let mut ranges: Vec<ConversionRange> = self
+            .segments(bounds)
+            .into_iter()
+            .map(|(segment, range)| {
+                let add = range.map_or(0, |range| range.add);
+
+                ConversionRange {
+                    source: segment.shifted(add),
+                    add: -add,
+                }
+            })
+            .collect();
 
-        if previous.is_none() {
-            return Some(next);
-        }
+        ranges.sort_by_key(|range| range.source);
 
-        let previous = previous.unwrap();
+        if ranges
+            .windows(2)
+            .any(|pair| pair[0].source.end > pair[1].source.start)
+        {
+            return None;
+        }
 
-        let previous_difference = previous.source_start - start;
-        let next_difference = next.source_start - start;
+        ranges.retain(|range| range.add != 0);
 
-        match next_difference < previous_difference {
-            true => Some(next),
-            false => Some(previous),
-        }
-    })
+        Some(ConversionMap { ranges })
+    }
 }
 
-fn find_range_containing_value(
-    ranges: &Vec<ConversionRange>,
-    value: i64,
-) -> Option<&ConversionRange> {
-    ranges.iter().find(|range| {
-        let range_end = range.source_end;
-        range.source_start <= value && range_end >= value
-    })
+fn find_range_containing_value(ranges: &[ConversionRange], value: i64) -> Option<&ConversionRange> {
+    let index = ranges.partition_point(|range| range.source.end <= value);
+
+    ranges
+        .get(index)
+        .filter(|range| range.source.contains(value))
 }
 
 fn convert_by_range(value: i64, range: &ConversionRange) -> i64 {
@@ -166,11 +271,10 @@ mod test_conversion_range {
     fn constructs_conversion_range_from_text() {
         let input = "50 98 2";
 
-        let range = ConversionRange::new(input);
+        let range = ConversionRange::new(input).unwrap();
 
         assert_eq!(range.add, -48);
-        assert_eq!(range.source_start, 98);
-        assert_eq!(range.source_end, 99);
+        assert_eq!(range.source, Interval::new(98, 100));
     }
 }
 
@@ -180,61 +284,124 @@ mod test_conversion_map {
 
     #[test]
     fn constructed_conversion_map_converts_correctly() {
-        let input = String::from("50 98 2\n52 50 48");
-
-        let map = ConversionMap::from_text(input);
+        let map = ConversionMap::from_text("50 98 2\n52 50 48").unwrap();
 
         assert_eq!(map.convert(99), 51);
         assert_eq!(map.convert(60), 62);
     }
 
     #[test]
-    fn correctly_subdivides_input_range() {
-        let input = String::from("50 98 2\n52 50 48");
+    fn rejects_malformed_ranges() {
+        let error =
+            ConversionMap::from_section("seed-to-soil map:\n50 98 2\n52 5o 48").unwrap_err();
+        assert_eq!(error, ParseError::new(3, 4, "invalid number '5o'"));
+
+        let error = ConversionMap::from_section("seed-to-soil map:\n50 98").unwrap_err();
+        assert_eq!(error.line, 2);
 
-        let map = ConversionMap::from_text(input);
+        let error = ConversionMap::from_section("seed-to-soil\n50 98 2").unwrap_err();
+        assert_eq!(error.line, 1);
+
+        let error = ConversionMap::from_section("seed-soil map:\n50 98 2").unwrap_err();
+        assert_eq!(error.line, 1);
+    }
+
+    #[test]
+    fn reads_link_from_header() {
+        let (link, map) = ConversionMap::from_section("seed-to-soil map:\n50 98 2").unwrap();
+
+        assert_eq!(link, Link::new("seed", "soil"));
+        assert_eq!(map.convert(98), 50);
+    }
+
+    #[test]
+    fn correctly_subdivides_input_range() {
+        let map = ConversionMap::from_text("50 98 2\n52 50 48").unwrap();
 
         // input:
-        // [90, 91, 92, 93, 94, 95, 96, 97] (90, 97)
-        // [98, 99]
-        // [100]
+        // [90, 91, 92, 93, 94, 95, 96, 97] [90, 98)
+        // [98, 99] [98, 100)
+        // [100] [100, 101)
         // output:
-        // [92, 93, 94, 95, 96, 97, 98, 99] (92, 99)
-        // [50, 51] (50, 51)
-        // [100] (100, 100)
+        // [92, 93, 94, 95, 96, 97, 98, 99] [92, 100)
+        // [50, 51] [50, 52)
+        // [100] [100, 101), merged with [92, 100)
 
         assert_eq!(
-            map.convert_range((90, 100)),
-            vec![(92, 99), (50, 51), (100, 100)]
+            map.convert_range(Interval::new(90, 101)).intervals(),
+            &[Interval::new(50, 52), Interval::new(92, 101)]
+        );
+        assert_eq!(
+            map.convert_range(Interval::new(40, 51)).intervals(),
+            &[Interval::new(40, 50), Interval::new(52, 53)]
         );
-        assert_eq!(map.convert_range((40, 50)), vec![(40, 49), (52, 52)]);
     }
 
     #[test]
     fn finds_range_containing_value() {
-        let map = ConversionMap::from_text(String::from("50 98 2\n52 50 48"));
+        let map = ConversionMap::from_text("50 98 2\n52 50 48").unwrap();
         let ranges = &map.ranges;
 
-        let matched = find_range_containing_value(&ranges, 50);
-        assert_eq!(matched.unwrap().source_start, 50);
+        let matched = find_range_containing_value(ranges, 50);
+        assert_eq!(matched.unwrap().source.start, 50);
         assert_eq!(matched.unwrap().add, 2);
 
-        let matched = find_range_containing_value(&ranges, 99);
-        assert_eq!(matched.unwrap().source_start, 98);
+        let matched = find_range_containing_value(ranges, 99);
+        assert_eq!(matched.unwrap().source.start, 98);
         assert_eq!(matched.unwrap().add, -48);
 
-        let matched = find_range_containing_value(&ranges, 0);
-        assert_eq!(matched.is_none(), true);
+        let matched = find_range_containing_value(ranges, 0);
+        assert!(matched.is_none());
     }
 
     #[test]
-    fn finds_range_with_next_numerical_start() {
-        let map = ConversionMap::from_text(String::from("50 98 2\n52 50 48"));
-        let ranges = &map.ranges;
+    fn resolves_overlapping_ranges_in_listed_order() {
+        let map = ConversionMap::from_text("0 10 5\n100 12 6").unwrap();
 
-        let next = find_next_range(&ranges, 0);
+        assert_eq!(map.convert(12), 2);
+        assert_eq!(map.convert(15), 103);
+        assert_eq!(
+            map.convert_range(Interval::new(9, 19)).intervals(),
+            &[
+                Interval::new(0, 5),
+                Interval::new(9, 10),
+                Interval::new(18, 19),
+                Interval::new(103, 106)
+            ]
+        );
+    }
+
+    #[test]
+    fn composes_maps() {
+        let first = ConversionMap::from_text("50 98 2\n52 50 48").unwrap();
+        let second = ConversionMap::from_text("0 15 37\n37 52 2\n39 0 15").unwrap();
+        let composed = first.compose(&second);
+
+        for value in 0..120 {
+            assert_eq!(
+                composed.convert(value),
+                second.convert(first.convert(value))
+            );
+        }
+
+        let identity = ConversionMap::default();
+        assert_eq!(identity.compose(&first).convert(98), 50);
+        assert_eq!(first.compose(&identity).convert(98), 50);
+    }
+
+    #[test]
+    fn inverts_maps() {
+        let map = ConversionMap::from_text("50 98 2\n52 50 48").unwrap();
+        let inverse = map.inverse().unwrap();
+
+        for value in 0..120 {
+            assert_eq!(inverse.convert(map.convert(value)), value);
+        }
 
-        assert_eq!(next.unwrap().source_start, 50);
-        assert_eq!(next.unwrap().add, 2);
+        // 10 and 0 both convert to 10
+        assert!(ConversionMap::from_text("10 0 1")
+            .unwrap()
+            .inverse()
+            .is_none());
     }
 }
@@ -0,0 +1,215 @@
+use std::fmt::Display;
+
+// the numbers from `start` up to, but not including, `end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Interval {
+        Interval { start, end }
+    }
+
+    pub fn with_length(start: i64, length: i64) -> Interval {
+        Interval::new(start, start + length)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    pub fn len(&self) -> u128 {
+        match self.is_empty() {
+            true => 0,
+            false => (self.end as i128 - self.start as i128) as u128,
+        }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    // may be empty
+    pub fn intersection(&self, other: &Interval) -> Interval {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    pub fn shifted(&self, by: i64) -> Interval {
+        Interval::new(self.start + by, self.end + by)
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+// sorted intervals that neither overlap nor touch, and are never empty
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    // merges overlapping and touching intervals
+    pub fn new(intervals: impl IntoIterator<Item = Interval>) -> IntervalSet {
+        let mut sorted: Vec<Interval> = intervals
+            .into_iter()
+            .filter(|interval| !interval.is_empty())
+            .collect();
+        sorted.sort();
+
+        let mut merged: Vec<Interval> = vec![];
+
+        for interval in sorted {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+
+        IntervalSet { intervals: merged }
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // number of values in the set
+    pub fn len(&self) -> u128 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self
+            .intervals
+            .partition_point(|interval| interval.end <= value);
+
+        self.intervals
+            .get(index)
+            .is_some_and(|interval| interval.contains(value))
+    }
+}
+
+impl IntervalSet {
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        IntervalSet::new(self.intervals.iter().chain(&other.intervals).copied())
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = vec![];
+        let (mut index, mut other_index) = (0, 0);
+
+        while let (Some(interval), Some(other_interval)) =
+            (self.intervals.get(index), other.intervals.get(other_index))
+        {
+            let overlap = interval.intersection(other_interval);
+            if !overlap.is_empty() {
+                intervals.push(overlap);
+            }
+
+            match interval.end < other_interval.end {
+                true => index += 1,
+                false => other_index += 1,
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = vec![];
+        let mut other_index = 0;
+
+        for interval in &self.intervals {
+            // intervals of `other` ending before this one can't cut any later ones either
+            while other.intervals[other_index..]
+                .first()
+                .is_some_and(|cut| cut.end <= interval.start)
+            {
+                other_index += 1;
+            }
+
+            let mut start = interval.start;
+
+            for cut in other.intervals[other_index..]
+                .iter()
+                .take_while(|cut| cut.start < interval.end)
+            {
+                if start < cut.start {
+                    intervals.push(Interval::new(start, cut.start));
+                }
+
+                start = start.max(cut.end);
+            }
+
+            if start < interval.end {
+                intervals.push(Interval::new(start, interval.end));
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        IntervalSet::new(
+            intervals
+                .iter()
+                .map(|(start, end)| Interval::new(*start, *end)),
+        )
+    }
+
+    #[test]
+    fn merges_overlapping_and_touching_intervals() {
+        let intervals = set(&[(10, 15), (0, 3), (3, 5), (12, 20), (7, 7), (30, 31)]);
+
+        assert_eq!(intervals, set(&[(0, 5), (10, 20), (30, 31)]));
+        assert_eq!(intervals.intervals().len(), 3);
+        assert_eq!(intervals.len(), 16);
+        assert_eq!(intervals.min(), Some(0));
+        assert!(intervals.contains(19));
+        assert!(!intervals.contains(20));
+        assert!(!intervals.contains(5));
+        assert!(IntervalSet::default().is_empty());
+    }
+
+    #[test]
+    fn combines_sets() {
+        let first = set(&[(0, 10), (20, 30)]);
+        let second = set(&[(5, 25), (28, 29), (40, 50)]);
+
+        assert_eq!(first.union(&second), set(&[(0, 30), (40, 50)]));
+        assert_eq!(
+            first.intersection(&second),
+            set(&[(5, 10), (20, 25), (28, 29)])
+        );
+        assert_eq!(
+            first.difference(&second),
+            set(&[(0, 5), (25, 28), (29, 30)])
+        );
+        assert_eq!(second.difference(&first), set(&[(10, 20), (40, 50)]));
+    }
+
+    #[test]
+    fn counts_values_beyond_i64() {
+        let intervals = set(&[(i64::MIN, i64::MAX)]);
+
+        assert_eq!(intervals.len(), u64::MAX as u128);
+    }
+}
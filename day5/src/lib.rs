@@ -0,0 +1,49 @@
+mod almanac;
+mod conversion_map;
+mod interval_set;
+mod seed_list;
+mod seed_ranges;
+mod validation;
+
+use almanac::Almanac;
+use common::{Answer, ParseError, Solution};
+use seed_list::find_lowest_seed_location;
+use seed_ranges::find_lowest_seed_range_location;
+pub use validation::{validate_almanac, Finding, ValidationReport};
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Almanac;
+
+    fn parse(text: &str) -> Result<Almanac, ParseError> {
+        Almanac::from_text(text)
+    }
+
+    fn part1(input: &Almanac) -> Answer {
+        find_lowest_seed_location(input).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(input: &Almanac) -> Answer {
+        find_lowest_seed_range_location(input).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day5>(include_str!("../readme.md"));
+    }
+
+    #[test]
+    fn leaves_answers_unsolved_without_seeds() {
+        let almanac = Day5::parse("seeds:\n\nseed-to-location map:\n50 98 2").unwrap();
+
+        assert_eq!(Day5::part1(&almanac), Answer::Unsolved);
+        assert_eq!(Day5::part2(&almanac), Answer::Unsolved);
+    }
+}
@@ -1,15 +1,27 @@
-mod conversion_map;
-mod seed_list;
-mod seed_ranges;
+use common::print_answers;
+use day5::{validate_almanac, Day5};
+use std::{env, fs::read_to_string, process::ExitCode};
 
-use seed_list::parse_almanac_seed_list;
-use seed_ranges::parse_almanac_seed_ranges;
-use std::fs::read_to_string;
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
 
-fn main() {
-    let file_path = "input.txt";
-    let text = read_to_string(file_path).unwrap();
+    match args.iter().map(String::as_str).collect::<Vec<&str>>()[..] {
+        // --validate [path] reports every problem in an almanac, failing if any is an error
+        ["--validate"] => validate("input.txt"),
+        ["--validate", path] => validate(path),
+        _ => {
+            print_answers::<Day5>(&read_to_string("input.txt").unwrap());
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+fn validate(path: &str) -> ExitCode {
+    let report = validate_almanac(&read_to_string(path).unwrap());
+    println!("{report}");
 
-    parse_almanac_seed_list(&text);
-    parse_almanac_seed_ranges(&text);
+    match report.is_valid() {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    }
 }
@@ -1,59 +1,35 @@
-use crate::conversion_map::Range;
-use std::cmp::min;
+use crate::almanac::Almanac;
+use crate::interval_set::{Interval, IntervalSet};
 
-use crate::conversion_map::ConversionMap;
+// none without seed ranges or a route from seed to location
+pub fn find_lowest_seed_range_location(almanac: &Almanac) -> Option<i64> {
+    let seed_ranges = parse_seed_ranges(&almanac.seeds);
+    let chain = almanac.chain("seed", "location")?;
 
-pub fn parse_almanac_seed_ranges(input: &String) {
-    let entries: Vec<&str> = input.split("\n\n").collect();
-
-    let seed_list = parse_seed_ranges(entries.get(0).copied());
-    let seed_to_soil = ConversionMap::from_formatted_text(entries.get(1).copied());
-    let soil_to_fertilizer = ConversionMap::from_formatted_text(entries.get(2).copied());
-    let fertilizer_to_water = ConversionMap::from_formatted_text(entries.get(3).copied());
-    let water_to_light = ConversionMap::from_formatted_text(entries.get(4).copied());
-    let light_to_temperature = ConversionMap::from_formatted_text(entries.get(5).copied());
-    let temperature_to_humidity = ConversionMap::from_formatted_text(entries.get(6).copied());
-    let humidity_to_location = ConversionMap::from_formatted_text(entries.get(7).copied());
-
-    let locations: Vec<Range> = seed_list
-        .iter()
-        .flat_map(|value| {
-            let soil = seed_to_soil.convert_range(*value);
-            let fertilizer = soil_to_fertilizer.convert_ranges(soil);
-            let water = fertilizer_to_water.convert_ranges(fertilizer);
-            let light = water_to_light.convert_ranges(water);
-            let temperature = light_to_temperature.convert_ranges(light);
-            let humidity = temperature_to_humidity.convert_ranges(temperature);
-            humidity_to_location.convert_ranges(humidity)
-        })
-        .collect();
-
-    let minimum_location_number = locations
-        .iter()
-        .map(|(start, end)| min(start, end))
-        .min()
-        .unwrap_or(&0);
-
-    assert_eq!(*minimum_location_number, 20191102);
+    chain.convert_ranges(&seed_ranges).min()
 }
 
-fn parse_seed_ranges(text: Option<&str>) -> Vec<Range> {
-    let mut ranges: Vec<Range> = vec![];
-    let mut start = None;
-
-    for number_text in text.unwrap_or(&"").split(" ").into_iter().skip(1) {
-        let number = number_text.parse::<i64>().unwrap_or(0);
+// pairs of start and length
+fn parse_seed_ranges(seeds: &[i64]) -> IntervalSet {
+    IntervalSet::new(
+        seeds
+            .chunks_exact(2)
+            .map(|pair| Interval::with_length(pair[0], pair[1])),
+    )
+}
 
-        if start.is_none() {
-            start = Some(number);
-            continue;
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        let start_value = start.unwrap();
-        ranges.push((start_value, start_value + number));
+    #[test]
+    fn parses_seed_ranges_without_extra_seed() {
+        let seeds = parse_seed_ranges(&[79, 14, 55, 13, 93, 1]);
 
-        start = None;
+        assert_eq!(seeds.len(), 28);
+        assert!(seeds.contains(92));
+        assert!(!seeds.contains(68));
+        assert!(seeds.contains(93));
+        assert!(!seeds.contains(94));
     }
-
-    ranges
 }
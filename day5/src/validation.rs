@@ -0,0 +1,398 @@
+use crate::{
+    almanac::{split_sections, Almanac},
+    conversion_map::{ConversionRange, Link},
+    interval_set::{Interval, IntervalSet},
+};
+use common::{parse_number, ParseError};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Finding {
+    Malformed(ParseError),
+    // sources of the range on `line` that the earlier listed range on `other_line` also converts
+    Overlap {
+        line: usize,
+        other_line: usize,
+        sources: Interval,
+    },
+    // sources between two ranges that no range converts, so they keep their number
+    Gap {
+        line: usize,
+        previous_line: usize,
+        sources: Interval,
+    },
+    // destinations reached from two ranges, or from a range and unconverted sources
+    Collision {
+        line: usize,
+        other_line: Option<usize>,
+        destinations: Interval,
+    },
+}
+
+impl Finding {
+    pub fn line(&self) -> usize {
+        match self {
+            Finding::Malformed(error) => error.line,
+            Finding::Overlap { line, .. }
+            | Finding::Gap { line, .. }
+            | Finding::Collision { line, .. } => *line,
+        }
+    }
+
+    // gaps are allowed by the puzzle, but worth a look
+    pub fn is_error(&self) -> bool {
+        !matches!(self, Finding::Gap { .. })
+    }
+}
+
+impl Display for Finding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Finding::Malformed(error) => write!(f, "{error}"),
+            Finding::Overlap {
+                line,
+                other_line,
+                sources,
+            } => write!(
+                f,
+                "line {line}: sources {sources} overlap line {other_line}, which takes precedence"
+            ),
+            Finding::Gap {
+                line,
+                previous_line,
+                sources,
+            } => write!(
+                f,
+                "line {line}: sources {sources} after line {previous_line} have no range and keep their number"
+            ),
+            Finding::Collision {
+                line,
+                other_line: Some(other_line),
+                destinations,
+            } => write!(
+                f,
+                "line {line}: destinations {destinations} overlap line {other_line}"
+            ),
+            Finding::Collision {
+                line,
+                other_line: None,
+                destinations,
+            } => write!(
+                f,
+                "line {line}: destinations {destinations} are also reached by sources without a range"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationReport {
+    // ordered by line
+    pub findings: Vec<Finding>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        !self.findings.iter().any(Finding::is_error)
+    }
+}
+
+impl Display for ValidationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for finding in &self.findings {
+            writeln!(f, "{finding}")?;
+        }
+
+        let errors = self
+            .findings
+            .iter()
+            .filter(|finding| finding.is_error())
+            .count();
+
+        write!(
+            f,
+            "{errors} errors, {} warnings",
+            self.findings.len() - errors
+        )
+    }
+}
+
+// unlike parsing, carries on past malformed lines to report every problem at once
+pub fn validate_almanac(text: &str) -> ValidationReport {
+    let mut findings = vec![];
+
+    let sections: Vec<Vec<(usize, &str)>> = split_sections(text)
+        .into_iter()
+        .map(|section| numbered_lines(text, section))
+        .collect();
+    let mut sections = sections.iter();
+
+    if let Some(seeds) = sections.next() {
+        findings.extend(validate_seeds(text, seeds));
+    }
+
+    for section in sections {
+        findings.extend(validate_section(text, section));
+    }
+
+    // links between categories are only checked once every line reads
+    if !findings
+        .iter()
+        .any(|finding| matches!(finding, Finding::Malformed(_)))
+    {
+        if let Err(error) = Almanac::from_text(text) {
+            findings.push(Finding::Malformed(error));
+        }
+    }
+
+    findings.sort_by_key(Finding::line);
+
+    ValidationReport { findings }
+}
+
+// the lines of a section, a slice of `text`, with their line number within the text
+fn numbered_lines<'a>(text: &str, section: &'a str) -> Vec<(usize, &'a str)> {
+    let offset = section.as_ptr() as usize - text.as_ptr() as usize;
+    let first_line = text[..offset].matches('\n').count() + 1;
+
+    section
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (first_line + index, line))
+        .collect()
+}
+
+fn validate_seeds(text: &str, section: &[(usize, &str)]) -> Vec<Finding> {
+    let mut findings = vec![];
+    let (_, line) = section[0];
+
+    match line.strip_prefix("seeds:") {
+        Some(seeds) => findings.extend(
+            seeds
+                .split_whitespace()
+                .filter_map(|seed| parse_number::<i64>(text, seed).err())
+                .map(Finding::Malformed),
+        ),
+        None => findings.push(Finding::Malformed(ParseError::at(
+            text,
+            line,
+            "expected 'seeds: <numbers>'",
+        ))),
+    }
+
+    findings.extend(section[1..].iter().map(|(_, line)| {
+        Finding::Malformed(ParseError::at(
+            text,
+            line,
+            "expected a blank line after the seeds",
+        ))
+    }));
+
+    findings
+}
+
+fn validate_section(text: &str, section: &[(usize, &str)]) -> Vec<Finding> {
+    let mut findings = vec![];
+    let (_, header) = section[0];
+
+    if let Err(error) = Link::from_header(header) {
+        findings.push(Finding::Malformed(error.within(text, header)));
+    }
+
+    let mut ranges: Vec<(usize, ConversionRange)> = vec![];
+
+    for (number, line) in &section[1..] {
+        match ConversionRange::new(line) {
+            Ok(range) if range.source.is_empty() => {
+                let length = line.split_whitespace().last().unwrap_or(line);
+                findings.push(Finding::Malformed(ParseError::at(
+                    text,
+                    length,
+                    "range length must be positive",
+                )));
+            }
+            Ok(range) => ranges.push((*number, range)),
+            Err(error) => findings.push(Finding::Malformed(error.within(text, line))),
+        }
+    }
+
+    findings.extend(validate_ranges(&ranges));
+
+    findings
+}
+
+fn validate_ranges(ranges: &[(usize, ConversionRange)]) -> Vec<Finding> {
+    let mut findings = vec![];
+
+    let sources = ranges.iter().map(|(line, range)| (*line, range.source));
+
+    for (line, other_line, interval) in compare_neighbours(sources.clone().collect()) {
+        findings.push(match interval.is_empty() {
+            // the earlier listed range takes precedence
+            false => Finding::Overlap {
+                line: line.max(other_line),
+                other_line: line.min(other_line),
+                sources: interval,
+            },
+            true => Finding::Gap {
+                line,
+                previous_line: other_line,
+                sources: Interval::new(interval.end, interval.start),
+            },
+        });
+    }
+
+    let destinations: Vec<(usize, Interval)> = ranges
+        .iter()
+        .map(|(line, range)| (*line, range.source.shifted(range.add)))
+        .collect();
+
+    for (line, other_line, interval) in compare_neighbours(destinations.clone()) {
+        if !interval.is_empty() {
+            findings.push(Finding::Collision {
+                line: line.max(other_line),
+                other_line: Some(line.min(other_line)),
+                destinations: interval,
+            });
+        }
+    }
+
+    // unconverted sources keep their number, which a range may also convert to
+    let sources = IntervalSet::new(sources.map(|(_, source)| source));
+
+    for (line, destination) in destinations {
+        let unconverted = IntervalSet::new([destination]).difference(&sources);
+
+        findings.extend(
+            unconverted
+                .intervals()
+                .iter()
+                .map(|interval| Finding::Collision {
+                    line,
+                    other_line: None,
+                    destinations: *interval,
+                }),
+        );
+    }
+
+    findings
+}
+
+// walks the intervals in order of their start, comparing each with the furthest reaching one
+// before it; yields their overlap, or the reversed, empty interval of the gap between them
+fn compare_neighbours(mut intervals: Vec<(usize, Interval)>) -> Vec<(usize, usize, Interval)> {
+    intervals.sort_by_key(|(line, interval)| (interval.start, *line));
+
+    let mut comparisons = vec![];
+    let mut furthest: Option<(usize, Interval)> = None;
+
+    for (line, interval) in intervals {
+        let Some((furthest_line, furthest_interval)) = furthest else {
+            furthest = Some((line, interval));
+            continue;
+        };
+
+        if interval.start != furthest_interval.end {
+            comparisons.push((
+                line,
+                furthest_line,
+                interval.intersection(&furthest_interval),
+            ));
+        }
+
+        if interval.end > furthest_interval.end {
+            furthest = Some((line, interval));
+        }
+    }
+
+    comparisons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::parse_examples;
+
+    #[test]
+    fn accepts_readme_example() {
+        let readme = include_str!("../readme.md");
+        let example = &parse_examples(readme).unwrap()[0];
+
+        let report = validate_almanac(&example.input);
+
+        assert_eq!(report.findings, vec![]);
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn flags_overlaps_gaps_and_collisions() {
+        let text = "seeds: 1\n\nseed-to-location map:\n20 10 5\n30 12 5\n10 30 5\n22 20 3";
+
+        let report = validate_almanac(text);
+
+        assert!(!report.is_valid());
+        assert_eq!(
+            report.to_string(),
+            "line 4: destinations [23, 25) are also reached by sources without a range\n\
+             line 5: sources [12, 15) overlap line 4, which takes precedence\n\
+             line 6: sources [23, 30) after line 7 have no range and keep their number\n\
+             line 7: sources [17, 20) after line 5 have no range and keep their number\n\
+             line 7: destinations [22, 25) overlap line 4\n\
+             line 7: destinations [23, 25) are also reached by sources without a range\n\
+             4 errors, 2 warnings"
+        );
+    }
+
+    #[test]
+    fn flags_malformed_lines() {
+        let text = "seeds: 1 x\n\nseed-to-location map\n1 2\n1 2 0\n\nlocation-to-seed map:\n2 2 3";
+
+        let report = validate_almanac(text);
+
+        assert_eq!(
+            report.findings,
+            vec![
+                Finding::Malformed(ParseError::new(1, 10, "invalid number 'x'")),
+                Finding::Malformed(ParseError::new(
+                    3,
+                    1,
+                    "expected '<source>-to-<destination> map:'"
+                )),
+                Finding::Malformed(ParseError::new(
+                    4,
+                    1,
+                    "expected '<destination start> <source start> <length>'"
+                )),
+                Finding::Malformed(ParseError::new(5, 5, "range length must be positive")),
+            ]
+        );
+
+        let report = validate_almanac("seeds: 1\n\nseed-to-soil map:\n3 1 2\n1 3 2");
+        assert_eq!(
+            report.findings,
+            vec![Finding::Malformed(ParseError::new(
+                1,
+                1,
+                "no maps lead from 'seed' to 'location'"
+            ))]
+        );
+    }
+
+    #[test]
+    fn splits_sections_like_parsing() {
+        let text = "seeds: 1\n  \nseed-to-location map:\n1 1 2\n\t\n\nlocation-to-soil map:\n5 5 1";
+
+        assert!(Almanac::from_text(text).is_ok());
+        assert_eq!(validate_almanac(text).findings, vec![]);
+
+        let report = validate_almanac("seeds: 1\n \nseed-to-location map:\n3 1 0");
+        assert_eq!(
+            report.findings,
+            vec![Finding::Malformed(ParseError::new(
+                4,
+                5,
+                "range length must be positive"
+            ))]
+        );
+    }
+}
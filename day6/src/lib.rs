@@ -0,0 +1,52 @@
+mod multiple_games;
+mod race_sheet;
+mod single_game;
+mod toy_boat;
+
+use common::{Answer, ParseError, Solution};
+use multiple_games::find_record_beat_product;
+pub use race_sheet::{Race, RaceSheet};
+use single_game::find_single_race_record_beats;
+pub use toy_boat::ToyBoat;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = RaceSheet;
+
+    fn parse(text: &str) -> Result<RaceSheet, ParseError> {
+        RaceSheet::from_text(text)
+    }
+
+    fn part1(input: &RaceSheet) -> Answer {
+        find_record_beat_product(&input.races).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(input: &RaceSheet) -> Answer {
+        input.single_race.as_ref().map_or(Answer::Unsolved, |race| {
+            find_single_race_record_beats(race).into()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day6>(include_str!("../readme.md"));
+    }
+
+    #[test]
+    fn answers_races_beyond_i128() {
+        let sheet = Day6::parse(&format!("Time: {}\nDistance: 0", u128::MAX)).unwrap();
+        assert_eq!(Day6::part2(&sheet), Answer::Large(u128::MAX - 1));
+
+        // joined, the numbers no longer fit in 128 bits
+        let sheet = Day6::parse(&format!("Time: 4 {}\nDistance: 2 0", 1_u128 << 126)).unwrap();
+        assert_eq!(Day6::part1(&sheet), Answer::Large(3 * ((1 << 126) - 1)));
+        assert_eq!(Day6::part2(&sheet), Answer::Unsolved);
+    }
+}
@@ -1,11 +1,73 @@
-mod multiple_games;
-mod single_game;
-mod toy_boat;
+use common::{print_answers, Solution};
+use day6::{Day6, Race, ToyBoat};
+use std::{env, fs::read_to_string, process::ExitCode};
 
-use multiple_games::parse_multiple_games;
-use single_game::parse_single_game;
+const USAGE: &str = "usage: day6 [--intervals | --min-time <ways>]";
 
-fn main() {
-    parse_multiple_games();
-    parse_single_game();
+fn main() -> ExitCode {
+    let text = read_to_string("input.txt").unwrap();
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    if args.is_empty() {
+        print_answers::<Day6>(&text);
+        return ExitCode::SUCCESS;
+    }
+
+    let sheet = match Day6::parse(&text) {
+        Ok(sheet) => sheet,
+        Err(error) => {
+            eprintln!("error: input.txt: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let boat = ToyBoat::new(None);
+    // the single race is left out when its joined numbers do not fit in 128 bits
+    let races = sheet.races.iter().chain(sheet.single_race.as_ref());
+
+    match args[..] {
+        // --intervals lists the charge times beating each record, the single race last
+        ["--intervals"] => {
+            for race in races {
+                let times = boat.winning_charge_times(race.time, race.record);
+
+                match times {
+                    Some(times) => println!(
+                        "{}: hold {} to {} ms, {} ways",
+                        describe(race),
+                        times.start(),
+                        times.end(),
+                        times.end() - times.start() + 1
+                    ),
+                    None => println!("{}: the record can't be beaten", describe(race)),
+                }
+            }
+        }
+        // --min-time <ways> finds the shortest race with that many ways to beat each record
+        ["--min-time", ways] => {
+            let Ok(ways) = ways.parse::<u128>() else {
+                eprintln!("error: invalid number of ways '{ways}'\n{USAGE}");
+                return ExitCode::from(2);
+            };
+
+            for race in races {
+                match boat.find_minimum_race_time(race.record, ways) {
+                    Some(time) => {
+                        println!("{}: at least {ways} ways from {time} ms", describe(race))
+                    }
+                    None => println!("{}: no race is long enough for {ways} ways", describe(race)),
+                }
+            }
+        }
+        _ => {
+            eprintln!("error: unexpected arguments '{}'\n{USAGE}", args.join(" "));
+            return ExitCode::from(2);
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn describe(race: &Race) -> String {
+    format!("{} ms race, record {} mm", race.time, race.record)
 }
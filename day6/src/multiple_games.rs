@@ -1,49 +1,17 @@
-use std::fs::read_to_string;
+use crate::{race_sheet::Race, toy_boat::ToyBoat};
 
-use crate::toy_boat::ToyBoat;
-
-pub fn parse_multiple_games() {
-    let (times, records) = parse_race_input("input.txt");
-    let mut product = 1;
-
-    for index in 0..times.len() {
-        let time = times.get(index).unwrap();
-        let record = records.get(index).unwrap();
-
-        let number_of_beats = find_possible_record_beats(*time, *record);
-
-        product *= number_of_beats;
-    }
-
-    assert_eq!(product, 4568778)
-}
-
-fn parse_race_input(file_path: &str) -> (Vec<u64>, Vec<u64>) {
-    let text = read_to_string(file_path).unwrap();
-
-    let lines: Vec<&str> = text.lines().collect();
-
-    (
-        parse_numbers_from_line(lines.get(0).unwrap()),
-        parse_numbers_from_line(lines.get(1).unwrap()),
-    )
-}
-
-fn parse_numbers_from_line(line: &str) -> Vec<u64> {
-    line.split_whitespace()
-        .filter(|value| value.chars().all(|character| character.is_ascii_digit()))
-        .map(|value| value.parse().unwrap())
-        .collect()
+// none when the product does not fit in u128
+pub fn find_record_beat_product(races: &[Race]) -> Option<u128> {
+    races
+        .iter()
+        .map(|race| find_possible_record_beats(race.time, race.record))
+        .try_fold(1_u128, |product, ways| product.checked_mul(ways))
 }
 
-fn find_possible_record_beats(time: u64, record: u64) -> u64 {
+fn find_possible_record_beats(time: u128, record: u128) -> u128 {
     let boat = ToyBoat::new(None);
 
-    boat.calculate_distances_traveled(time)
-        .iter()
-        .filter(|value| value > &&record)
-        .collect::<Vec<&u64>>()
-        .len() as u64
+    boat.count_winning_charge_times(time, record)
 }
 
 #[cfg(test)]
@@ -56,4 +24,27 @@ mod test_toy_boat {
 
         assert_eq!(find_possible_record_beats(15, 40), 8);
     }
+
+    #[test]
+    fn finds_no_product_beyond_u128() {
+        let race = Race {
+            time: 1 << 100,
+            record: 0,
+        };
+
+        assert_eq!(
+            find_record_beat_product(&[Race { time: 7, record: 9 }]),
+            Some(4)
+        );
+        assert_eq!(
+            find_record_beat_product(&[
+                race,
+                Race {
+                    time: 1 << 100,
+                    record: 0
+                }
+            ]),
+            None
+        );
+    }
 }
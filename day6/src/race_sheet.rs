@@ -0,0 +1,119 @@
+use common::{parse_number, ParseError};
+
+#[derive(Debug, PartialEq)]
+pub struct Race {
+    pub time: u128,
+    pub record: u128,
+}
+
+#[derive(Debug)]
+pub struct RaceSheet {
+    pub races: Vec<Race>,
+    // the same sheet read with the spaces between digits ignored, none when a joined number
+    // does not fit in 128 bits
+    pub single_race: Option<Race>,
+}
+
+impl RaceSheet {
+    pub fn from_text(text: &str) -> Result<RaceSheet, ParseError> {
+        let mut lines = text.lines();
+
+        let times = lines.next().unwrap_or("");
+        let records = lines.next().unwrap_or("");
+
+        let time_numbers = parse_labelled_line(text, times, "Time:")?;
+        let record_numbers = parse_labelled_line(text, records, "Distance:")?;
+
+        if time_numbers.len() != record_numbers.len() {
+            return Err(ParseError::at(
+                text,
+                records,
+                format!(
+                    "expected {} distances, found {}",
+                    time_numbers.len(),
+                    record_numbers.len()
+                ),
+            ));
+        }
+
+        let races = time_numbers
+            .iter()
+            .zip(record_numbers.iter())
+            .map(|(time, record)| {
+                Ok(Race {
+                    time: parse_number(text, time)?,
+                    record: parse_number(text, record)?,
+                })
+            })
+            .collect::<Result<Vec<Race>, ParseError>>()?;
+
+        let single_race = parse_joined_number(&time_numbers)
+            .zip(parse_joined_number(&record_numbers))
+            .map(|(time, record)| Race { time, record });
+
+        Ok(RaceSheet { races, single_race })
+    }
+}
+
+fn parse_labelled_line<'a>(
+    text: &str,
+    line: &'a str,
+    label: &str,
+) -> Result<Vec<&'a str>, ParseError> {
+    let numbers = line
+        .strip_prefix(label)
+        .ok_or_else(|| ParseError::at(text, line, format!("expected '{label} <numbers>'")))?;
+
+    Ok(numbers.split_whitespace().collect())
+}
+
+// the numbers were already checked to be digits, so only their length can fail this
+fn parse_joined_number(numbers: &[&str]) -> Option<u128> {
+    numbers.concat().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_race_sheet() {
+        let sheet = RaceSheet::from_text("Time:      7  15\nDistance:  9  40").unwrap();
+
+        assert_eq!(
+            sheet.races,
+            vec![
+                Race { time: 7, record: 9 },
+                Race {
+                    time: 15,
+                    record: 40
+                }
+            ]
+        );
+        assert_eq!(
+            sheet.single_race,
+            Some(Race {
+                time: 715,
+                record: 940
+            })
+        );
+    }
+
+    #[test]
+    fn leaves_out_single_race_beyond_128_bits() {
+        let text = format!("Time: {} 1\nDistance: 0 0", u128::MAX);
+        let sheet = RaceSheet::from_text(&text).unwrap();
+
+        assert_eq!(sheet.races.len(), 2);
+        assert_eq!(sheet.single_race, None);
+    }
+
+    #[test]
+    fn rejects_malformed_race_sheets() {
+        let error = RaceSheet::from_text("Time: 7 15\nDistance: 9").unwrap_err();
+        assert_eq!(error.line, 2);
+
+        let error = RaceSheet::from_text("Time: 7 1x\nDistance: 9 40").unwrap_err();
+        assert_eq!(error, ParseError::new(1, 9, "invalid number '1x'"));
+    }
+}
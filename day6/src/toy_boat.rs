@@ -1,9 +1,12 @@
+use math::integer_sqrt;
+use std::ops::RangeInclusive;
+
 pub struct ToyBoat {
-    acceleration: u64,
+    acceleration: u128,
 }
 
 impl ToyBoat {
-    pub fn new(acceleration: Option<u64>) -> ToyBoat {
+    pub fn new(acceleration: Option<u128>) -> ToyBoat {
         ToyBoat {
             acceleration: acceleration.unwrap_or(1),
         }
@@ -11,18 +14,111 @@ impl ToyBoat {
 }
 
 impl ToyBoat {
-    fn calculate_distance_traveled(&self, charge_time: u64, race_time: u64) -> u64 {
-        let velocity = charge_time * self.acceleration;
-        velocity * (race_time - charge_time)
+    // None when the distance does not fit in u128, which beats any record
+    fn calculate_distance_traveled(&self, charge_time: u128, race_time: u128) -> Option<u128> {
+        let velocity = charge_time.checked_mul(self.acceleration);
+        let moving_time = race_time - charge_time;
+
+        match moving_time {
+            0 => Some(0),
+            _ => velocity?.checked_mul(moving_time),
+        }
+    }
+
+    fn beats_record(&self, charge_time: u128, race_time: u128, record: u128) -> bool {
+        self.calculate_distance_traveled(charge_time, race_time)
+            .is_none_or(|distance| distance > record)
+    }
+
+    // charge times beating the record; the distance is symmetric around half the race time
+    pub fn winning_charge_times(
+        &self,
+        race_time: u128,
+        record: u128,
+    ) -> Option<RangeInclusive<u128>> {
+        let half = race_time / 2;
+
+        if !self.beats_record(half, race_time, record) {
+            return None;
+        }
+
+        let first = self.first_winning_charge_time(race_time, record);
+
+        Some(first..=race_time - first)
     }
 
-    pub fn calculate_distances_traveled(&self, race_time: u64) -> Vec<u64> {
-        (0..race_time + 1)
-            .collect::<Vec<u64>>()
-            .iter()
-            .enumerate()
-            .map(|(charge_time, _)| self.calculate_distance_traveled(charge_time as u64, race_time))
-            .collect()
+    pub fn count_winning_charge_times(&self, race_time: u128, record: u128) -> u128 {
+        self.winning_charge_times(race_time, record)
+            .map_or(0, |times| times.end() - times.start() + 1)
+    }
+
+    // shortest race with at least `ways` charge times beating the record, none when it is
+    // longer than u128 allows
+    pub fn find_minimum_race_time(&self, record: u128, ways: u128) -> Option<u128> {
+        let enough = |race_time: u128| self.count_winning_charge_times(race_time, record) >= ways;
+
+        // every extra millisecond wins at least one more way, once any way wins
+        let mut high = 1;
+        while !enough(high) {
+            high = match high.checked_mul(2) {
+                Some(doubled) => doubled,
+                None if enough(u128::MAX) => u128::MAX,
+                None => return None,
+            };
+        }
+
+        let mut low = 0;
+        while low < high {
+            let middle = low + (high - low) / 2;
+
+            match enough(middle) {
+                true => high = middle,
+                false => low = middle + 1,
+            }
+        }
+
+        Some(low)
+    }
+
+    // expects the record to be beaten at half the race time
+    fn first_winning_charge_time(&self, race_time: u128, record: u128) -> u128 {
+        // a charge time t wins when t * (T - t) >= r, where r is the lowest distance per
+        // unit of acceleration that beats the record; the lower root of t² - Tt + r = 0 is
+        // (T - √(T² - 4r)) / 2
+        let estimate = match race_time.checked_mul(race_time) {
+            Some(square) => {
+                let needed = record / self.acceleration + 1;
+                (race_time - integer_sqrt(square - 4 * needed)) / 2
+            }
+            None => return self.search_first_winning_charge_time(race_time, record),
+        };
+
+        // the root is rounded down, so the estimate can be one short
+        let mut first = estimate;
+        while !self.beats_record(first, race_time, record) {
+            first += 1;
+        }
+        while first > 0 && self.beats_record(first - 1, race_time, record) {
+            first -= 1;
+        }
+
+        first
+    }
+
+    // the square of races longer than 2^64 milliseconds does not fit in u128
+    fn search_first_winning_charge_time(&self, race_time: u128, record: u128) -> u128 {
+        let (mut low, mut high) = (0, race_time / 2);
+
+        while low < high {
+            let middle = low + (high - low) / 2;
+
+            match self.beats_record(middle, race_time, record) {
+                true => high = middle,
+                false => low = middle + 1,
+            }
+        }
+
+        low
     }
 }
 
@@ -34,16 +130,74 @@ mod test_toy_boat {
     fn calculates_distance_traveled() {
         let toy_boat = ToyBoat::new(None);
 
-        assert_eq!(toy_boat.calculate_distance_traveled(5, 7), 10);
+        assert_eq!(toy_boat.calculate_distance_traveled(5, 7), Some(10));
+        assert_eq!(toy_boat.calculate_distance_traveled(1 << 64, 1 << 65), None);
+    }
+
+    #[test]
+    fn finds_winning_charge_times() {
+        let toy_boat = ToyBoat::new(None);
+
+        assert_eq!(toy_boat.winning_charge_times(7, 9), Some(2..=5));
+        assert_eq!(toy_boat.winning_charge_times(30, 200), Some(11..=19));
+        assert_eq!(
+            toy_boat.winning_charge_times(71530, 940200),
+            Some(14..=71516)
+        );
+        assert_eq!(toy_boat.winning_charge_times(7, 12), None);
+
+        let fast_boat = ToyBoat::new(Some(3));
+        assert_eq!(fast_boat.winning_charge_times(7, 30), Some(3..=4));
+        assert_eq!(fast_boat.winning_charge_times(7, 29), Some(2..=5));
+        assert_eq!(fast_boat.winning_charge_times(7, 36), None);
+    }
+
+    #[test]
+    fn counts_winning_charge_times_like_trying_each() {
+        let toy_boat = ToyBoat::new(Some(2));
+
+        for race_time in 0..40 {
+            for record in 0..300 {
+                let tried = (0..=race_time)
+                    .filter(|charge_time| toy_boat.beats_record(*charge_time, race_time, record))
+                    .count() as u128;
+
+                assert_eq!(
+                    toy_boat.count_winning_charge_times(race_time, record),
+                    tried
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn solves_races_beyond_u64() {
+        let toy_boat = ToyBoat::new(None);
+
+        let race_time = 1 << 100;
+        let times = toy_boat.winning_charge_times(race_time, 1 << 120).unwrap();
+        assert_eq!(*times.start(), (1 << 20) + 1);
+        assert_eq!(*times.end(), race_time - (1 << 20) - 1);
+
+        let race_time = u128::MAX;
+        assert_eq!(
+            toy_boat.count_winning_charge_times(race_time, u128::MAX),
+            race_time - 3
+        );
     }
 
     #[test]
-    fn calculates_distances_traveled() {
+    fn finds_minimum_race_time() {
         let toy_boat = ToyBoat::new(None);
 
+        assert_eq!(toy_boat.find_minimum_race_time(9, 4), Some(7));
+        assert_eq!(toy_boat.find_minimum_race_time(9, 5), Some(8));
+        assert_eq!(toy_boat.find_minimum_race_time(9, 1), Some(7));
+        assert_eq!(toy_boat.find_minimum_race_time(940200, 71503), Some(71530));
         assert_eq!(
-            toy_boat.calculate_distances_traveled(7),
-            vec![0, 6, 10, 12, 12, 10, 6, 0]
+            toy_boat.find_minimum_race_time(0, u128::MAX - 1),
+            Some(u128::MAX)
         );
+        assert_eq!(toy_boat.find_minimum_race_time(0, u128::MAX), None);
     }
 }
@@ -1,8 +1,9 @@
 use std::cmp::Ordering;
 
 use crate::hand_strength::{hand_strength_from_cards, Card, HandStrength};
+use common::{parse_number, ParseError};
 
-#[derive(Ord, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub struct CardHand {
     strength: HandStrength,
     cards: Vec<Card>,
@@ -10,14 +11,14 @@ pub struct CardHand {
 }
 
 impl CardHand {
-    pub fn new(input: &str) -> CardHand {
-        let (cards, bid) = parse_card_hand_from_text(input);
+    pub fn new(input: &str) -> Result<CardHand, ParseError> {
+        let (cards, bid) = parse_card_hand_from_text(input)?;
 
-        CardHand {
+        Ok(CardHand {
             strength: hand_strength_from_cards(&cards),
             cards,
             bid,
-        }
+        })
     }
 }
 
@@ -41,18 +42,22 @@ impl PartialEq for CardHand {
     }
 }
 
-impl Eq for CardHand {
-    fn assert_receiver_is_total_eq(&self) {}
-}
+impl Eq for CardHand {}
 
 impl PartialOrd for CardHand {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CardHand {
+    fn cmp(&self, other: &Self) -> Ordering {
         if self.strength > other.strength {
-            return Some(Ordering::Greater);
+            return Ordering::Greater;
         }
 
         if self.strength < other.strength {
-            return Some(Ordering::Less);
+            return Ordering::Less;
         }
 
         // let range = self.cards.len()).map(|value| value as u16).enumerate();
@@ -60,37 +65,58 @@ impl PartialOrd for CardHand {
         for (index, value) in self.cards.iter().enumerate() {
             let other_value = other.cards.get(index).unwrap_or(&0);
 
-            if value > other_value || value < other_value {
-                return Some(value.cmp(&other_value));
+            if value != other_value {
+                return value.cmp(other_value);
             }
         }
 
-        Some(Ordering::Equal)
+        Ordering::Equal
     }
 }
 
-fn parse_card_hand_from_text(text: &str) -> (Vec<Card>, u32) {
+fn parse_card_hand_from_text(text: &str) -> Result<(Vec<Card>, u32), ParseError> {
     let components: Vec<&str> = text.split_whitespace().collect();
-    let card_text = components.get(0).unwrap();
-    let bid_text = components.get(1).unwrap();
 
-    let cards = card_text.chars().map(parse_card_from_text).collect();
-    let bid = bid_text.parse::<u32>().unwrap();
+    let [card_text, bid_text] = components[..] else {
+        return Err(ParseError::at(text, text, "expected '<cards> <bid>'"));
+    };
+
+    let cards = card_text
+        .char_indices()
+        .map(|(index, character)| {
+            parse_card_from_text(character).ok_or_else(|| {
+                let symbol = &card_text[index..(index + character.len_utf8())];
 
-    (cards, bid)
+                ParseError::at(text, symbol, format!("invalid card '{character}'"))
+            })
+        })
+        .collect::<Result<Vec<Card>, ParseError>>()?;
+
+    Ok((cards, parse_number(text, bid_text)?))
 }
 
-fn parse_card_from_text(character: char) -> Card {
-    if character.is_ascii_digit() {
-        return character.to_string().parse::<u16>().unwrap();
+fn parse_card_from_text(character: char) -> Option<Card> {
+    match character {
+        '2'..='9' => character.to_digit(10).map(|value| value as Card),
+        'A' => Some(14),
+        'K' => Some(13),
+        'Q' => Some(12),
+        'J' => Some(11),
+        'T' => Some(10),
+        _ => None,
     }
+}
 
-    match character {
-        'A' => 14,
-        'K' => 13,
-        'Q' => 12,
-        'J' => 11,
-        'T' => 10,
-        _ => panic!("invalid card"),
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_malformed_card_hands() {
+        let error = CardHand::new("32X3K 765").unwrap_err();
+        assert_eq!(error, ParseError::new(1, 3, "invalid card 'X'"));
+
+        let error = CardHand::new("32T3K").unwrap_err();
+        assert_eq!(error.column, 1);
     }
 }
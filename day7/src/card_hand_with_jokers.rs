@@ -14,7 +14,7 @@ fn replace_jokers(card_hand: &CardHand, joker_value: Card) -> CardHand {
     let cards = card_hand.get_cards();
 
     let new_cards: Vec<Card> = cards
-        .into_iter()
+        .iter()
         .map(|card| {
             let card = *card;
             if card == joker_value {
@@ -25,16 +25,16 @@ fn replace_jokers(card_hand: &CardHand, joker_value: Card) -> CardHand {
         })
         .collect();
 
-    let strength = augment_hand_strength_with_jokers(&card_hand.get_cards(), joker_value);
+    let strength = augment_hand_strength_with_jokers(card_hand.get_cards(), joker_value);
 
     card_hand.replace_cards(new_cards, strength)
 }
 
-fn augment_hand_strength_with_jokers(new_cards: &Vec<Card>, joker_value: Card) -> HandStrength {
+fn augment_hand_strength_with_jokers(new_cards: &[Card], joker_value: Card) -> HandStrength {
     let non_jokers: Vec<Card> = new_cards
         .iter()
         .filter(|value| **value != joker_value)
-        .map(|value| *value)
+        .copied()
         .collect();
 
     let num_jokers = new_cards.len() - non_jokers.len();
@@ -51,7 +51,7 @@ fn augment_hand_strength_with_jokers(new_cards: &Vec<Card>, joker_value: Card) -
         .position(|value| value == greatest_count)
         .unwrap_or(0);
 
-    card_values[index] = card_values[index] + (num_jokers as u16);
+    card_values[index] += num_jokers as u16;
 
     hand_strength_from_card_values(card_values)
 }
@@ -28,17 +28,17 @@ impl Deref for HandStrength {
     type Target = HandStrength;
 
     fn deref(&self) -> &Self::Target {
-        &self
+        self
     }
 }
 
-pub fn hand_strength_from_cards(cards: &Vec<Card>) -> HandStrength {
+pub fn hand_strength_from_cards(cards: &[Card]) -> HandStrength {
     let values = values_from_cards(cards);
 
     hand_strength_from_card_values(values)
 }
 
-pub fn values_from_cards(cards: &Vec<Card>) -> Vec<u16> {
+pub fn values_from_cards(cards: &[Card]) -> Vec<u16> {
     let mut map: HashMap<u16, u16> = HashMap::new();
 
     for card in cards.iter() {
@@ -46,7 +46,7 @@ pub fn values_from_cards(cards: &Vec<Card>) -> Vec<u16> {
         map.insert(*card, current + 1);
     }
 
-    map.values().map(|value| *value).collect()
+    map.values().copied().collect()
 }
 
 pub fn hand_strength_from_card_values(values: Vec<u16>) -> HandStrength {
@@ -81,37 +81,37 @@ mod test_hand_strength {
     #[test]
     fn evaluates_hand_strength() {
         assert_eq!(
-            hand_strength_from_cards(&vec![14, 14, 14, 14, 14]),
+            hand_strength_from_cards(&[14, 14, 14, 14, 14]),
             HandStrength::FiveOfKind
         );
 
         assert_eq!(
-            hand_strength_from_cards(&vec![14, 14, 14, 14, 13]),
+            hand_strength_from_cards(&[14, 14, 14, 14, 13]),
             HandStrength::FourOfKind
         );
 
         assert_eq!(
-            hand_strength_from_cards(&vec![14, 14, 14, 13, 13]),
+            hand_strength_from_cards(&[14, 14, 14, 13, 13]),
             HandStrength::FullHouse
         );
 
         assert_eq!(
-            hand_strength_from_cards(&vec![14, 14, 14, 13, 12]),
+            hand_strength_from_cards(&[14, 14, 14, 13, 12]),
             HandStrength::ThreeOfKind
         );
 
         assert_eq!(
-            hand_strength_from_cards(&vec![14, 14, 13, 13, 12]),
+            hand_strength_from_cards(&[14, 14, 13, 13, 12]),
             HandStrength::TwoPair
         );
 
         assert_eq!(
-            hand_strength_from_cards(&vec![14, 14, 13, 12, 11]),
+            hand_strength_from_cards(&[14, 14, 13, 12, 11]),
             HandStrength::OnePair
         );
 
         assert_eq!(
-            hand_strength_from_cards(&vec![14, 13, 12, 11, 10]),
+            hand_strength_from_cards(&[14, 13, 12, 11, 10]),
             HandStrength::None
         );
     }
@@ -0,0 +1,78 @@
+mod card_hand;
+mod card_hand_with_jokers;
+mod hand_strength;
+
+use card_hand::CardHand;
+use card_hand_with_jokers::use_jokers_in_card_hands;
+use common::{parse_lines, Answer, ParseError, Solution};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<CardHand>;
+
+    fn parse(text: &str) -> Result<Vec<CardHand>, ParseError> {
+        parse_lines(text, CardHand::new)
+    }
+
+    fn part1(input: &Vec<CardHand>) -> Answer {
+        score_card_hands(input.to_vec()).into()
+    }
+
+    fn part2(input: &Vec<CardHand>) -> Answer {
+        let card_hands_with_jokers = use_jokers_in_card_hands(input.to_vec(), 11);
+
+        score_card_hands(card_hands_with_jokers).into()
+    }
+}
+
+fn order_card_hands(card_hands: Vec<CardHand>) -> Vec<CardHand> {
+    let mut ordered = card_hands.to_vec();
+    ordered.sort();
+
+    ordered
+}
+
+fn score_card_hands(card_hands: Vec<CardHand>) -> u32 {
+    let ordered = order_card_hands(card_hands);
+
+    ordered
+        .iter()
+        .enumerate()
+        .fold(0, |previous, (index, next)| {
+            previous + (index as u32 + 1) * next.bid
+        })
+}
+
+#[cfg(test)]
+mod test_card_hand_scoring {
+    use super::*;
+
+    #[test]
+    fn correctly_scores_card_hand_list_without_jokers() {
+        let input = String::from("32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483");
+        let card_hands = parse_lines(&input, CardHand::new).unwrap();
+
+        assert_eq!(score_card_hands(card_hands), 6440);
+    }
+
+    #[test]
+    fn correctly_scores_card_hand_list_with_jokers() {
+        let input = String::from("32T3K 765\nT55J5 684\nKK677 28\nKTJJT 220\nQQQJA 483");
+        let card_hands = parse_lines(&input, CardHand::new).unwrap();
+        let with_jokers_applied = use_jokers_in_card_hands(card_hands, 11);
+
+        assert_eq!(score_card_hands(with_jokers_applied), 5905);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day7>(include_str!("../readme.md"));
+    }
+}
@@ -0,0 +1,39 @@
+mod model;
+mod parse;
+mod part1;
+mod part2;
+
+use common::{Answer, ParseError, Solution};
+use model::Network;
+use parse::parse_network;
+use part1::calculate_necessary_steps;
+use part2::calculate_necessary_ghost_steps;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Network;
+
+    fn parse(text: &str) -> Result<Network, ParseError> {
+        parse_network(text)
+    }
+
+    fn part1(input: &Network) -> Answer {
+        calculate_necessary_steps(input).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(input: &Network) -> Answer {
+        calculate_necessary_ghost_steps(input).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day8>(include_str!("../readme.md"));
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 #[derive(Debug)]
 pub enum Direction {
     Left,
@@ -21,3 +23,9 @@ impl Location {
         }
     }
 }
+
+#[derive(Debug)]
+pub struct Network {
+    pub directions: Vec<Direction>,
+    pub locations: HashMap<String, Location>,
+}
@@ -1,43 +1,138 @@
+use common::{parse_lines, ParseError};
 use std::collections::HashMap;
 
-use crate::model::{Direction, Location};
+use crate::model::{Direction, Location, Network};
 
-pub fn parse_instructions(text: &str) -> Vec<Direction> {
-    text.chars()
-        .map(|character| match character {
-            'L' => Direction::Left,
-            _ => Direction::Right,
+pub fn parse_network(text: &str) -> Result<Network, ParseError> {
+    let (instructions, locations) = text
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::at(text, text, "expected instructions and locations"))?;
+
+    if instructions.is_empty() {
+        return Err(ParseError::new(1, 1, "expected at least one instruction"));
+    }
+
+    let directions = parse_instructions(text, instructions)?;
+    let locations = parse_locations(locations).map_err(|error| error.within(text, locations))?;
+
+    // ghosts need somewhere to start and somewhere to end
+    for end in ['A', 'Z'] {
+        if !locations.keys().any(|id| id.ends_with(end)) {
+            return Err(ParseError::new(
+                1,
+                1,
+                format!("missing location ending in '{end}'"),
+            ));
+        }
+    }
+
+    Ok(Network {
+        directions,
+        locations,
+    })
+}
+
+fn parse_instructions(text: &str, instructions: &str) -> Result<Vec<Direction>, ParseError> {
+    instructions
+        .char_indices()
+        .map(|(index, character)| match character {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(ParseError::at(
+                text,
+                &instructions[index..(index + character.len_utf8())],
+                format!("invalid direction '{character}'"),
+            )),
         })
         .collect()
 }
 
-pub fn parse_locations(text: &str) -> HashMap<String, Location> {
-    let mut location_map = HashMap::new();
+fn parse_locations(text: &str) -> Result<HashMap<String, Location>, ParseError> {
+    let mut locations = HashMap::new();
+
+    for (line, location) in text.lines().zip(parse_lines(text, parse_location)?) {
+        if locations.contains_key(&location.id) {
+            let id = &line[..location.id.len()];
+            return Err(ParseError::at(
+                text,
+                id,
+                format!("duplicate location '{id}'"),
+            ));
+        }
 
+        locations.insert(location.id.clone(), location);
+    }
+
+    // every location led to must exist
     for line in text.lines() {
-        let location = parse_location(line);
-        location_map.insert(location.id.clone(), location);
+        let unknown = location_references(line).find(|id| !locations.contains_key(*id));
+
+        if let Some(id) = unknown {
+            return Err(ParseError::at(text, id, format!("unknown location '{id}'")));
+        }
     }
 
-    location_map
+    Ok(locations)
 }
 
-fn parse_location(text: &str) -> Location {
-    let components: Vec<&str> = text.split(" = ").collect();
-    let id = components.get(0).unwrap();
+fn parse_location(text: &str) -> Result<Location, ParseError> {
+    let (id, ids) = text
+        .split_once(" = ")
+        .ok_or_else(|| ParseError::at(text, text, "expected '<id> = (<left>, <right>)'"))?;
 
-    let (left_id, right_id) = parse_ids(components.get(1).unwrap());
+    let (left_id, right_id) =
+        parse_ids(ids).ok_or_else(|| ParseError::at(text, ids, "expected '(<left>, <right>)'"))?;
 
-    Location::new(String::from(*id), left_id, right_id)
+    Ok(Location::new(
+        String::from(id),
+        String::from(left_id),
+        String::from(right_id),
+    ))
 }
 
-fn parse_ids(input: &str) -> (String, String) {
-    let replaced = input.replace('(', "");
-    let replaced = replaced.replace(')', "");
-    let replaced = replaced.replace(',', "");
-    let replaced = replaced.replace(' ', "");
+fn parse_ids(input: &str) -> Option<(&str, &str)> {
+    input.strip_prefix('(')?.strip_suffix(')')?.split_once(", ")
+}
+
+// the left and right id of a location line that has already been parsed
+fn location_references(line: &str) -> impl Iterator<Item = &str> {
+    let ids = line.split_once(" = ").and_then(|(_, ids)| parse_ids(ids));
+
+    ids.into_iter().flat_map(|(left, right)| [left, right])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_malformed_networks() {
+        let error = parse_network("LR\n\nAAA = (BBB, CCC)\nBBB = BBB, CCC").unwrap_err();
+        assert_eq!(error, ParseError::new(4, 7, "expected '(<left>, <right>)'"));
 
-    let (left_id, right_id) = replaced.split_at(3);
+        let error = parse_network("LX\n\nAAA = (BBB, CCC)").unwrap_err();
+        assert_eq!(error, ParseError::new(1, 2, "invalid direction 'X'"));
 
-    (String::from(left_id), String::from(right_id))
+        let error = parse_network("\n\nAAA = (AAA, AAA)").unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(1, 1, "expected at least one instruction")
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_and_duplicate_locations() {
+        let error = parse_network("L\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, YYY)").unwrap_err();
+        assert_eq!(error, ParseError::new(4, 13, "unknown location 'YYY'"));
+
+        let error =
+            parse_network("L\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\nAAA = (AAA, AAA)").unwrap_err();
+        assert_eq!(error, ParseError::new(5, 1, "duplicate location 'AAA'"));
+
+        let error = parse_network("L\n\nAAA = (AAA, AAA)").unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(1, 1, "missing location ending in 'Z'")
+        );
+    }
 }
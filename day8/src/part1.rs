@@ -1,26 +1,39 @@
-use crate::{
-    model::{Direction, Location},
-    parse::{parse_instructions, parse_locations},
-};
+use crate::model::{Direction, Location, Network};
 use std::collections::HashMap;
 
-pub fn calculate_necessary_steps(text: &str) -> u32 {
-    let components: Vec<&str> = text.split("\n\n").collect();
+// none without AAA or ZZZ, or when AAA never leads to ZZZ
+pub fn calculate_necessary_steps(network: &Network) -> Option<u32> {
+    let Network {
+        directions,
+        locations,
+    } = network;
 
-    let directions = parse_instructions(components.get(0).unwrap());
-    let locations = parse_locations(components.get(1).unwrap());
+    if !locations.contains_key("ZZZ") {
+        return None;
+    }
 
     transverse_locations(locations, directions)
 }
 
-fn transverse_locations(locations: HashMap<String, Location>, directions: Vec<Direction>) -> u32 {
+fn transverse_locations(
+    locations: &HashMap<String, Location>,
+    directions: &[Direction],
+) -> Option<u32> {
     let mut num_steps = 0;
     let mut current = 0;
     let mut current_id = "AAA";
 
+    // after this many steps some location was reached twice at the same instruction, so the
+    // walk goes round in circles
+    let limit = locations.len() * directions.len();
+
     while current_id != "ZZZ" {
-        let current_location = locations.get(current_id).unwrap();
-        let direction = directions.get(current).unwrap();
+        if num_steps as usize >= limit {
+            return None;
+        }
+
+        let current_location = locations.get(current_id)?;
+        let direction = &directions[current];
 
         current_id = match direction {
             Direction::Left => &current_location.left_id,
@@ -34,18 +47,28 @@ fn transverse_locations(locations: HashMap<String, Location>, directions: Vec<Di
         }
     }
 
-    num_steps
+    Some(num_steps)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse::parse_network;
 
     #[test]
     fn calculates_necessary_steps() {
         let input_text = "RL\n\nAAA = (BBB, CCC)\nBBB = (DDD, EEE)\nCCC = (ZZZ, GGG)\nDDD = (DDD, DDD)\nEEE = (EEE, EEE)\nGGG = (GGG, GGG)\nZZZ = (ZZZ, ZZZ)";
 
-        let steps = calculate_necessary_steps(input_text);
-        assert_eq!(steps, 2)
+        let steps = calculate_necessary_steps(&parse_network(input_text).unwrap());
+        assert_eq!(steps, Some(2))
+    }
+
+    #[test]
+    fn finds_no_steps_without_way_to_zzz() {
+        let network = parse_network("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(calculate_necessary_steps(&network), None);
+
+        let network = parse_network("L\n\n11A = (11Z, 11Z)\n11Z = (11Z, 11Z)").unwrap();
+        assert_eq!(calculate_necessary_steps(&network), None);
     }
 }
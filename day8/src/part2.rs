@@ -1,25 +1,23 @@
-use num::integer::lcm;
+use math::lowest_common_multiple;
 use std::collections::HashMap;
 
-use crate::{
-    model::{Direction, Location},
-    parse::{parse_instructions, parse_locations},
-};
+use crate::model::{Direction, Location, Network};
 
-pub fn calculate_necessary_ghost_steps(text: &str) -> u128 {
-    let components: Vec<&str> = text.split("\n\n").collect();
+// none when a ghost never reaches a location ending in Z, or the steps overflow i128
+pub fn calculate_necessary_ghost_steps(network: &Network) -> Option<i128> {
+    let Network {
+        directions,
+        locations,
+    } = network;
 
-    let directions = parse_instructions(components.get(0).unwrap());
-    let locations = parse_locations(components.get(1).unwrap());
+    let starting_locations = find_locations_ids_ending_with(locations, 'A');
 
-    let starting_locations = find_locations_ids_ending_with(&locations, 'A');
-
-    let loop_lengths: Vec<u128> = starting_locations
+    let loop_lengths: Vec<i128> = starting_locations
         .iter()
-        .map(|id| find_loop_length_for_id(id, &locations, &directions))
-        .collect();
+        .map(|id| find_loop_length_for_id(id, locations, directions))
+        .collect::<Option<Vec<i128>>>()?;
 
-    lowest_common_multiple(loop_lengths)
+    lowest_common_multiple(&loop_lengths)
 }
 
 fn find_locations_ids_ending_with(
@@ -38,20 +36,27 @@ fn find_locations_ids_ending_with(
 fn find_loop_length_for_id(
     id: &str,
     locations: &HashMap<String, Location>,
-    directions: &Vec<Direction>,
-) -> u128 {
+    directions: &[Direction],
+) -> Option<i128> {
     let mut num_steps = 0;
     let mut direction_index = 0;
     let mut current_id = id;
 
-    loop {
-        let current_location = locations.get(current_id).unwrap();
-        let direction = directions.get(direction_index).unwrap();
+    // as in part 1, a longer walk goes round in circles
+    let limit = (locations.len() * directions.len()) as i128;
 
+    loop {
         if current_id.ends_with('Z') {
-            break num_steps;
+            break Some(num_steps);
         }
 
+        if num_steps >= limit {
+            break None;
+        }
+
+        let current_location = locations.get(current_id)?;
+        let direction = &directions[direction_index];
+
         current_id = match direction {
             Direction::Left => &current_location.left_id,
             Direction::Right => &current_location.right_id,
@@ -66,30 +71,25 @@ fn find_loop_length_for_id(
     }
 }
 
-fn lowest_common_multiple(numbers: Vec<u128>) -> u128 {
-    numbers
-        .iter()
-        .skip(1)
-        .fold(*numbers.get(0).unwrap(), |previous, next| {
-            lcm::<u128>(previous, *next)
-        })
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse::parse_network;
 
     #[test]
-    fn finds_lowest_common_denominator() {
-        let numbers = vec![12, 4, 22];
+    fn calculates_ghost_steps() {
+        let input = "LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\n22A = (22B, XXX)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22B, 22B)\nXXX = (XXX, XXX)";
 
-        assert_eq!(lowest_common_multiple(numbers), 132)
+        assert_eq!(
+            calculate_necessary_ghost_steps(&parse_network(input).unwrap()),
+            Some(6)
+        )
     }
 
     #[test]
-    fn calculates_ghost_steps() {
-        let input = "LR\n\n11A = (11B, XXX)\n11B = (XXX, 11Z)\n11Z = (11B, XXX)\n22A = (22B, XXX)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22B, 22B)\nXXX = (XXX, XXX)";
+    fn finds_no_steps_for_ghosts_going_round_in_circles() {
+        let network = parse_network("L\n\n11A = (11A, 11Z)\n11Z = (11Z, 11Z)").unwrap();
 
-        assert_eq!(calculate_necessary_ghost_steps(input), 6)
+        assert_eq!(calculate_necessary_ghost_steps(&network), None);
     }
 }
@@ -0,0 +1,155 @@
+use common::{parse_lines, parse_number, Answer, ParseError, Solution};
+
+type NumberLine = Vec<i32>;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<NumberLine>;
+
+    fn parse(text: &str) -> Result<Vec<NumberLine>, ParseError> {
+        parse_number_lines(text)
+    }
+
+    fn part1(input: &Vec<NumberLine>) -> Answer {
+        let result: i32 = input.iter().map(find_next_last_in_number_line).sum();
+
+        result.into()
+    }
+
+    fn part2(input: &Vec<NumberLine>) -> Answer {
+        let result: i32 = input.iter().map(find_next_first_in_number_line).sum();
+
+        result.into()
+    }
+}
+
+fn parse_number_lines(text: &str) -> Result<Vec<NumberLine>, ParseError> {
+    parse_lines(text, |text_line| {
+        text_line
+            .split_whitespace()
+            .map(|number| parse_number(text_line, number))
+            .collect()
+    })
+}
+
+fn find_next_last_in_number_line(line: &NumberLine) -> i32 {
+    let lines = unwrap_line(line);
+
+    lines
+        .iter()
+        .rev()
+        .map(|line| line.iter().last().unwrap())
+        .sum()
+}
+
+fn find_next_first_in_number_line(line: &NumberLine) -> i32 {
+    let lines = unwrap_line(line);
+
+    lines
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, line)| (index, line.first().unwrap()))
+        .fold(0, |accumulator, (index, next)| {
+            if index == 0 {
+                return *next;
+            }
+            next - accumulator
+        })
+}
+
+fn unwrap_line(line: &NumberLine) -> Vec<NumberLine> {
+    let mut lines: Vec<NumberLine> = vec![line.to_vec()];
+    let mut lines_index = 0;
+
+    loop {
+        let line = lines.get(lines_index).unwrap();
+        let next_line = find_next_line_by_difference(line);
+
+        lines.push(next_line.to_vec());
+
+        if next_line.iter().all(|value| value == &0) {
+            break;
+        }
+
+        lines_index += 1;
+    }
+
+    lines
+}
+
+fn find_next_line_by_difference(line: &NumberLine) -> NumberLine {
+    line.iter()
+        .skip(1)
+        .enumerate()
+        .map(|(index, value)| {
+            let previous = line.get(index).unwrap();
+            value - previous
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::check_examples;
+
+    #[test]
+    fn finds_next_line_in_sequence() {
+        let input = vec![0, 3, 6, 9, 12, 15];
+
+        let result = find_next_line_by_difference(&input);
+        assert_eq!(result, vec![3, 3, 3, 3, 3]);
+
+        let result = find_next_line_by_difference(&result);
+        assert_eq!(result, vec![0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn unwraps_line() {
+        let input = vec![0, 3, 6, 9, 12, 15];
+
+        let result = unwrap_line(&input);
+        assert_eq!(
+            result,
+            vec![input.to_vec(), vec![3, 3, 3, 3, 3], vec![0, 0, 0, 0]]
+        );
+    }
+
+    #[test]
+    fn calculates_next_last_number_in_line() {
+        let input = vec![0, 3, 6, 9, 12, 15];
+        assert_eq!(find_next_last_in_number_line(&input), 18);
+
+        let input = vec![1, 3, 6, 10, 15, 21];
+        assert_eq!(find_next_last_in_number_line(&input), 28);
+
+        let input = vec![10, 13, 16, 21, 30, 45];
+        assert_eq!(find_next_last_in_number_line(&input), 68);
+    }
+
+    #[test]
+    fn calculates_next_first_number_in_line() {
+        let input = vec![0, 3, 6, 9, 12, 15];
+        assert_eq!(find_next_first_in_number_line(&input), -3);
+
+        let input = vec![1, 3, 6, 10, 15, 21];
+        assert_eq!(find_next_first_in_number_line(&input), 0);
+
+        let input = vec![10, 13, 16, 21, 30, 45];
+        assert_eq!(find_next_first_in_number_line(&input), 5);
+    }
+
+    #[test]
+    fn rejects_malformed_number_lines() {
+        let error = parse_number_lines("0 3 6\n1 3 x").unwrap_err();
+
+        assert_eq!(error, ParseError::new(2, 5, "invalid number 'x'"));
+    }
+
+    #[test]
+    fn solves_readme_examples() {
+        check_examples::<Day9>(include_str!("../readme.md"));
+    }
+}
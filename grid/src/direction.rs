@@ -0,0 +1,85 @@
+use crate::Position;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    N,
+    E,
+    S,
+    W,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
+
+    pub fn from_symbol(symbol: char) -> Option<Direction> {
+        match symbol {
+            'N' | 'U' | '^' => Some(Direction::N),
+            'E' | 'R' | '>' => Some(Direction::E),
+            'S' | 'D' | 'v' => Some(Direction::S),
+            'W' | 'L' | '<' => Some(Direction::W),
+            _ => None,
+        }
+    }
+}
+
+impl Direction {
+    pub fn opposite(&self) -> Direction {
+        match self {
+            Direction::N => Direction::S,
+            Direction::E => Direction::W,
+            Direction::S => Direction::N,
+            Direction::W => Direction::E,
+        }
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        match self {
+            Direction::N => Direction::W,
+            Direction::E => Direction::N,
+            Direction::S => Direction::E,
+            Direction::W => Direction::S,
+        }
+    }
+
+    pub fn turn_right(&self) -> Direction {
+        self.turn_left().opposite()
+    }
+
+    pub fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::N => (0, -1),
+            Direction::E => (1, 0),
+            Direction::S => (0, 1),
+            Direction::W => (-1, 0),
+        }
+    }
+
+    // moves one step without an upper bound; only leaving the grid past zero returns None
+    pub fn step(&self, position: &Position) -> Option<Position> {
+        let (x, y) = *position;
+        let (dx, dy) = self.offset();
+
+        Some((x.checked_add_signed(dx)?, y.checked_add_signed(dy)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_and_reverses() {
+        assert_eq!(Direction::N.opposite(), Direction::S);
+        assert_eq!(Direction::N.turn_left(), Direction::W);
+        assert_eq!(Direction::N.turn_right(), Direction::E);
+        assert_eq!(Direction::W.turn_right(), Direction::N);
+    }
+
+    #[test]
+    fn steps_from_position() {
+        assert_eq!(Direction::E.step(&(0, 0)), Some((1, 0)));
+        assert_eq!(Direction::S.step(&(2, 3)), Some((2, 4)));
+        assert_eq!(Direction::N.step(&(2, 0)), None);
+        assert_eq!(Direction::W.step(&(0, 2)), None);
+    }
+}
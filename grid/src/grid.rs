@@ -0,0 +1,325 @@
+use crate::{Direction, Position};
+use common::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), width * height, "grid cells must fill the size");
+
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    // rejects ragged rows
+    pub fn from_text(
+        text: &str,
+        mut parse_cell: impl FnMut(Position, char) -> T,
+    ) -> Result<Grid<T>, ParseError> {
+        Grid::parse(text, |position, symbol| Some(parse_cell(position, symbol)))
+    }
+
+    // like from_text, but also rejects symbols the cell parser returns None for
+    pub fn parse(
+        text: &str,
+        mut parse_cell: impl FnMut(Position, char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut cells = vec![];
+        let mut width: Option<usize> = None;
+        let mut height = 0;
+
+        for (y, line) in text.lines().enumerate() {
+            let mut line_width = 0;
+
+            for (x, symbol) in line.chars().enumerate() {
+                let cell = parse_cell((x, y), symbol).ok_or_else(|| {
+                    ParseError::new(y + 1, x + 1, format!("unexpected symbol '{symbol}'"))
+                })?;
+
+                cells.push(cell);
+                line_width = x + 1;
+            }
+
+            match width {
+                Some(width) if width != line_width => {
+                    return Err(ParseError::new(
+                        y + 1,
+                        width.min(line_width) + 1,
+                        format!("expected {width} columns, found {line_width}"),
+                    ));
+                }
+                _ => width = Some(line_width),
+            }
+
+            height = y + 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    pub fn transpose(&self) -> Grid<T> {
+        let cells = (0..self.width)
+            .flat_map(|x| self.col(x).cloned().collect::<Vec<T>>())
+            .collect();
+
+        Grid::new(self.height, self.width, cells)
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T> {
+        let cells = (0..self.width)
+            .flat_map(|x| self.col(x).rev().cloned().collect::<Vec<T>>())
+            .collect();
+
+        Grid::new(self.height, self.width, cells)
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Grid<T> {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|x| self.col(x).cloned().collect::<Vec<T>>())
+            .collect();
+
+        Grid::new(self.height, self.width, cells)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    pub fn contains(&self, position: &Position) -> bool {
+        let (x, y) = *position;
+
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: &Position) -> Option<&T> {
+        match self.contains(position) {
+            true => self.cells.get(self.index(position)),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, position: &Position) -> Option<&mut T> {
+        match self.contains(position) {
+            true => {
+                let index = self.index(position);
+                self.cells.get_mut(index)
+            }
+            false => None,
+        }
+    }
+
+    pub fn set(&mut self, position: &Position, value: T) {
+        let cell = self
+            .get_mut(position)
+            .expect("position is outside the grid");
+
+        *cell = value;
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        (0..self.height).flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[(y * self.width)..((y + 1) * self.width)]
+    }
+
+    pub fn col(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells
+            .iter()
+            .skip(x)
+            // a grid without columns has no cells to step over
+            .step_by(self.width.max(1))
+            .take(self.height)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn step(&self, position: &Position, direction: Direction) -> Option<Position> {
+        direction
+            .step(position)
+            .filter(|position| self.contains(position))
+    }
+
+    pub fn neighbors(&self, position: &Position) -> Vec<(Direction, Position)> {
+        Direction::ALL
+            .iter()
+            .filter_map(|direction| {
+                self.step(position, *direction)
+                    .map(|neighbor| (*direction, neighbor))
+            })
+            .collect()
+    }
+
+    pub fn all_neighbors(&self, position: &Position) -> Vec<Position> {
+        let (x, y) = *position;
+        let mut neighbors = vec![];
+
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                if dx == 0 && dy == 0 {
+                    continue;
+                }
+
+                let neighbor = x.checked_add_signed(dx).zip(y.checked_add_signed(dy));
+
+                match neighbor {
+                    Some(neighbor) if self.contains(&neighbor) => neighbors.push(neighbor),
+                    _ => {}
+                }
+            }
+        }
+
+        neighbors
+    }
+
+    fn index(&self, position: &Position) -> usize {
+        let (x, y) = *position;
+
+        y * self.width + x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(text: &str) -> Grid<u32> {
+        Grid::from_text(text, |_, symbol| symbol.to_digit(10).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn constructs_grid_from_text() {
+        let grid = digits("123\n456");
+
+        assert_eq!(grid.size(), (3, 2));
+        assert_eq!(grid.get(&(0, 0)), Some(&1));
+        assert_eq!(grid.get(&(2, 1)), Some(&6));
+        assert_eq!(grid.get(&(3, 0)), None);
+        assert_eq!(grid.get(&(0, 2)), None);
+    }
+
+    #[test]
+    fn rejects_unexpected_symbols_and_ragged_rows() {
+        let parse = |text| Grid::parse(text, |_, symbol| symbol.to_digit(10));
+
+        assert_eq!(parse("123\n456"), Ok(digits("123\n456")));
+        assert_eq!(
+            parse("123\n4x6"),
+            Err(ParseError::new(2, 2, "unexpected symbol 'x'"))
+        );
+        assert_eq!(
+            parse("123\n45"),
+            Err(ParseError::new(2, 3, "expected 3 columns, found 2"))
+        );
+    }
+
+    #[test]
+    fn rejects_ragged_text() {
+        let grid = Grid::from_text("12\n3", |_, symbol| symbol);
+
+        assert_eq!(
+            grid,
+            Err(ParseError::new(2, 2, "expected 2 columns, found 1"))
+        );
+    }
+
+    #[test]
+    fn handles_empty_grids() {
+        let empty = digits("");
+        assert_eq!(empty.size(), (0, 0));
+        assert_eq!(empty.rows().count(), 0);
+        assert_eq!(empty.transpose(), empty);
+
+        let blank = Grid::from_text("\n\n", |_, symbol| symbol).unwrap();
+        assert_eq!(blank.size(), (0, 2));
+        assert_eq!(blank.rows().collect::<Vec<&[char]>>(), vec![&[], &[]]);
+        assert_eq!(blank.col(0).count(), 0);
+        assert_eq!(blank.rotate_clockwise().size(), (2, 0));
+        assert_eq!(blank.positions().count(), 0);
+    }
+
+    #[test]
+    fn slices_rows_and_cols() {
+        let grid = digits("123\n456");
+
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.col(1).copied().collect::<Vec<u32>>(), vec![2, 5]);
+        assert_eq!(grid.rows().count(), 2);
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let grid = digits("123\n456");
+
+        assert_eq!(grid.transpose(), digits("14\n25\n36"));
+        assert_eq!(grid.rotate_clockwise(), digits("41\n52\n63"));
+        assert_eq!(grid.rotate_counter_clockwise(), digits("36\n25\n14"));
+        assert_eq!(
+            grid.rotate_clockwise().rotate_counter_clockwise(),
+            grid.clone()
+        );
+    }
+
+    #[test]
+    fn finds_neighbors_within_bounds() {
+        let grid = digits("123\n456\n789");
+
+        assert_eq!(
+            grid.neighbors(&(0, 0)),
+            vec![(Direction::E, (1, 0)), (Direction::S, (0, 1))]
+        );
+        assert_eq!(grid.neighbors(&(1, 1)).len(), 4);
+
+        assert_eq!(grid.all_neighbors(&(0, 0)), vec![(1, 0), (0, 1), (1, 1)]);
+        assert_eq!(grid.all_neighbors(&(1, 1)).len(), 8);
+        assert_eq!(grid.all_neighbors(&(2, 2)).len(), 3);
+    }
+
+    #[test]
+    fn updates_and_finds_cells() {
+        let mut grid = digits("123\n456");
+
+        grid.set(&(1, 1), 0);
+
+        assert_eq!(grid.find(|cell| *cell == 0), Some((1, 1)));
+        assert_eq!(grid.find(|cell| *cell == 5), None);
+    }
+}
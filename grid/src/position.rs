@@ -0,0 +1 @@
+pub type Position = (usize, usize);
@@ -0,0 +1,9 @@
+mod line;
+mod number;
+mod polygon;
+mod rational;
+
+pub use line::{Intersection, Line};
+pub use number::{chinese_remainder, gcd, integer_sqrt, lcm, lowest_common_multiple};
+pub use polygon::{area, boundary_points, double_area, interior_points, lattice_points, Point};
+pub use rational::Rational;
@@ -0,0 +1,96 @@
+use crate::Rational;
+
+// every point + t * direction for any t
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line {
+    pub point: (i128, i128),
+    pub direction: (i128, i128),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Intersection {
+    pub position: (Rational, Rational),
+    // the t that reaches the position along each line
+    pub parameters: (Rational, Rational),
+}
+
+impl Line {
+    pub fn new(point: (i128, i128), direction: (i128, i128)) -> Line {
+        Line { point, direction }
+    }
+}
+
+impl Line {
+    // returns None for parallel lines, including identical ones
+    pub fn intersect(&self, other: &Line) -> Option<Intersection> {
+        let (x1, y1) = self.point;
+        let (dx1, dy1) = self.direction;
+        let (x2, y2) = other.point;
+        let (dx2, dy2) = other.direction;
+
+        let denominator = cross((dx1, dy1), (dx2, dy2));
+        if denominator == 0 {
+            return None;
+        }
+
+        let offset = (
+            x2.checked_sub(x1).expect("intersection overflows i128"),
+            y2.checked_sub(y1).expect("intersection overflows i128"),
+        );
+
+        let t1 = Rational::new(cross(offset, (dx2, dy2)), denominator);
+        let t2 = Rational::new(cross(offset, (dx1, dy1)), denominator);
+
+        let along = |start: i128, step: i128| {
+            t1.checked_mul(step.into())
+                .and_then(|distance| distance.checked_add(start.into()))
+                .expect("intersection overflows i128")
+        };
+
+        let x = along(x1, dx1);
+        let y = along(y1, dy1);
+
+        Some(Intersection {
+            position: (x, y),
+            parameters: (t1, t2),
+        })
+    }
+}
+
+fn cross(a: (i128, i128), b: (i128, i128)) -> i128 {
+    a.0.checked_mul(b.1)
+        .zip(a.1.checked_mul(b.0))
+        .and_then(|(first, second)| first.checked_sub(second))
+        .expect("cross product overflows i128")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_line_intersection() {
+        let line1 = Line::new((19, 13), (-2, 1));
+        let line2 = Line::new((18, 19), (-1, -1));
+
+        let intersection = line1.intersect(&line2).unwrap();
+
+        assert_eq!(
+            intersection.position,
+            (Rational::new(43, 3), Rational::new(46, 3))
+        );
+        assert_eq!(
+            intersection.parameters,
+            (Rational::new(7, 3), Rational::new(11, 3))
+        );
+    }
+
+    #[test]
+    fn finds_no_intersection_for_parallel_lines() {
+        let line1 = Line::new((18, 19), (-1, -1));
+        let line2 = Line::new((20, 25), (-2, -2));
+
+        assert_eq!(line1.intersect(&line2), None);
+        assert_eq!(line1.intersect(&line1), None);
+    }
+}
@@ -0,0 +1,137 @@
+// https://en.wikipedia.org/wiki/Euclidean_algorithm
+pub fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+// returns None when the result does not fit
+pub fn lcm(a: i128, b: i128) -> Option<i128> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b)?.checked_abs()
+}
+
+pub fn lowest_common_multiple(numbers: &[i128]) -> Option<i128> {
+    numbers
+        .iter()
+        .try_fold(1, |previous, next| lcm(previous, *next))
+}
+
+// returns (gcd, x, y) such that a * x + b * y = gcd
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    match b {
+        0 => (a, 1, 0),
+        _ => {
+            let (gcd, x, y) = extended_gcd(b, a % b);
+            (gcd, y, x - (a / b) * y)
+        }
+    }
+}
+
+// https://en.wikipedia.org/wiki/Integer_square_root
+
+// largest x such that x * x <= n
+pub fn integer_sqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+
+    // Newton's method descends onto the root from any starting point above it
+    let mut root = 1_u128 << (128 - n.leading_zeros()).div_ceil(2);
+
+    loop {
+        let next = (root + n / root) / 2;
+
+        if next >= root {
+            return root;
+        }
+
+        root = next;
+    }
+}
+
+// https://en.wikipedia.org/wiki/Chinese_remainder_theorem
+
+// solves x = residue (mod modulus) for every (residue, modulus) pair,
+// returning (x, combined modulus) with the smallest non-negative x;
+// moduli do not need to be coprime, but the congruences must agree
+pub fn chinese_remainder(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    congruences.iter().try_fold(
+        (0, 1),
+        |(residue, modulus), (next_residue, next_modulus)| {
+            let next_residue = next_residue.rem_euclid(*next_modulus);
+            let (gcd, inverse, _) = extended_gcd(modulus, *next_modulus);
+
+            let difference = next_residue - residue;
+            if difference % gcd != 0 {
+                return None;
+            }
+
+            let combined = lcm(modulus, *next_modulus)?;
+            let step = (difference / gcd)
+                .checked_mul(inverse)?
+                .rem_euclid(next_modulus / gcd);
+            let result = step.checked_mul(modulus)?.checked_add(residue)?;
+
+            Some((result.rem_euclid(combined), combined))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_greatest_common_divisor() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(gcd(0, 0), 0);
+    }
+
+    #[test]
+    fn finds_lowest_common_multiple() {
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(-4, 6), Some(12));
+        assert_eq!(lowest_common_multiple(&[12, 4, 22]), Some(132));
+        assert_eq!(lowest_common_multiple(&[]), Some(1));
+    }
+
+    #[test]
+    fn detects_lowest_common_multiple_overflow() {
+        assert_eq!(lcm(i128::MAX, i128::MAX - 1), None);
+        assert_eq!(lowest_common_multiple(&[i128::MAX, 2]), None);
+    }
+
+    #[test]
+    fn finds_integer_square_root() {
+        let roots: Vec<u128> = (0..=10).map(integer_sqrt).collect();
+        assert_eq!(roots, vec![0, 1, 1, 1, 2, 2, 2, 2, 2, 3, 3]);
+
+        assert_eq!(integer_sqrt(u128::MAX), u64::MAX as u128);
+        assert_eq!(integer_sqrt((1 << 100) - 1), (1 << 50) - 1);
+    }
+
+    #[test]
+    fn solves_chinese_remainder_congruences() {
+        assert_eq!(
+            chinese_remainder(&[(2, 3), (3, 5), (2, 7)]),
+            Some((23, 105))
+        );
+        assert_eq!(chinese_remainder(&[(-1, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(chinese_remainder(&[]), Some((0, 1)));
+    }
+
+    #[test]
+    fn rejects_conflicting_congruences() {
+        assert_eq!(chinese_remainder(&[(1, 4), (2, 6)]), None);
+    }
+}
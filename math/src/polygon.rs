@@ -0,0 +1,72 @@
+use crate::{gcd, Rational};
+
+pub type Point = (i64, i64);
+
+// https://en.wikipedia.org/wiki/Shoelace_formula
+pub fn double_area(vertices: &[Point]) -> i128 {
+    let sum: i128 = edges(vertices)
+        .map(|((x1, y1), (x2, y2))| x1 * y2 - y1 * x2)
+        .sum();
+
+    sum.abs()
+}
+
+pub fn area(vertices: &[Point]) -> Rational {
+    Rational::new(double_area(vertices), 2)
+}
+
+pub fn boundary_points(vertices: &[Point]) -> i128 {
+    edges(vertices)
+        .map(|((x1, y1), (x2, y2))| gcd(x2 - x1, y2 - y1))
+        .sum()
+}
+
+// https://en.wikipedia.org/wiki/Pick%27s_theorem
+
+// A = i + b/2 - 1
+// i = A - b/2 + 1
+pub fn interior_points(double_area: i128, boundary_points: i128) -> i128 {
+    (double_area - boundary_points + 2) / 2
+}
+
+pub fn lattice_points(vertices: &[Point]) -> i128 {
+    let boundary = boundary_points(vertices);
+
+    interior_points(double_area(vertices), boundary) + boundary
+}
+
+fn edges(vertices: &[Point]) -> impl Iterator<Item = ((i128, i128), (i128, i128))> + '_ {
+    let widen = |(x, y): &Point| (*x as i128, *y as i128);
+
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(move |(point, next)| (widen(point), widen(next)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_shoelace_formula() {
+        let vertices = vec![(1, 6), (3, 1), (7, 2), (4, 4), (8, 5)];
+
+        assert_eq!(double_area(&vertices), 33);
+        assert_eq!(area(&vertices), Rational::new(33, 2));
+    }
+
+    #[test]
+    fn counts_lattice_points() {
+        let square = vec![(0, 0), (4, 0), (4, 4), (0, 4)];
+
+        assert_eq!(boundary_points(&square), 16);
+        assert_eq!(interior_points(double_area(&square), 16), 9);
+        assert_eq!(lattice_points(&square), 25);
+
+        let triangle = vec![(0, 0), (6, 3), (0, 3)];
+
+        assert_eq!(boundary_points(&triangle), 12);
+        assert_eq!(lattice_points(&triangle), 16);
+    }
+}
@@ -0,0 +1,271 @@
+use crate::gcd;
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+// always stored in lowest terms with a positive denominator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub fn new(numerator: i128, denominator: i128) -> Rational {
+        assert_ne!(denominator, 0, "denominator must not be zero");
+
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+
+        Rational {
+            numerator: numerator / divisor,
+            denominator: denominator / divisor,
+        }
+    }
+}
+
+impl Rational {
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Rational::new(value, 1)
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.is_integer() {
+            true => write!(f, "{}", self.numerator),
+            false => write!(f, "{}/{}", self.numerator, self.denominator),
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_fractions(
+            (self.numerator, self.denominator),
+            (other.numerator, other.denominator),
+        )
+    }
+}
+
+// compares the integer parts first and then the remainders, so nothing is multiplied and
+// nothing can overflow; denominators must be positive
+fn compare_fractions(
+    (numerator, denominator): (i128, i128),
+    (other_numerator, other_denominator): (i128, i128),
+) -> Ordering {
+    let whole = numerator.div_euclid(denominator);
+    let other_whole = other_numerator.div_euclid(other_denominator);
+
+    if whole != other_whole {
+        return whole.cmp(&other_whole);
+    }
+
+    let remainder = numerator.rem_euclid(denominator);
+    let other_remainder = other_numerator.rem_euclid(other_denominator);
+
+    // r/d < r'/d' exactly when d'/r' < d/r
+    match (remainder, other_remainder) {
+        (0, 0) => Ordering::Equal,
+        (0, _) => Ordering::Less,
+        (_, 0) => Ordering::Greater,
+        _ => compare_fractions(
+            (other_denominator, other_remainder),
+            (denominator, remainder),
+        ),
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// like the operators, but return None instead of overflowing
+impl Rational {
+    pub fn checked_add(self, other: Rational) -> Option<Rational> {
+        let numerator = self
+            .numerator
+            .checked_mul(other.denominator)?
+            .checked_add(other.numerator.checked_mul(self.denominator)?)?;
+
+        Some(Rational::new(
+            numerator,
+            self.denominator.checked_mul(other.denominator)?,
+        ))
+    }
+
+    pub fn checked_sub(self, other: Rational) -> Option<Rational> {
+        self.checked_add(other.checked_neg()?)
+    }
+
+    pub fn checked_mul(self, other: Rational) -> Option<Rational> {
+        Some(Rational::new(
+            self.numerator.checked_mul(other.numerator)?,
+            self.denominator.checked_mul(other.denominator)?,
+        ))
+    }
+
+    // also None when dividing by zero
+    pub fn checked_div(self, other: Rational) -> Option<Rational> {
+        if other.numerator == 0 {
+            return None;
+        }
+
+        Some(Rational::new(
+            self.numerator.checked_mul(other.denominator)?,
+            self.denominator.checked_mul(other.numerator)?,
+        ))
+    }
+
+    pub fn checked_neg(self) -> Option<Rational> {
+        Some(Rational::new(
+            self.numerator.checked_neg()?,
+            self.denominator,
+        ))
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Rational {
+        self.checked_add(other).expect("rational overflows i128")
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Rational {
+        self.checked_sub(other).expect("rational overflows i128")
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Rational {
+        self.checked_mul(other).expect("rational overflows i128")
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, other: Rational) -> Rational {
+        assert_ne!(other.numerator, 0, "division by zero");
+
+        self.checked_div(other).expect("rational overflows i128")
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        self.checked_neg().expect("rational overflows i128")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reduces_to_lowest_terms() {
+        assert_eq!(Rational::new(4, -6), Rational::new(-2, 3));
+        assert_eq!(Rational::new(-4, -6).to_string(), "2/3");
+        assert_eq!(Rational::new(6, 3).to_string(), "2");
+        assert!(Rational::new(0, -5).is_integer());
+    }
+
+    #[test]
+    fn performs_arithmetic() {
+        let half = Rational::new(1, 2);
+        let third = Rational::new(1, 3);
+
+        assert_eq!(half + third, Rational::new(5, 6));
+        assert_eq!(half - third, Rational::new(1, 6));
+        assert_eq!(half * third, Rational::new(1, 6));
+        assert_eq!(half / third, Rational::new(3, 2));
+        assert_eq!(-half, Rational::new(-1, 2));
+    }
+
+    #[test]
+    fn detects_overflow() {
+        let large = Rational::from(i128::MAX);
+        let half = Rational::new(1, 2);
+
+        assert_eq!(large.checked_add(Rational::from(1)), None);
+        assert_eq!(large.checked_sub(Rational::from(-1)), None);
+        assert_eq!(large.checked_mul(Rational::from(2)), None);
+        assert_eq!(large.checked_div(half), None);
+        assert_eq!(half.checked_div(Rational::from(0)), None);
+        assert_eq!(large.checked_sub(large), Some(Rational::from(0)));
+        assert_eq!(large.checked_mul(half), Some(Rational::new(i128::MAX, 2)));
+    }
+
+    #[test]
+    fn compares_values() {
+        assert!(Rational::new(1, 3) < Rational::new(1, 2));
+        assert!(Rational::new(-1, 2) < Rational::from(0));
+        assert_eq!(
+            Rational::new(2, 4).cmp(&Rational::new(1, 2)),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn compares_values_like_cross_multiplying() {
+        let values: Vec<Rational> = (-12..=12)
+            .flat_map(|numerator| {
+                (1..=7).map(move |denominator| Rational::new(numerator, denominator))
+            })
+            .collect();
+
+        for first in &values {
+            for second in &values {
+                let expected = (first.numerator * second.denominator)
+                    .cmp(&(second.numerator * first.denominator));
+
+                assert_eq!(first.cmp(second), expected, "{first} and {second}");
+            }
+        }
+    }
+
+    #[test]
+    fn compares_values_without_overflowing() {
+        let max = i128::MAX;
+
+        // 1 + 1/(max - 1) against 1 + 1/(max - 2)
+        assert!(Rational::new(max, max - 1) < Rational::new(max - 1, max - 2));
+        assert!(Rational::new(-max, max - 1) > Rational::new(-(max - 1), max - 2));
+        assert!(Rational::new(-max, 2) < Rational::new(-max, 3));
+        assert!(Rational::new(max - 1, max) < Rational::from(1));
+        assert_eq!(
+            Rational::new(max, 3).cmp(&Rational::new(max, 3)),
+            Ordering::Equal
+        );
+    }
+}
@@ -0,0 +1,6 @@
+mod priority_queue;
+mod shortest_path;
+mod state;
+
+pub use shortest_path::{a_star, dijkstra, shortest_distances, SearchResult};
+pub use state::SearchState;
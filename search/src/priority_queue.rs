@@ -0,0 +1,60 @@
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone)]
+pub struct QueueNode<S> {
+    pub priority: u32,
+    pub cost: u32,
+    pub state: S,
+}
+
+impl<S> QueueNode<S> {
+    pub fn new(priority: u32, cost: u32, state: S) -> QueueNode<S> {
+        QueueNode {
+            priority,
+            cost,
+            state,
+        }
+    }
+}
+
+impl<S> PartialEq for QueueNode<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S> Eq for QueueNode<S> {}
+
+impl<S> PartialOrd for QueueNode<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// reversed so that BinaryHeap pops the lowest priority first
+impl<S> Ord for QueueNode<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BinaryHeap;
+
+    #[test]
+    fn pops_lowest_priority_first() {
+        let mut queue = BinaryHeap::new();
+
+        queue.push(QueueNode::new(1, 1, 'a'));
+        queue.push(QueueNode::new(2, 2, 'b'));
+        queue.push(QueueNode::new(0, 1, 'c'));
+
+        let order: Vec<char> = std::iter::from_fn(|| queue.pop())
+            .map(|node| node.state)
+            .collect();
+
+        assert_eq!(order, vec!['c', 'a', 'b']);
+    }
+}
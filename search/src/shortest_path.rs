@@ -0,0 +1,213 @@
+use crate::{priority_queue::QueueNode, SearchState};
+use std::collections::{BinaryHeap, HashMap};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResult<S> {
+    pub cost: u32,
+    pub path: Vec<S>,
+    pub visited: usize,
+    pub discovered: usize,
+}
+
+// https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm
+pub fn dijkstra<G, S: SearchState<G>>(graph: &G, start: S) -> Option<SearchResult<S>> {
+    find_shortest_path(graph, start, false)
+}
+
+// https://en.wikipedia.org/wiki/A*_search_algorithm
+pub fn a_star<G, S: SearchState<G>>(graph: &G, start: S) -> Option<SearchResult<S>> {
+    find_shortest_path(graph, start, true)
+}
+
+pub fn shortest_distances<G, S: SearchState<G>>(graph: &G, start: S) -> HashMap<S, u32> {
+    let mut queue = BinaryHeap::from([QueueNode::new(0, 0, start.clone())]);
+    let mut distance: HashMap<S, u32> = HashMap::from([(start, 0)]);
+
+    while let Some(QueueNode { cost, state, .. }) = queue.pop() {
+        if distance.get(&state).is_some_and(|best| best < &cost) {
+            continue;
+        }
+
+        for (next, step_cost) in state.successors(graph) {
+            let next_cost = cost + step_cost;
+
+            if distance.get(&next).is_some_and(|best| best <= &next_cost) {
+                continue;
+            }
+
+            distance.insert(next.clone(), next_cost);
+            queue.push(QueueNode::new(next_cost, next_cost, next));
+        }
+    }
+
+    distance
+}
+
+fn find_shortest_path<G, S: SearchState<G>>(
+    graph: &G,
+    start: S,
+    use_heuristic: bool,
+) -> Option<SearchResult<S>> {
+    let heuristic = |state: &S| match use_heuristic {
+        true => state.heuristic(graph),
+        false => 0,
+    };
+
+    let mut queue = BinaryHeap::from([QueueNode::new(heuristic(&start), 0, start.clone())]);
+    let mut came_from: HashMap<S, S> = HashMap::new();
+    let mut distance: HashMap<S, u32> = HashMap::from([(start, 0)]);
+    let mut visited = 0;
+
+    while let Some(QueueNode { cost, state, .. }) = queue.pop() {
+        if distance.get(&state).is_some_and(|best| best < &cost) {
+            continue;
+        }
+
+        visited += 1;
+
+        if state.is_goal(graph) {
+            return Some(SearchResult {
+                cost,
+                path: construct_path(&came_from, state),
+                visited,
+                discovered: distance.len(),
+            });
+        }
+
+        for (next, step_cost) in state.successors(graph) {
+            let next_cost = cost + step_cost;
+
+            if distance.get(&next).is_some_and(|best| best <= &next_cost) {
+                continue;
+            }
+
+            came_from.insert(next.clone(), state.clone());
+            distance.insert(next.clone(), next_cost);
+
+            queue.push(QueueNode::new(
+                next_cost + heuristic(&next),
+                next_cost,
+                next,
+            ));
+        }
+    }
+
+    None
+}
+
+fn construct_path<S: Clone + Eq + std::hash::Hash>(came_from: &HashMap<S, S>, end: S) -> Vec<S> {
+    let mut path = vec![end];
+
+    while let Some(previous) = came_from.get(path.last().unwrap()) {
+        path.push(previous.clone());
+    }
+
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // weighted edges of a small directed graph, searched from node 0 to the goal node
+    struct Edges {
+        edges: Vec<Vec<(usize, u32)>>,
+        goal: usize,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct Node(usize);
+
+    impl SearchState<Edges> for Node {
+        fn successors(&self, graph: &Edges) -> Vec<(Self, u32)> {
+            graph.edges[self.0]
+                .iter()
+                .map(|(next, cost)| (Node(*next), *cost))
+                .collect()
+        }
+
+        fn is_goal(&self, graph: &Edges) -> bool {
+            self.0 == graph.goal
+        }
+    }
+
+    fn edges() -> Edges {
+        Edges {
+            edges: vec![
+                vec![(1, 7), (2, 9), (5, 14)],
+                vec![(2, 10), (3, 15)],
+                vec![(3, 11), (5, 2)],
+                vec![(4, 6)],
+                vec![],
+                vec![(4, 9)],
+            ],
+            goal: 4,
+        }
+    }
+
+    #[test]
+    fn finds_shortest_path_with_dijkstra() {
+        let result = dijkstra(&edges(), Node(0)).unwrap();
+
+        assert_eq!(result.cost, 20);
+        assert_eq!(result.path, vec![Node(0), Node(2), Node(5), Node(4)]);
+        assert!(result.visited <= result.discovered);
+    }
+
+    #[test]
+    fn finds_no_path_to_unreachable_goal() {
+        let mut graph = edges();
+        graph.edges[5].clear();
+        graph.edges[3].clear();
+
+        assert_eq!(dijkstra(&graph, Node(0)), None);
+    }
+
+    #[test]
+    fn finds_shortest_distances_to_all_states() {
+        let distances = shortest_distances(&edges(), Node(0));
+
+        assert_eq!(distances.len(), 6);
+        assert_eq!(distances.get(&Node(3)), Some(&20));
+        assert_eq!(distances.get(&Node(5)), Some(&11));
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct Point(i32, i32);
+
+    // open plane with unit steps, searched towards (5, 5)
+    struct Plane;
+
+    impl SearchState<Plane> for Point {
+        fn successors(&self, _graph: &Plane) -> Vec<(Self, u32)> {
+            let Point(x, y) = *self;
+
+            vec![
+                (Point(x + 1, y), 1),
+                (Point(x - 1, y), 1),
+                (Point(x, y + 1), 1),
+                (Point(x, y - 1), 1),
+            ]
+        }
+
+        fn is_goal(&self, _graph: &Plane) -> bool {
+            self == &Point(5, 5)
+        }
+
+        fn heuristic(&self, _graph: &Plane) -> u32 {
+            self.0.abs_diff(5) + self.1.abs_diff(5)
+        }
+    }
+
+    #[test]
+    fn a_star_visits_fewer_states_than_dijkstra() {
+        let with_heuristic = a_star(&Plane, Point(0, 0)).unwrap();
+        let without_heuristic = dijkstra(&Plane, Point(0, 0)).unwrap();
+
+        assert_eq!(with_heuristic.cost, 10);
+        assert_eq!(without_heuristic.cost, 10);
+        assert_eq!(with_heuristic.path.len(), 11);
+        assert!(with_heuristic.visited < without_heuristic.visited);
+    }
+}
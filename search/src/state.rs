@@ -0,0 +1,12 @@
+use std::hash::Hash;
+
+pub trait SearchState<G>: Clone + Eq + Hash {
+    fn successors(&self, graph: &G) -> Vec<(Self, u32)>;
+
+    fn is_goal(&self, graph: &G) -> bool;
+
+    // must never overestimate the remaining cost for A* to find the shortest path
+    fn heuristic(&self, _graph: &G) -> u32 {
+        0
+    }
+}